@@ -1,23 +1,32 @@
 use crate::{contract::ManagerResult, error::ManagerError, queries::query_module_cw2};
 use crate::{validation, versioning};
 use abstract_core::objects::gov_type::GovernanceDetails;
-use abstract_core::version_control::ModuleResponse;
-use abstract_macros::abstract_response;
+use abstract_core::version_control::{ModuleDeprecation, ModuleResponse};
+use abstract_macros::{abstract_response, with_abstract_event};
 use abstract_sdk::{
     core::{
+        account_factory::ExecuteMsg as AccountFactoryMsg,
         manager::state::DEPENDENTS,
         manager::state::{
-            AccountInfo, SuspensionStatus, ACCOUNT_MODULES, CONFIG, INFO, SUSPENSION_STATUS,
+            AccountInfo, Config, Guardians, InstallPolicy, ModuleSubscription, Proposal,
+            ProposalAction, Recovery, ScheduledOwnerTransfer, SuspensionStatus, ACCOUNT_ID,
+            ACCOUNT_MODULES, CONFIG, FIRST_VALID_PROPOSAL_ID, GUARDIANS, INFO, INSTALL_POLICY,
+            MODULE_IDS, NEXT_PROPOSAL_ID, OWNER, PENDING_OWNER_TRANSFER, PENDING_RECOVERY,
+            PROPOSALS, SUBSCRIPTIONS, SUB_ACCOUNTS, SUSPENSION_STATUS,
         },
-        manager::{CallbackMsg, ExecuteMsg},
+        manager::{BatchAction, CallbackMsg, ExecuteMsg},
         module_factory::ExecuteMsg as ModuleFactoryMsg,
         objects::{
+            account_id::AccountId,
             dependency::Dependency,
-            module::{Module, ModuleInfo, ModuleVersion},
+            fee::FixedFee,
+            module::{Module, ModuleInfo, ModuleVersion, Monetization},
             module_reference::ModuleReference,
+            namespace::Namespace,
             validation::{validate_description, validate_link, validate_name},
         },
-        proxy::ExecuteMsg as ProxyMsg,
+        proxy::{state::MODULE_PERMISSIONS, ExecuteMsg as ProxyMsg, ModulePermissionsUnchecked},
+        version_control::ExecuteMsg as VcExecuteMsg,
         IBC_CLIENT, MANAGER, PROXY,
     },
     cw_helpers::wasm_smart_query,
@@ -33,17 +42,24 @@ use abstract_core::manager::state::ACCOUNT_FACTORY;
 use abstract_core::manager::InternalConfigAction;
 use abstract_sdk::cw_helpers::AbstractAttributes;
 use cosmwasm_std::{
-    ensure, from_binary, to_binary, wasm_execute, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty,
-    Env, MessageInfo, Response, StdError, StdResult, Storage, WasmMsg,
+    ensure, from_binary, to_binary, wasm_execute, Addr, Api, BankMsg, Binary, Coin, CosmosMsg,
+    Deps, DepsMut, Empty, Env, MessageInfo, Response, StdError, StdResult, Storage, Uint128,
+    WasmMsg,
 };
 use cw2::{get_contract_version, ContractVersion};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use semver::Version;
+use std::collections::BTreeMap;
 
 #[abstract_response(MANAGER)]
 pub struct ManagerResponse;
 
 pub(crate) const MIGRATE_CONTEXT: Item<Vec<(String, Vec<Dependency>)>> = Item::new("context");
+/// Modules registered during a batch, their dependencies are asserted in the batch callback.
+pub(crate) const INSTALL_CONTEXT: Item<Vec<String>> = Item::new("install_context");
+/// Proxy permission scopes of modules that are being installed, applied when the module is registered.
+pub(crate) const PENDING_PERMISSIONS: Map<&str, ModulePermissionsUnchecked> =
+    Map::new("pending_permissions");
 
 /// Adds, updates or removes provided addresses.
 /// Should only be called by contract that adds/removes modules.
@@ -59,24 +75,39 @@ pub fn update_module_addresses(
                 return Err(ManagerError::InvalidModuleName {});
             };
             // validate addr
-            ACCOUNT_MODULES.save(
-                deps.storage,
-                id.as_str(),
-                &deps.api.addr_validate(&new_address)?,
-            )?;
+            save_module_address(deps.storage, &id, &deps.api.addr_validate(&new_address)?)?;
         }
     }
 
     if let Some(modules_to_remove) = to_remove {
         for id in modules_to_remove.into_iter() {
             validation::validate_not_proxy(&id)?;
-            ACCOUNT_MODULES.remove(deps.storage, id.as_str());
+            remove_module_address(deps.storage, &id)?;
         }
     }
 
     Ok(ManagerResponse::action("update_module_addresses"))
 }
 
+/// Registers the module address under its id, replacing the previous address of the module.
+pub(crate) fn save_module_address(
+    storage: &mut dyn Storage,
+    id: &str,
+    address: &Addr,
+) -> StdResult<()> {
+    remove_module_address(storage, id)?;
+    ACCOUNT_MODULES.save(storage, id, address)?;
+    MODULE_IDS.save(storage, address, &id.to_string())
+}
+
+fn remove_module_address(storage: &mut dyn Storage, id: &str) -> StdResult<()> {
+    if let Some(address) = ACCOUNT_MODULES.may_load(storage, id)? {
+        MODULE_IDS.remove(storage, &address);
+    }
+    ACCOUNT_MODULES.remove(storage, id);
+    Ok(())
+}
+
 // Attempts to install a new module through the Module Factory Contract
 pub fn install_module(
     deps: DepsMut,
@@ -84,6 +115,7 @@ pub fn install_module(
     _env: Env,
     module: ModuleInfo,
     init_msg: Option<Binary>,
+    permissions: Option<ModulePermissionsUnchecked>,
 ) -> ManagerResult {
     // only owner can call this method
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
@@ -98,13 +130,34 @@ pub fn install_module(
 
     let config = CONFIG.load(deps.storage)?;
 
-    let response =
-        ManagerResponse::new("install_module", vec![("module", module.id_with_version())])
-            .add_message(wasm_execute(
-                config.module_factory_address,
-                &ModuleFactoryMsg::InstallModule { module, init_msg },
-                msg_info.funds, // We forward all the funds to the module_factory address for them to use in the install
-            )?);
+    // Deprecated modules can still be installed
+    let requested_module = VersionControlContract::new(config.version_control_address)
+        .module_registry(deps.as_ref())
+        .query_all_module_config(module.clone())?;
+    assert_install_allowed(deps.storage, &requested_module.module.info)?;
+    if let Some(permissions) = permissions {
+        // Only modules that are whitelisted on the proxy can be scoped
+        if !matches!(
+            requested_module.module.reference,
+            ModuleReference::App(_) | ModuleReference::Adapter(_)
+        ) {
+            return Err(ManagerError::NotProxyModule(module.id()));
+        }
+        PENDING_PERMISSIONS.save(deps.storage, &module.id(), &permissions)?;
+    }
+    let mut attributes = vec![("module", module.id_with_version())];
+    if let Some(deprecation) = &requested_module.config.deprecation {
+        attributes.push((
+            "deprecation_warning",
+            deprecation_warning(&requested_module.module.info, deprecation),
+        ));
+    }
+
+    let response = ManagerResponse::new("install_module", attributes).add_message(wasm_execute(
+        config.module_factory_address,
+        &ModuleFactoryMsg::InstallModule { module, init_msg },
+        msg_info.funds, // We forward all the funds to the module_factory address for them to use in the install
+    )?);
 
     Ok(response)
 }
@@ -113,7 +166,7 @@ pub fn install_module(
 pub fn register_module(
     mut deps: DepsMut,
     msg_info: MessageInfo,
-    _env: Env,
+    env: Env,
     module: Module,
     module_address: String,
 ) -> ManagerResult {
@@ -125,11 +178,20 @@ pub fn register_module(
         return Err(ManagerError::CallerNotModuleFactory {});
     }
 
+    if matches!(
+        module.reference,
+        ModuleReference::App(_) | ModuleReference::Adapter(_)
+    ) {
+        start_subscription(deps.branch(), &env, config, &module.info)?;
+    }
+
     let mut response = update_module_addresses(
         deps.branch(),
         Some(vec![(module.info.id(), module_address.clone())]),
         None,
     )?;
+    let permissions = PENDING_PERMISSIONS.may_load(deps.storage, &module.info.id())?;
+    PENDING_PERMISSIONS.remove(deps.storage, &module.info.id());
 
     match module {
         Module {
@@ -137,13 +199,12 @@ pub fn register_module(
             info,
             ..
         } => {
-            let id = info.id();
             // assert version requirements
-            let dependencies = versioning::assert_install_requirements(deps.as_ref(), &id)?;
-            versioning::set_as_dependent(deps.storage, id, dependencies)?;
+            register_dependencies(deps.branch(), info.id())?;
             response = response.add_message(add_module_to_proxy(
                 proxy_addr.into_string(),
                 module_address,
+                permissions,
             )?)
         }
         Module {
@@ -151,13 +212,12 @@ pub fn register_module(
             info,
             ..
         } => {
-            let id = info.id();
             // assert version requirements
-            let dependencies = versioning::assert_install_requirements(deps.as_ref(), &id)?;
-            versioning::set_as_dependent(deps.storage, id, dependencies)?;
+            register_dependencies(deps.branch(), info.id())?;
             response = response.add_message(add_module_to_proxy(
                 proxy_addr.into_string(),
                 module_address,
+                permissions,
             )?)
         }
         _ => (),
@@ -166,9 +226,222 @@ pub fn register_module(
     Ok(response)
 }
 
+/// Start the subscription to a newly registered module, its first period is paid on install.
+fn start_subscription(
+    deps: DepsMut,
+    env: &Env,
+    config: Config,
+    module_info: &ModuleInfo,
+) -> ManagerResult<()> {
+    let version_control = VersionControlContract::new(config.version_control_address);
+    let monetization = version_control
+        .module_registry(deps.as_ref())
+        .query_all_module_config(module_info.clone())?
+        .config
+        .monetization;
+
+    if let Monetization::Subscription {
+        per_period,
+        period_seconds,
+    } = monetization
+    {
+        SUBSCRIPTIONS.save(
+            deps.storage,
+            &module_info.id(),
+            &ModuleSubscription {
+                per_period,
+                period_seconds,
+                paid_until: env.block.time.plus_seconds(period_seconds),
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Re-price the subscription to an installed module with its current monetization.
+/// A module that is no longer monetized with a subscription ends it, a new subscription starts lapsed.
+fn sync_subscription(
+    storage: &mut dyn Storage,
+    module_id: &str,
+    monetization: Monetization,
+) -> ManagerResult<Option<ModuleSubscription>> {
+    let Monetization::Subscription {
+        per_period,
+        period_seconds,
+    } = monetization
+    else {
+        SUBSCRIPTIONS.remove(storage, module_id);
+        return Ok(None);
+    };
+    let paid_until = SUBSCRIPTIONS
+        .may_load(storage, module_id)?
+        .map(|subscription| subscription.paid_until)
+        .unwrap_or_default();
+    let subscription = ModuleSubscription {
+        per_period,
+        period_seconds,
+        paid_until,
+    };
+    SUBSCRIPTIONS.save(storage, module_id, &subscription)?;
+    Ok(Some(subscription))
+}
+
+/// Pay the next period of the subscription to the module with the ID [`module_id`].
+/// The subscription is re-priced with the monetization of the installed module version first.
+/// The fee is split between the module's revenue recipients, or transferred to the proxy of its namespace owner.
+pub fn pay_subscription(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    module_id: String,
+) -> ManagerResult {
+    let config = CONFIG.load(deps.storage)?;
+    let version_control = VersionControlContract::new(config.version_control_address);
+    let module_addr = load_module_addr(deps.storage, &module_id)?;
+    let installed_version = query_module_cw2(&deps.as_ref(), module_addr)?.version;
+    let module_config = version_control
+        .module_registry(deps.as_ref())
+        .query_all_module_config(ModuleInfo::from_id(
+            &module_id,
+            ModuleVersion::Version(installed_version),
+        )?)?
+        .config;
+
+    let subscribed = SUBSCRIPTIONS.has(deps.storage, &module_id);
+    let Some(mut subscription) =
+        sync_subscription(deps.storage, &module_id, module_config.monetization)?
+    else {
+        if !subscribed {
+            return Err(ManagerError::NoSubscription(module_id));
+        }
+        // The module became free, the payment is refunded
+        let mut response = ManagerResponse::new("end_subscription", vec![("module", module_id)]);
+        if !msg_info.funds.is_empty() {
+            response = response.add_message(BankMsg::Send {
+                to_address: msg_info.sender.into_string(),
+                amount: msg_info.funds,
+            });
+        }
+        return Ok(response);
+    };
+    let fee = FixedFee::new(&subscription.per_period).assert_payment(&msg_info)?;
+
+    // A lapsed subscription restarts at the current block
+    subscription.paid_until = subscription
+        .paid_until
+        .max(env.block.time)
+        .plus_seconds(subscription.period_seconds);
+    SUBSCRIPTIONS.save(deps.storage, &module_id, &subscription)?;
+
+    let fee_msgs = match module_config.revenue_split {
+        // Renewals are split like the first period paid on install
+        Some(split) => split.msgs(fee),
+        None => {
+            let namespace_account = version_control
+                .module_registry(deps.as_ref())
+                .query_namespace(ModuleInfo::from_id_latest(&module_id)?.namespace)?;
+            vec![CosmosMsg::Bank(BankMsg::Send {
+                to_address: namespace_account.account_base.proxy.into_string(),
+                amount: vec![fee],
+            })]
+        }
+    };
+
+    Ok(ManagerResponse::new(
+        "pay_subscription",
+        vec![
+            ("module", module_id),
+            ("paid_until", subscription.paid_until.to_string()),
+        ],
+    )
+    .add_messages(fee_msgs))
+}
+
+/// Assert that the subscription to the module with the ID [`module_id`] did not lapse.
+/// Modules without a subscription are always active.
+fn assert_subscription_active(
+    storage: &dyn Storage,
+    env: &Env,
+    module_id: &str,
+) -> ManagerResult<()> {
+    match SUBSCRIPTIONS.may_load(storage, module_id)? {
+        Some(subscription) if !subscription.is_active(env.block.time) => {
+            Err(ManagerError::SubscriptionLapsed {
+                module_id: module_id.to_string(),
+                paid_until: subscription.paid_until,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Assert the install requirements of a module and set it as dependent on its dependencies.
+/// When the module is registered during a batch, the assertion is deferred to the batch callback.
+fn register_dependencies(deps: DepsMut, module_id: String) -> ManagerResult<()> {
+    if let Some(mut batch_installs) = INSTALL_CONTEXT.may_load(deps.storage)? {
+        batch_installs.push(module_id);
+        INSTALL_CONTEXT.save(deps.storage, &batch_installs)?;
+        return Ok(());
+    }
+    let dependencies = versioning::assert_install_requirements(deps.as_ref(), &module_id)?;
+    versioning::set_as_dependent(deps.storage, module_id, dependencies)
+}
+
+/// Execute a batch of actions atomically.
+/// The install requirements of the modules installed in the batch are asserted once,
+/// after all the actions are executed.
+pub fn execute_batch(
+    mut deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    actions: Vec<BatchAction>,
+) -> ManagerResult {
+    // only owner can execute batches
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    if actions.is_empty() {
+        return Err(ManagerError::NoUpdates {});
+    }
+    // the funds are split across the actions
+    ensure!(
+        total_funds(&msg_info.funds)? == total_funds(actions.iter().flat_map(|a| &a.funds))?,
+        ManagerError::BatchFundsMismatch {}
+    );
+
+    INSTALL_CONTEXT.save(deps.storage, &vec![])?;
+    let mut response = ManagerResponse::new(
+        "execute_batch",
+        vec![("actions", actions.len().to_string())],
+    );
+    for BatchAction { msg, funds } in actions {
+        if matches!(msg, ExecuteMsg::Batch { .. } | ExecuteMsg::Callback(_)) {
+            return Err(ManagerError::InvalidBatchAction {});
+        }
+        let action_info = MessageInfo {
+            sender: msg_info.sender.clone(),
+            funds,
+        };
+        let action_response =
+            crate::contract::execute(deps.branch(), env.clone(), action_info, msg)?;
+        response = response
+            .add_submessages(action_response.messages)
+            .add_attributes(action_response.attributes)
+            .add_events(action_response.events);
+    }
+
+    // Assert the install requirements after all the actions are executed
+    let callback_msg = wasm_execute(
+        env.contract.address,
+        &ExecuteMsg::Callback(CallbackMsg {}),
+        vec![],
+    )?;
+
+    Ok(response.add_message(callback_msg))
+}
+
 /// Execute the [`exec_msg`] on the provided [`module_id`],
 pub fn exec_on_module(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     module_id: String,
     exec_msg: Binary,
@@ -177,6 +450,7 @@ pub fn exec_on_module(
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
 
     let module_addr = load_module_addr(deps.storage, &module_id)?;
+    assert_subscription_active(deps.storage, &env, &module_id)?;
 
     let response = ManagerResponse::new("exec_on_module", vec![("module", module_id)]).add_message(
         CosmosMsg::Wasm(WasmMsg::Execute {
@@ -189,6 +463,89 @@ pub fn exec_on_module(
     Ok(response)
 }
 
+/// Set the permission scopes of the module with the ID [`module_id`] on the proxy
+pub fn update_module_permissions(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    module_id: String,
+    permissions: Option<ModulePermissionsUnchecked>,
+) -> ManagerResult {
+    // only owner can scope module permissions
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    let module_addr = load_module_addr(deps.storage, &module_id)?;
+    let proxy_addr = ACCOUNT_MODULES.load(deps.storage, PROXY)?;
+
+    let update_msg = wasm_execute(
+        proxy_addr,
+        &ProxyMsg::UpdateModulePermissions {
+            module: module_addr.into_string(),
+            permissions,
+        },
+        vec![],
+    )?;
+
+    Ok(
+        ManagerResponse::new("update_module_permissions", vec![("module", module_id)])
+            .add_message(update_msg),
+    )
+}
+
+/// Create a sub-account owned by this Account through the Account Factory
+pub fn create_sub_account(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    name: String,
+    description: Option<String>,
+    link: Option<String>,
+) -> ManagerResult {
+    // only owner can create sub-accounts
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    let account_factory = ACCOUNT_FACTORY
+        .get(deps.as_ref())?
+        .ok_or_else(|| StdError::generic_err("account factory not set"))?;
+
+    let create_msg = wasm_execute(
+        account_factory,
+        &AccountFactoryMsg::CreateSubAccount {
+            name,
+            description,
+            link,
+        },
+        vec![],
+    )?;
+
+    Ok(ManagerResponse::action("create_sub_account").add_message(create_msg))
+}
+
+/// Forward an execution message to the manager of a sub-account
+pub fn exec_on_sub_account(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    account_id: AccountId,
+    exec_msg: Binary,
+) -> ManagerResult {
+    // only owner can execute on sub-accounts
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    let sub_account_manager = SUB_ACCOUNTS
+        .may_load(deps.storage, account_id)?
+        .ok_or(ManagerError::SubAccountNotFound(account_id))?;
+
+    let response = ManagerResponse::new(
+        "exec_on_sub_account",
+        vec![("account_id", account_id.to_string())],
+    )
+    .add_message(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: sub_account_manager.into(),
+        msg: exec_msg,
+        funds: vec![],
+    }));
+
+    Ok(response)
+}
+
 /// Checked load of a module address
 fn load_module_addr(storage: &dyn Storage, module_id: &String) -> Result<Addr, ManagerError> {
     ACCOUNT_MODULES
@@ -223,7 +580,8 @@ pub fn uninstall_module(deps: DepsMut, msg_info: MessageInfo, module_id: String)
     let module_addr = load_module_addr(deps.storage, &module_id)?;
     let remove_from_proxy_msg =
         remove_module_from_proxy(proxy.into_string(), module_addr.into_string())?;
-    ACCOUNT_MODULES.remove(deps.storage, &module_id);
+    remove_module_address(deps.storage, &module_id)?;
+    SUBSCRIPTIONS.remove(deps.storage, &module_id);
 
     Ok(
         ManagerResponse::new("uninstall_module", vec![("module", module_id)])
@@ -231,6 +589,33 @@ pub fn uninstall_module(deps: DepsMut, msg_info: MessageInfo, module_id: String)
     )
 }
 
+/// Uninstall a module together with all the modules that depend on it.
+/// Modules are uninstalled before their dependencies.
+pub fn uninstall_module_cascade(
+    mut deps: DepsMut,
+    msg_info: MessageInfo,
+    module_id: String,
+) -> ManagerResult {
+    // only owner can uninstall modules
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    load_module_addr(deps.storage, &module_id)?;
+
+    let uninstall_order = versioning::uninstall_order(deps.storage, &module_id)?;
+
+    let mut response = ManagerResponse::new(
+        "uninstall_module_cascade",
+        vec![("modules", uninstall_order.join(","))],
+    );
+    for id in uninstall_order {
+        let uninstall_response = uninstall_module(deps.branch(), msg_info.clone(), id)?;
+        response = response
+            .add_submessages(uninstall_response.messages)
+            .add_events(uninstall_response.events);
+    }
+
+    Ok(response)
+}
+
 pub fn set_owner(
     deps: DepsMut,
     env: Env,
@@ -238,27 +623,40 @@ pub fn set_owner(
     new_owner: GovernanceDetails<String>,
 ) -> ManagerResult {
     // verify the provided governance details
-    let verified_gov = new_owner.verify(deps.api)?;
-    let new_owner_addr = verified_gov.owner_address();
-
-    // Update the account information
-    let mut acc_info = INFO.load(deps.storage)?;
+    let verified_gov = verify_governance(deps.api, new_owner)?;
+    update_governance(deps, env, &info.sender, verified_gov, "update_owner")
+}
 
-    // Check that there are changes
-    if acc_info.governance_details == verified_gov {
-        return Err(ManagerError::NoUpdates {});
+/// Verify governance details provided by the owner or guardians.
+/// Sub-account governance is only set by the account factory when it creates the sub-account.
+fn verify_governance(
+    api: &dyn Api,
+    governance: GovernanceDetails<String>,
+) -> ManagerResult<GovernanceDetails<Addr>> {
+    if matches!(governance, GovernanceDetails::SubAccount { .. }) {
+        return Err(ManagerError::SubAccountGovernance {});
     }
+    Ok(governance.verify(api)?)
+}
 
-    acc_info.governance_details = verified_gov.clone();
-    INFO.save(deps.storage, &acc_info)?;
+/// Update the governance of the Account and start the ownership transfer to the new owner.
+/// `sender` must be the current owner.
+fn update_governance(
+    mut deps: DepsMut,
+    env: Env,
+    sender: &Addr,
+    verified_gov: GovernanceDetails<Addr>,
+    action: &str,
+) -> ManagerResult {
+    let (new_owner_addr, detach_msgs) = replace_governance(deps.branch(), &env, &verified_gov)?;
 
     // Update the Owner of the Account
     let ownership = cw_ownable::update_ownership(
         deps,
         &env.block,
-        &info.sender,
+        sender,
         cw_ownable::Action::TransferOwnership {
-            new_owner: new_owner_addr.into_string(),
+            new_owner: new_owner_addr.to_string(),
             expiry: None,
         },
     )?;
@@ -266,87 +664,643 @@ pub fn set_owner(
     let mut attrs = vec![("governance_type", verified_gov.to_string()).into()];
     attrs.extend(ownership.into_attributes());
 
-    Ok(ManagerResponse::new("update_owner", attrs))
+    let mut response = ManagerResponse::new(action, attrs).add_messages(detach_msgs);
+    if new_owner_addr == env.contract.address {
+        // Accept the ownership on behalf of the multisig
+        response = response.add_message(wasm_execute(
+            env.contract.address,
+            &ExecuteMsg::UpdateOwnership(cw_ownable::Action::AcceptOwnership),
+            vec![],
+        )?);
+    }
+
+    Ok(response)
 }
 
-/// Migrate modules through address updates or contract migrations
-/// The dependency store is updated during migration
-/// A reply message is called after performing all the migrations which ensures version compatibility of the new state.
-/// Migrations are performed in-order and should be done in a top-down approach.
-pub fn upgrade_modules(
-    mut deps: DepsMut,
+/// Replace the governance details of the Account, returning the address of the new owner and the messages to detach it from its parent.
+/// A scheduled ownership transfer and the open multisig proposals are dropped as they were made for the previous governance.
+/// A sub-account is detached from its parent Account in the parent manager and version control.
+fn replace_governance(
+    deps: DepsMut,
+    env: &Env,
+    verified_gov: &GovernanceDetails<Addr>,
+) -> ManagerResult<(Addr, Vec<CosmosMsg>)> {
+    // Multisig governance is enforced by the manager itself
+    let new_owner_addr = verified_gov
+        .owner_address()
+        .unwrap_or_else(|| env.contract.address.clone());
+
+    // Update the account information
+    let mut acc_info = INFO.load(deps.storage)?;
+
+    // Check that there are changes
+    if &acc_info.governance_details == verified_gov {
+        return Err(ManagerError::NoUpdates {});
+    }
+
+    let previous_gov = std::mem::replace(&mut acc_info.governance_details, verified_gov.clone());
+    INFO.save(deps.storage, &acc_info)?;
+    PENDING_OWNER_TRANSFER.remove(deps.storage);
+    // Open proposals were approved under the previous governance
+    let next_proposal_id = NEXT_PROPOSAL_ID.may_load(deps.storage)?.unwrap_or_default();
+    FIRST_VALID_PROPOSAL_ID.save(deps.storage, &next_proposal_id)?;
+
+    let detach_msgs = match previous_gov {
+        GovernanceDetails::SubAccount {
+            manager: parent_manager,
+            ..
+        } => {
+            let account_id = ACCOUNT_ID.load(deps.storage)?;
+            let config = CONFIG.load(deps.storage)?;
+            vec![
+                wasm_execute(
+                    parent_manager,
+                    &ExecuteMsg::UpdateInternalConfig(to_binary(
+                        &InternalConfigAction::UnregisterSubAccount { account_id },
+                    )?),
+                    vec![],
+                )?
+                .into(),
+                wasm_execute(
+                    config.version_control_address,
+                    &VcExecuteMsg::RemoveAccountParent { account_id },
+                    vec![],
+                )?
+                .into(),
+            ]
+        }
+        _ => vec![],
+    };
+
+    Ok((new_owner_addr, detach_msgs))
+}
+
+/// Schedule an ownership transfer that can be executed after the delay.
+/// The transfer can be cancelled by the owner until it is executed.
+pub fn schedule_owner_transfer(
+    deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    modules: Vec<(ModuleInfo, Option<Binary>)>,
+    new_owner: GovernanceDetails<String>,
+    delay_seconds: u64,
+    expiry_seconds: Option<u64>,
 ) -> ManagerResult {
     cw_ownable::assert_owner(deps.storage, &info.sender)?;
-    ensure!(!modules.is_empty(), ManagerError::NoUpdates {});
-
-    let mut upgrade_msgs = vec![];
+    if PENDING_OWNER_TRANSFER.exists(deps.storage) {
+        return Err(ManagerError::OwnerTransferAlreadyScheduled {});
+    }
 
-    let mut manager_migrate_info = None;
+    let governance_details = verify_governance(deps.api, new_owner)?;
+    let executable_at = env.block.time.plus_seconds(delay_seconds);
+    let transfer = ScheduledOwnerTransfer {
+        governance_details,
+        executable_at,
+        expires_at: expiry_seconds.map(|expiry| executable_at.plus_seconds(expiry)),
+    };
+    PENDING_OWNER_TRANSFER.save(deps.storage, &transfer)?;
 
-    let mut upgraded_module_ids = Vec::new();
+    Ok(ManagerResponse::new(
+        "schedule_owner_transfer",
+        vec![
+            ("governance_type", transfer.governance_details.to_string()),
+            ("executable_at", executable_at.to_string()),
+        ],
+    ))
+}
 
-    // Set the migrate messages for each module that's not the manager and update the dependency store
-    for (module_info, migrate_msg) in modules {
-        let module_id = module_info.id();
+/// Cancel the scheduled ownership transfer
+pub fn cancel_owner_transfer(deps: DepsMut, info: MessageInfo) -> ManagerResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    if !PENDING_OWNER_TRANSFER.exists(deps.storage) {
+        return Err(ManagerError::NoOwnerTransferScheduled {});
+    }
+    PENDING_OWNER_TRANSFER.remove(deps.storage);
 
-        // Check for duplicates
-        if upgraded_module_ids.contains(&module_id) {
-            return Err(ManagerError::DuplicateModuleMigration { module_id });
-        } else {
-            upgraded_module_ids.push(module_id.clone());
-        }
+    Ok(ManagerResponse::action("cancel_owner_transfer"))
+}
 
-        if module_id == MANAGER {
-            manager_migrate_info = Some((module_info, migrate_msg));
-        } else {
-            set_migrate_msgs_and_context(
-                deps.branch(),
-                module_info,
-                migrate_msg,
-                &mut upgrade_msgs,
-            )?;
+/// Execute the scheduled ownership transfer once the delay has passed.
+/// Callable by the owner or the new owner.
+pub fn execute_owner_transfer(deps: DepsMut, env: Env, info: MessageInfo) -> ManagerResult {
+    let transfer = PENDING_OWNER_TRANSFER
+        .may_load(deps.storage)?
+        .ok_or(ManagerError::NoOwnerTransferScheduled {})?;
+    let owner = cw_ownable::get_ownership(deps.storage)?
+        .owner
+        .ok_or(cw_ownable::OwnershipError::NoOwner)?;
+
+    let is_new_owner = transfer.governance_details.owner_address() == Some(info.sender.clone());
+    if info.sender != owner && !is_new_owner {
+        return Err(cw_ownable::OwnershipError::NotOwner.into());
+    }
+    if env.block.time < transfer.executable_at {
+        return Err(ManagerError::OwnerTransferNotReady {
+            executable_at: transfer.executable_at,
+        });
+    }
+    if let Some(expires_at) = transfer.expires_at {
+        if env.block.time >= expires_at {
+            return Err(ManagerError::OwnerTransferExpired { expires_at });
         }
     }
 
-    // Upgrade the manager last
-    if let Some((manager_info, manager_migrate_msg)) = manager_migrate_info {
-        upgrade_msgs.push(self_upgrade_msg(
-            deps,
-            &env.contract.address,
-            manager_info,
-            manager_migrate_msg.unwrap_or_default(),
-        )?);
+    update_governance(
+        deps,
+        env,
+        &owner,
+        transfer.governance_details,
+        "execute_owner_transfer",
+    )
+}
+
+/// Set the guardians of the Account, an empty guardian list removes them.
+/// A pending recovery is cancelled, unless it is approved by the guardians.
+pub fn update_guardians(
+    deps: DepsMut,
+    info: MessageInfo,
+    guardians: Vec<String>,
+    quorum: u64,
+    delay_seconds: u64,
+) -> ManagerResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    assert_no_approved_recovery(deps.storage)?;
+    PENDING_RECOVERY.remove(deps.storage);
+
+    if guardians.is_empty() {
+        GUARDIANS.remove(deps.storage);
+        return Ok(with_abstract_event!(
+            Response::new(),
+            MANAGER,
+            "update_guardians",
+            [("guardians", "0")]
+        ));
     }
 
-    let callback_msg = wasm_execute(
-        env.contract.address,
-        &ExecuteMsg::Callback(CallbackMsg {}),
-        vec![],
+    let mut members: Vec<Addr> = Vec::with_capacity(guardians.len());
+    for guardian in guardians {
+        let guardian = deps.api.addr_validate(&guardian)?;
+        if members.contains(&guardian) {
+            return Err(ManagerError::InvalidGuardians(format!(
+                "duplicate guardian {guardian}"
+            )));
+        }
+        members.push(guardian);
+    }
+    if quorum == 0 || quorum > members.len() as u64 {
+        return Err(ManagerError::InvalidGuardians(format!(
+            "quorum must be between 1 and {}",
+            members.len()
+        )));
+    }
+    let guardian_count = members.len();
+    GUARDIANS.save(
+        deps.storage,
+        &Guardians {
+            members,
+            quorum,
+            delay: delay_seconds,
+        },
     )?;
-    Ok(ManagerResponse::new(
-        "upgrade_modules",
-        vec![("upgraded_modules", upgraded_module_ids.join(","))],
-    )
-    .add_messages(upgrade_msgs)
-    .add_message(callback_msg))
+
+    Ok(with_abstract_event!(
+        Response::new(),
+        MANAGER,
+        "update_guardians",
+        [
+            ("guardians", guardian_count.to_string()),
+            ("quorum", quorum.to_string()),
+            ("delay", delay_seconds.to_string())
+        ]
+    ))
 }
 
-pub fn set_migrate_msgs_and_context(
+/// Propose to transfer the Account to a new owner, the proposer's approval is recorded directly.
+pub fn propose_recovery(
     deps: DepsMut,
-    module_info: ModuleInfo,
-    migrate_msg: Option<Binary>,
-    msgs: &mut Vec<CosmosMsg>,
-) -> Result<(), ManagerError> {
-    let old_module_addr = load_module_addr(deps.storage, &module_info.id())?;
-    let old_module_cw2 = query_module_cw2(&deps.as_ref(), old_module_addr.clone())?;
-    let requested_module = query_module(deps.as_ref(), module_info.clone(), Some(old_module_cw2))?;
+    env: Env,
+    info: MessageInfo,
+    new_owner: GovernanceDetails<String>,
+) -> ManagerResult {
+    let guardians = assert_guardian(deps.storage, &info.sender)?;
+    if PENDING_RECOVERY.exists(deps.storage) {
+        return Err(ManagerError::RecoveryAlreadyProposed {});
+    }
 
-    let migrate_msgs = match requested_module.module.reference {
-        // upgrading an adapter is done by moving the authorized addresses to the new contract address and updating the permissions on the proxy.
-        ModuleReference::Adapter(new_adapter_addr) => handle_adapter_migration(
+    let mut recovery = Recovery {
+        governance_details: verify_governance(deps.api, new_owner)?,
+        approvals: vec![],
+        executable_at: None,
+    };
+    approve(&env, &guardians, &mut recovery, info.sender.clone());
+    PENDING_RECOVERY.save(deps.storage, &recovery)?;
+
+    Ok(with_abstract_event!(
+        Response::new(),
+        MANAGER,
+        "propose_recovery",
+        [
+            ("guardian", info.sender.into_string()),
+            ("governance_type", recovery.governance_details.to_string())
+        ]
+    ))
+}
+
+/// Approve the pending recovery
+pub fn approve_recovery(deps: DepsMut, env: Env, info: MessageInfo) -> ManagerResult {
+    let guardians = assert_guardian(deps.storage, &info.sender)?;
+    let mut recovery = PENDING_RECOVERY
+        .may_load(deps.storage)?
+        .ok_or(ManagerError::NoRecoveryProposed {})?;
+    if recovery.approvals.contains(&info.sender) {
+        return Err(ManagerError::AlreadyApprovedRecovery(
+            info.sender.into_string(),
+        ));
+    }
+
+    approve(&env, &guardians, &mut recovery, info.sender.clone());
+    PENDING_RECOVERY.save(deps.storage, &recovery)?;
+
+    Ok(with_abstract_event!(
+        Response::new(),
+        MANAGER,
+        "approve_recovery",
+        [
+            ("guardian", info.sender.into_string()),
+            ("approvals", recovery.approvals.len().to_string())
+        ]
+    ))
+}
+
+/// Record the guardian's approval and start the delay once the quorum is reached
+fn approve(env: &Env, guardians: &Guardians, recovery: &mut Recovery, guardian: Addr) {
+    recovery.approvals.push(guardian);
+    if recovery.executable_at.is_none() && recovery.approvals.len() as u64 >= guardians.quorum {
+        recovery.executable_at = Some(env.block.time.plus_seconds(guardians.delay));
+    }
+}
+
+/// Execute the pending recovery, which transfers the Account to the new owner and lifts its suspension.
+/// The new owner is set directly as the previous owner may be compromised.
+pub fn execute_recovery(mut deps: DepsMut, env: Env, info: MessageInfo) -> ManagerResult {
+    let guardians = assert_guardian(deps.storage, &info.sender)?;
+    let recovery = PENDING_RECOVERY
+        .may_load(deps.storage)?
+        .ok_or(ManagerError::NoRecoveryProposed {})?;
+    let Some(executable_at) = recovery.executable_at else {
+        return Err(ManagerError::RecoveryQuorumNotMet {
+            approvals: recovery.approvals.len() as u64,
+            quorum: guardians.quorum,
+        });
+    };
+    if env.block.time < executable_at {
+        return Err(ManagerError::RecoveryNotReady { executable_at });
+    }
+
+    PENDING_RECOVERY.remove(deps.storage);
+    SUSPENSION_STATUS.save(deps.storage, &false)?;
+
+    let (new_owner_addr, detach_msgs) =
+        replace_governance(deps.branch(), &env, &recovery.governance_details)?;
+    let ownership = cw_ownable::Ownership {
+        owner: Some(new_owner_addr),
+        pending_owner: None,
+        pending_expiry: None,
+    };
+    OWNER.save(deps.storage, &ownership)?;
+
+    let mut attrs = vec![
+        ("governance_type", recovery.governance_details.to_string()).into(),
+        ("guardian", info.sender.into_string()).into(),
+    ];
+    attrs.extend(ownership.into_attributes());
+
+    Ok(ManagerResponse::new("execute_recovery", attrs).add_messages(detach_msgs))
+}
+
+/// Cancel the pending recovery, which is no longer possible once the guardians approved it
+pub fn cancel_recovery(deps: DepsMut, info: MessageInfo) -> ManagerResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    assert_no_approved_recovery(deps.storage)?;
+    if !PENDING_RECOVERY.exists(deps.storage) {
+        return Err(ManagerError::NoRecoveryProposed {});
+    }
+    PENDING_RECOVERY.remove(deps.storage);
+
+    Ok(with_abstract_event!(
+        Response::new(),
+        MANAGER,
+        "cancel_recovery"
+    ))
+}
+
+/// Asserts that a recovery is pending and approved by the guardians' quorum
+fn assert_recovery_approved(storage: &dyn Storage) -> ManagerResult<()> {
+    let guardians = GUARDIANS.load(storage)?;
+    let approvals = PENDING_RECOVERY
+        .may_load(storage)?
+        .map(|recovery| recovery.approvals.len() as u64)
+        .unwrap_or_default();
+    if approvals < guardians.quorum {
+        return Err(ManagerError::RecoveryQuorumNotMet {
+            approvals,
+            quorum: guardians.quorum,
+        });
+    }
+    Ok(())
+}
+
+/// Asserts that no recovery approved by the guardians' quorum is pending, which the owner can't undo
+fn assert_no_approved_recovery(storage: &dyn Storage) -> ManagerResult<()> {
+    let approved = PENDING_RECOVERY
+        .may_load(storage)?
+        .map_or(false, |recovery| recovery.executable_at.is_some());
+    if approved {
+        return Err(ManagerError::RecoveryApproved {});
+    }
+    Ok(())
+}
+
+/// Loads the guardians, erroring if the address is not one of them
+fn assert_guardian(storage: &dyn Storage, addr: &Addr) -> ManagerResult<Guardians> {
+    match GUARDIANS.may_load(storage)? {
+        Some(guardians) if guardians.members.contains(addr) => Ok(guardians),
+        _ => Err(ManagerError::NotGuardian(addr.to_string())),
+    }
+}
+
+/// Create a multisig proposal, the proposer's approval is recorded directly.
+pub fn propose(
+    deps: DepsMut,
+    info: MessageInfo,
+    description: String,
+    actions: Vec<ProposalAction>,
+) -> ManagerResult {
+    let governance = INFO.load(deps.storage)?.governance_details;
+    assert_multisig_member(&governance, &info.sender)?;
+    if actions.is_empty() {
+        return Err(ManagerError::EmptyProposal {});
+    }
+
+    let proposal_id = NEXT_PROPOSAL_ID.may_load(deps.storage)?.unwrap_or_default();
+    PROPOSALS.save(
+        deps.storage,
+        proposal_id,
+        &Proposal {
+            proposer: info.sender.clone(),
+            description,
+            actions,
+            approvals: vec![info.sender],
+            executed: false,
+        },
+    )?;
+    NEXT_PROPOSAL_ID.save(deps.storage, &(proposal_id + 1))?;
+
+    Ok(ManagerResponse::new(
+        "propose",
+        vec![("proposal_id", proposal_id.to_string())],
+    ))
+}
+
+/// Approve a multisig proposal
+pub fn vote(deps: DepsMut, info: MessageInfo, proposal_id: u64) -> ManagerResult {
+    let governance = INFO.load(deps.storage)?.governance_details;
+    assert_multisig_member(&governance, &info.sender)?;
+
+    let mut proposal = load_open_proposal(deps.storage, proposal_id)?;
+    if proposal.approvals.contains(&info.sender) {
+        return Err(ManagerError::AlreadyVoted(
+            info.sender.into_string(),
+            proposal_id,
+        ));
+    }
+    proposal.approvals.push(info.sender.clone());
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(ManagerResponse::new(
+        "vote",
+        vec![
+            ("proposal_id", proposal_id.to_string()),
+            ("voter", info.sender.into_string()),
+        ],
+    ))
+}
+
+/// Execute a multisig proposal that reached its threshold.
+/// The proposal's actions are dispatched by the manager to itself as the Account owner.
+pub fn execute_proposal(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> ManagerResult {
+    let governance = INFO.load(deps.storage)?.governance_details;
+    assert_multisig_member(&governance, &info.sender)?;
+
+    let mut proposal = load_open_proposal(deps.storage, proposal_id)?;
+    let (approved, threshold) = proposal_weight(&governance, &proposal.approvals);
+    if approved < threshold {
+        return Err(ManagerError::ProposalThresholdNotMet {
+            proposal_id,
+            approved,
+            threshold,
+        });
+    }
+
+    ensure!(
+        total_funds(&info.funds)? == total_funds(proposal.actions.iter().flat_map(|a| &a.funds))?,
+        ManagerError::ProposalFundsMismatch(proposal_id)
+    );
+
+    let msgs = proposal
+        .actions
+        .iter()
+        .map(|action| wasm_execute(&env.contract.address, &action.msg, action.funds.clone()))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    proposal.executed = true;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(ManagerResponse::new(
+        "execute_proposal",
+        vec![("proposal_id", proposal_id.to_string())],
+    )
+    .add_messages(msgs))
+}
+
+/// Load a proposal that can still be voted on and executed
+fn load_open_proposal(storage: &dyn Storage, proposal_id: u64) -> ManagerResult<Proposal> {
+    let proposal = PROPOSALS.load(storage, proposal_id)?;
+    if proposal.executed {
+        return Err(ManagerError::ProposalExecuted(proposal_id));
+    }
+    if proposal_id
+        < FIRST_VALID_PROPOSAL_ID
+            .may_load(storage)?
+            .unwrap_or_default()
+    {
+        return Err(ManagerError::ProposalInvalidated(proposal_id));
+    }
+    Ok(proposal)
+}
+
+/// Sum of the coins per denom, sorted by denom
+fn total_funds<'a>(coins: impl IntoIterator<Item = &'a Coin>) -> StdResult<Vec<Coin>> {
+    let mut total: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for coin in coins {
+        let amount = total.entry(coin.denom.as_str()).or_default();
+        *amount = amount.checked_add(coin.amount)?;
+    }
+    Ok(total
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| Coin::new(amount.u128(), denom))
+        .collect())
+}
+
+fn assert_multisig_member(
+    governance: &GovernanceDetails<Addr>,
+    sender: &Addr,
+) -> Result<(), ManagerError> {
+    if !matches!(governance, GovernanceDetails::Multisig { .. }) {
+        return Err(ManagerError::NotMultisig {});
+    }
+    if governance.member_weight(sender).is_none() {
+        return Err(ManagerError::NotMultisigMember(sender.to_string()));
+    }
+    Ok(())
+}
+
+/// Returns the weight of the approvals by current members and the multisig threshold.
+pub(crate) fn proposal_weight(
+    governance: &GovernanceDetails<Addr>,
+    approvals: &[Addr],
+) -> (u64, u64) {
+    let approved: u64 = approvals
+        .iter()
+        .filter_map(|voter| governance.member_weight(voter))
+        .sum();
+    let threshold = match governance {
+        GovernanceDetails::Multisig { threshold, .. } => *threshold,
+        _ => 0,
+    };
+    (approved, threshold)
+}
+
+/// Migrate modules through address updates or contract migrations
+/// The dependency store is updated during migration
+/// A reply message is called after performing all the migrations which ensures version compatibility of the new state.
+/// Migrations are performed in-order and should be done in a top-down approach.
+pub fn upgrade_modules(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    modules: Vec<(ModuleInfo, Option<Binary>)>,
+) -> ManagerResult {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    ensure!(!modules.is_empty(), ManagerError::NoUpdates {});
+
+    let mut upgrade_msgs = vec![];
+
+    let mut manager_migrate_info = None;
+
+    let mut upgraded_module_ids = Vec::new();
+
+    let mut deprecation_warnings = Vec::new();
+
+    // Set the migrate messages for each module that's not the manager and update the dependency store
+    for (module_info, migrate_msg) in modules {
+        let module_id = module_info.id();
+
+        // Check for duplicates
+        if upgraded_module_ids.contains(&module_id) {
+            return Err(ManagerError::DuplicateModuleMigration { module_id });
+        } else {
+            upgraded_module_ids.push(module_id.clone());
+        }
+
+        if module_id == MANAGER {
+            manager_migrate_info = Some((module_info, migrate_msg));
+        } else {
+            if let Some(warning) = set_migrate_msgs_and_context(
+                deps.branch(),
+                module_info,
+                migrate_msg,
+                &mut upgrade_msgs,
+            )? {
+                deprecation_warnings.push(warning);
+            }
+        }
+    }
+
+    // Within a batch the batch callback asserts the migrations after all the actions are executed,
+    // an earlier callback would assert the modules installed in the batch before their dependencies are.
+    let in_batch = INSTALL_CONTEXT.may_load(deps.storage)?.is_some();
+
+    // Upgrade the manager last
+    if let Some((manager_info, manager_migrate_msg)) = manager_migrate_info {
+        upgrade_msgs.push(self_upgrade_msg(
+            deps,
+            &env.contract.address,
+            manager_info,
+            manager_migrate_msg.unwrap_or_default(),
+        )?);
+    }
+
+    let mut attributes = vec![("upgraded_modules", upgraded_module_ids.join(","))];
+    if !deprecation_warnings.is_empty() {
+        attributes.push(("deprecation_warnings", deprecation_warnings.join(";")));
+    }
+    let mut response =
+        ManagerResponse::new("upgrade_modules", attributes).add_messages(upgrade_msgs);
+    if !in_batch {
+        let callback_msg = wasm_execute(
+            env.contract.address,
+            &ExecuteMsg::Callback(CallbackMsg {}),
+            vec![],
+        )?;
+        response = response.add_message(callback_msg);
+    }
+    Ok(response)
+}
+
+/// Returns a warning if the requested module version is deprecated
+pub fn set_migrate_msgs_and_context(
+    deps: DepsMut,
+    module_info: ModuleInfo,
+    migrate_msg: Option<Binary>,
+    msgs: &mut Vec<CosmosMsg>,
+) -> Result<Option<String>, ManagerError> {
+    let old_module_addr = load_module_addr(deps.storage, &module_info.id())?;
+    let old_module_cw2 = query_module_cw2(&deps.as_ref(), old_module_addr.clone())?;
+    let requested_module = query_module(deps.as_ref(), module_info.clone(), Some(old_module_cw2))?;
+    // the Account's own contracts can always be upgraded
+    if !matches!(
+        requested_module.module.reference,
+        ModuleReference::AccountBase(_)
+    ) {
+        assert_install_allowed(deps.storage, &requested_module.module.info)?;
+    }
+    let warning = requested_module
+        .config
+        .deprecation
+        .as_ref()
+        .map(|deprecation| deprecation_warning(&requested_module.module.info, deprecation));
+
+    // carry the subscription over to the new version, re-priced
+    if matches!(
+        requested_module.module.reference,
+        ModuleReference::App(_) | ModuleReference::Adapter(_)
+    ) {
+        sync_subscription(
+            deps.storage,
+            &module_info.id(),
+            requested_module.config.monetization.clone(),
+        )?;
+    }
+
+    let migrate_msgs = match requested_module.module.reference {
+        // upgrading an adapter is done by moving the authorized addresses to the new contract address and updating the permissions on the proxy.
+        ModuleReference::Adapter(new_adapter_addr) => handle_adapter_migration(
             deps,
             requested_module.module.info,
             old_module_addr,
@@ -370,7 +1324,24 @@ pub fn set_migrate_msgs_and_context(
         _ => return Err(ManagerError::NotUpgradeable(module_info)),
     };
     msgs.extend(migrate_msgs);
-    Ok(())
+    Ok(warning)
+}
+
+/// Describes why a module version is deprecated and what to use instead
+fn deprecation_warning(module_info: &ModuleInfo, deprecation: &ModuleDeprecation) -> String {
+    match &deprecation.successor {
+        Some(successor) => format!(
+            "{} is deprecated in favor of {}: {}",
+            module_info.id_with_version(),
+            successor.id_with_version(),
+            deprecation.reason
+        ),
+        None => format!(
+            "{} is deprecated: {}",
+            module_info.id_with_version(),
+            deprecation.reason
+        ),
+    }
 }
 
 /// Handle Adapter module migration and return the migration messages
@@ -452,7 +1423,8 @@ fn build_module_migrate_msg(module_addr: Addr, new_code_id: u64, migrate_msg: Bi
 }
 
 /// Replaces the current adapter with a different version
-/// Also moves all the authorized address permissions to the new contract and removes them from the old
+/// Also moves all the authorized address permissions to the new contract and removes them from the old,
+/// and carries the adapter's permission scopes on the proxy over to the new contract
 pub fn replace_adapter(
     deps: DepsMut,
     new_adapter_addr: Addr,
@@ -469,6 +1441,9 @@ pub fn replace_adapter(
             proxy_address: proxy_addr.to_string(),
         }),
     )?)?;
+    let permissions = MODULE_PERMISSIONS
+        .query(&deps.querier, proxy_addr.clone(), &old_adapter_addr)?
+        .map(ModulePermissionsUnchecked::from);
     let authorized_to_migrate: Vec<String> = authorized_addresses
         .into_iter()
         .map(|addr| addr.into_string())
@@ -503,6 +1478,7 @@ pub fn replace_adapter(
     msgs.push(add_module_to_proxy(
         proxy_addr.into_string(),
         new_adapter_addr.into_string(),
+        permissions,
     )?);
 
     Ok(msgs)
@@ -537,14 +1513,78 @@ pub fn update_suspension_status(
     is_suspended: SuspensionStatus,
     response: Response,
 ) -> ManagerResult {
-    // only owner can update suspension status
-    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    // only owner can update suspension status,
+    // guardians can freeze the Account once a recovery is approved by their quorum
+    let is_guardian_freeze =
+        is_suspended && assert_guardian(deps.storage, &msg_info.sender).is_ok();
+    if is_guardian_freeze {
+        assert_recovery_approved(deps.storage)?;
+    } else {
+        cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+        // the freeze lasts until the approved recovery is executed
+        if !is_suspended {
+            assert_no_approved_recovery(deps.storage)?;
+        }
+    }
 
     SUSPENSION_STATUS.save(deps.storage, &is_suspended)?;
 
     Ok(response.add_abstract_attributes(vec![("is_suspended", is_suspended.to_string())]))
 }
 
+pub fn update_install_policy(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    install_policy: InstallPolicy,
+    response: Response,
+) -> ManagerResult {
+    // only owner can update the install policy
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    if let Some(namespaces) = &install_policy.allowed_namespaces {
+        for namespace in namespaces {
+            namespace.validate()?;
+        }
+    }
+    for module in &install_policy.denied_modules {
+        module.validate()?;
+    }
+    let allowed_namespaces = install_policy.allowed_namespaces.as_ref().map_or_else(
+        || "all".to_string(),
+        |namespaces| {
+            namespaces
+                .iter()
+                .map(Namespace::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        },
+    );
+    let denied_modules = install_policy
+        .denied_modules
+        .iter()
+        .map(ModuleInfo::id_with_version)
+        .collect::<Vec<_>>()
+        .join(",");
+    INSTALL_POLICY.save(deps.storage, &install_policy)?;
+
+    Ok(response.add_abstract_attributes(vec![
+        ("allowed_namespaces", allowed_namespaces),
+        ("denied_modules", denied_modules),
+    ]))
+}
+
+/// Assert the module version is allowed by the install policy of the Account
+fn assert_install_allowed(storage: &dyn Storage, module_info: &ModuleInfo) -> ManagerResult<()> {
+    let allowed = INSTALL_POLICY
+        .may_load(storage)?
+        .map_or(true, |policy| policy.allows(module_info));
+    ensure!(
+        allowed,
+        ManagerError::ModuleNotAllowed(module_info.id_with_version())
+    );
+    Ok(())
+}
+
 pub fn update_ibc_status(
     deps: DepsMut,
     msg_info: MessageInfo,
@@ -583,16 +1623,17 @@ fn install_ibc_client(deps: DepsMut, proxy: Addr) -> Result<CosmosMsg, ManagerEr
 
     let ibc_client_addr = ibc_client_module.module.reference.unwrap_native()?;
 
-    ACCOUNT_MODULES.save(deps.storage, IBC_CLIENT, &ibc_client_addr)?;
+    save_module_address(deps.storage, IBC_CLIENT, &ibc_client_addr)?;
 
     Ok(add_module_to_proxy(
         proxy.into_string(),
         ibc_client_addr.to_string(),
+        None,
     )?)
 }
 
 fn uninstall_ibc_client(deps: DepsMut, proxy: Addr, ibc_client: Addr) -> StdResult<CosmosMsg> {
-    ACCOUNT_MODULES.remove(deps.storage, IBC_CLIENT);
+    remove_module_address(deps.storage, IBC_CLIENT)?;
 
     remove_module_from_proxy(proxy.into_string(), ibc_client.into_string())
 }
@@ -610,17 +1651,7 @@ fn query_module(
 
     let module = match &module_info.version {
         ModuleVersion::Version(new_version) => {
-            let old_contract = old_contract_cw2.unwrap();
-
-            let new_version = new_version.parse::<Version>().unwrap();
-            let old_version = old_contract.version.parse::<Version>().unwrap();
-
-            if new_version < old_version {
-                return Err(ManagerError::OlderVersion(
-                    new_version.to_string(),
-                    old_version.to_string(),
-                ));
-            }
+            assert_not_older(new_version, old_contract_cw2.unwrap())?;
 
             Module {
                 info: module_info.clone(),
@@ -631,6 +1662,14 @@ fn query_module(
             // Query latest version of contract
             version_registry.query_module(module_info.clone())?
         }
+        ModuleVersion::Range(_) => {
+            // Version control resolves the highest registered version in range
+            let module = version_registry.query_module(module_info.clone())?;
+            if let Some(old_contract) = old_contract_cw2 {
+                assert_not_older(&module.info.version.to_string(), old_contract)?;
+            }
+            module
+        }
     };
 
     Ok(ModuleResponse {
@@ -645,6 +1684,19 @@ fn query_module(
     })
 }
 
+fn assert_not_older(new_version: &str, old_contract: ContractVersion) -> ManagerResult<()> {
+    let new_version = new_version.parse::<Version>().unwrap();
+    let old_version = old_contract.version.parse::<Version>().unwrap();
+
+    if new_version < old_version {
+        return Err(ManagerError::OlderVersion(
+            new_version.to_string(),
+            old_version.to_string(),
+        ));
+    }
+    Ok(())
+}
+
 fn self_upgrade_msg(
     deps: DepsMut,
     self_addr: &Addr,
@@ -668,11 +1720,13 @@ fn self_upgrade_msg(
 fn add_module_to_proxy(
     proxy_address: String,
     module_address: String,
+    permissions: Option<ModulePermissionsUnchecked>,
 ) -> StdResult<CosmosMsg<Empty>> {
     Ok(wasm_execute(
         proxy_address,
         &ProxyMsg::AddModule {
             module: module_address,
+            permissions,
         },
         vec![],
     )?
@@ -730,6 +1784,34 @@ pub fn update_internal_config(deps: DepsMut, info: MessageInfo, config: Binary)
                 .or_else(|_| cw_ownable::assert_owner(deps.storage, &info.sender))?;
             update_module_addresses(deps, to_add, to_remove)
         }
+        InternalConfigAction::RegisterSubAccount {
+            account_id,
+            manager,
+        } => {
+            // only Account Factory can register sub-accounts
+            ACCOUNT_FACTORY.assert_admin(deps.as_ref(), &info.sender)?;
+            let manager = deps.api.addr_validate(&manager)?;
+            SUB_ACCOUNTS.save(deps.storage, account_id, &manager)?;
+            Ok(ManagerResponse::new(
+                "register_sub_account",
+                vec![("account_id", account_id.to_string())],
+            ))
+        }
+        InternalConfigAction::UnregisterSubAccount { account_id } => {
+            // only the sub-account itself can unregister
+            let manager = SUB_ACCOUNTS
+                .may_load(deps.storage, account_id)?
+                .ok_or(ManagerError::SubAccountNotFound(account_id))?;
+            ensure!(
+                info.sender == manager,
+                ManagerError::NotSubAccountManager(info.sender.into_string(), account_id)
+            );
+            SUB_ACCOUNTS.remove(deps.storage, account_id);
+            Ok(ManagerResponse::new(
+                "unregister_sub_account",
+                vec![("account_id", account_id.to_string())],
+            ))
+        }
         _ => Err(ManagerError::InvalidConfigAction {
             error: StdError::generic_err("Unknown config action"),
         }),
@@ -745,6 +1827,10 @@ mod tests {
     use cosmwasm_std::{Order, OwnedDeps, StdError, Storage};
 
     use crate::contract;
+    use abstract_core::objects::module::ModuleMetadata;
+    use abstract_core::version_control::{
+        ModuleConfiguration, ModulesResponse, NamespaceResponse, QueryMsg as VcQueryMsg,
+    };
     use speculoos::prelude::*;
 
     use super::*;
@@ -800,6 +1886,62 @@ mod tests {
 
     type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
 
+    /// Version control querier that returns the provided monetization for every module
+    /// Mocks the version control and the module data of the test module at `module_addr`
+    fn mock_version_control_querier(monetization: Monetization) -> MockQuerier {
+        mock_version_control_querier_with_config(ModuleConfiguration::new(
+            monetization,
+            ModuleMetadata::default(),
+        ))
+    }
+
+    /// Version control querier that returns the provided configuration for every module
+    fn mock_version_control_querier_with_config(config: ModuleConfiguration) -> MockQuerier {
+        MockQuerierBuilder::default()
+            .with_contract_item(
+                "module_addr",
+                cw2::CONTRACT,
+                &ContractVersion {
+                    contract: "test:module".to_string(),
+                    version: "1.0.0".to_string(),
+                },
+            )
+            .with_contract_item(
+                "module_addr",
+                abstract_core::objects::module_version::MODULE,
+                &abstract_core::objects::module_version::ModuleData {
+                    module: "test:module".to_string(),
+                    version: "1.0.0".to_string(),
+                    dependencies: vec![],
+                    metadata: None,
+                },
+            )
+            .with_smart_handler(TEST_VERSION_CONTROL, move |msg| {
+                match from_binary(msg).unwrap() {
+                    VcQueryMsg::Modules { infos } => to_binary(&ModulesResponse {
+                        modules: infos
+                            .into_iter()
+                            .map(|info| ModuleResponse {
+                                module: Module {
+                                    info,
+                                    reference: ModuleReference::App(1),
+                                },
+                                config: config.clone(),
+                            })
+                            .collect(),
+                    }),
+                    VcQueryMsg::Namespace { .. } => to_binary(&NamespaceResponse {
+                        account_id: TEST_ACCOUNT_ID,
+                        account_base: test_account_base(),
+                        expires_at: None,
+                    }),
+                    _ => panic!("unexpected version control query"),
+                }
+                .map_err(|e| e.to_string())
+            })
+            .build()
+    }
+
     mod set_owner_and_gov_type {
         use super::*;
 
@@ -876,324 +2018,1729 @@ mod tests {
             execute_as_owner(deps.as_mut(), msg)?;
 
             let actual_info = INFO.load(deps.as_ref().storage)?;
-            assert_that(&actual_info.governance_details.owner_address().to_string())
-                .is_equal_to("new_gov".to_string());
+            assert_that(
+                &actual_info
+                    .governance_details
+                    .owner_address()
+                    .unwrap()
+                    .to_string(),
+            )
+            .is_equal_to("new_gov".to_string());
 
             Ok(())
         }
     }
 
-    mod update_module_addresses {
+    mod scheduled_owner_transfer {
         use super::*;
-        use abstract_core::manager::InternalConfigAction;
+
+        const NEW_OWNER: &str = "new_owner";
+        const DELAY: u64 = 100;
+
+        fn schedule_msg(expiry_seconds: Option<u64>) -> ExecuteMsg {
+            ExecuteMsg::ScheduleOwnerTransfer {
+                owner: GovernanceDetails::Monarchy {
+                    monarch: NEW_OWNER.to_string(),
+                },
+                delay_seconds: DELAY,
+                expiry_seconds,
+            }
+        }
+
+        fn execute_at(deps: DepsMut, sender: &str, seconds_passed: u64) -> ManagerResult {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds_passed);
+            contract::execute(
+                deps,
+                env,
+                mock_info(sender, &[]),
+                ExecuteMsg::ExecuteOwnerTransfer {},
+            )
+        }
 
         #[test]
-        fn manual_adds_module_to_account_modules() -> ManagerTestResult {
+        fn only_owner() -> ManagerTestResult {
+            test_only_owner(schedule_msg(None))?;
+            test_only_owner(ExecuteMsg::CancelOwnerTransfer {})
+        }
+
+        #[test]
+        fn executes_after_delay() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            mock_init(deps.as_mut()).unwrap();
+            mock_init(deps.as_mut())?;
 
-            let to_add: Vec<(String, String)> = vec![
-                ("test:module1".to_string(), "module1_addr".to_string()),
-                ("test:module2".to_string(), "module2_addr".to_string()),
-            ];
+            execute_as_owner(deps.as_mut(), schedule_msg(None))?;
+            let transfer = PENDING_OWNER_TRANSFER.load(&deps.storage)?;
+            assert_that!(transfer.executable_at)
+                .is_equal_to(mock_env().block.time.plus_seconds(DELAY));
 
-            let res = update_module_addresses(deps.as_mut(), Some(to_add.clone()), Some(vec![]));
-            assert_that(&res).is_ok();
+            let res = execute_at(deps.as_mut(), TEST_OWNER, DELAY - 1);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::OwnerTransferNotReady {
+                    executable_at: transfer.executable_at,
+                });
 
-            let actual_modules = load_account_modules(&deps.storage)?;
+            let res = execute_at(deps.as_mut(), "not_owner", DELAY);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::Ownership(OwnershipError::NotOwner));
 
-            speculoos::prelude::VecAssertions::has_length(
-                &mut assert_that(&actual_modules),
-                to_add.len(),
-            );
-            for (module_id, addr) in to_add {
-                speculoos::iter::ContainingIntoIterAssertions::contains(
-                    &mut assert_that(&actual_modules),
-                    &(module_id, Addr::unchecked(addr)),
-                );
-            }
+            // the new owner can execute the transfer
+            execute_at(deps.as_mut(), NEW_OWNER, DELAY)?;
+            assert_that!(PENDING_OWNER_TRANSFER.may_load(&deps.storage)?).is_none();
 
+            let info = INFO.load(&deps.storage)?;
+            assert_that!(info.governance_details.owner_address())
+                .is_equal_to(Some(Addr::unchecked(NEW_OWNER)));
+            let ownership = cw_ownable::get_ownership(&deps.storage)?;
+            assert_that!(ownership.pending_owner).is_equal_to(Some(Addr::unchecked(NEW_OWNER)));
             Ok(())
         }
 
         #[test]
-        fn missing_id() -> ManagerTestResult {
+        fn only_one_scheduled() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            mock_init(deps.as_mut()).unwrap();
-
-            let to_add: Vec<(String, String)> = vec![("".to_string(), "module1_addr".to_string())];
+            mock_init(deps.as_mut())?;
 
-            let res = update_module_addresses(deps.as_mut(), Some(to_add), Some(vec![]));
-            assert_that(&res)
+            execute_as_owner(deps.as_mut(), schedule_msg(None))?;
+            let res = execute_as_owner(deps.as_mut(), schedule_msg(None));
+            assert_that!(res)
                 .is_err()
-                .is_equal_to(ManagerError::InvalidModuleName {});
-
+                .is_equal_to(ManagerError::OwnerTransferAlreadyScheduled {});
             Ok(())
         }
 
         #[test]
-        fn manual_removes_module_from_account_modules() -> ManagerTestResult {
+        fn can_be_cancelled() -> ManagerTestResult {
             let mut deps = mock_dependencies();
             mock_init(deps.as_mut())?;
 
-            // manually add module
-            ACCOUNT_MODULES.save(
-                &mut deps.storage,
-                "test:module",
-                &Addr::unchecked("test_module_addr"),
-            )?;
+            execute_as_owner(deps.as_mut(), schedule_msg(None))?;
+            execute_as_owner(deps.as_mut(), ExecuteMsg::CancelOwnerTransfer {})?;
 
-            let to_remove: Vec<String> = vec!["test:module".to_string()];
+            let res = execute_at(deps.as_mut(), TEST_OWNER, DELAY);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::NoOwnerTransferScheduled {});
+            Ok(())
+        }
 
-            let res = update_module_addresses(deps.as_mut(), Some(vec![]), Some(to_remove));
-            assert_that(&res).is_ok();
+        #[test]
+        fn expires() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
 
-            let actual_modules = load_account_modules(&deps.storage)?;
+            execute_as_owner(deps.as_mut(), schedule_msg(Some(10)))?;
 
-            speculoos::prelude::VecAssertions::is_empty(&mut assert_that(&actual_modules));
+            let res = execute_at(deps.as_mut(), TEST_OWNER, DELAY + 10);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::OwnerTransferExpired {
+                    expires_at: mock_env().block.time.plus_seconds(DELAY + 10),
+                });
 
+            execute_at(deps.as_mut(), TEST_OWNER, DELAY + 9)?;
             Ok(())
         }
 
         #[test]
-        fn disallows_removing_proxy() -> ManagerTestResult {
+        fn dropped_when_governance_changes() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            init_with_proxy(&mut deps);
+            mock_init(deps.as_mut())?;
 
-            let to_remove: Vec<String> = vec![PROXY.to_string()];
+            execute_as_owner(deps.as_mut(), schedule_msg(None))?;
+            execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::SetOwner {
+                    owner: GovernanceDetails::Monarchy {
+                        monarch: "third_party".to_string(),
+                    },
+                },
+            )?;
+            assert_that!(PENDING_OWNER_TRANSFER.may_load(&deps.storage)?).is_none();
 
-            let res = update_module_addresses(deps.as_mut(), Some(vec![]), Some(to_remove));
-            assert_that(&res)
+            let res = execute_at(deps.as_mut(), NEW_OWNER, DELAY);
+            assert_that!(res)
                 .is_err()
-                .is_equal_to(ManagerError::CannotRemoveProxy {});
+                .is_equal_to(ManagerError::NoOwnerTransferScheduled {});
+            Ok(())
+        }
+    }
 
+    mod guardian_recovery {
+        use super::*;
+
+        const GUARDIAN_1: &str = "guardian_1";
+        const GUARDIAN_2: &str = "guardian_2";
+        const GUARDIAN_3: &str = "guardian_3";
+        const NEW_OWNER: &str = "new_owner";
+        const DELAY: u64 = 100;
+
+        fn init_guardians(deps: &mut MockDeps) -> ManagerTestResult {
+            mock_init(deps.as_mut())?;
+            execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateGuardians {
+                    guardians: vec![
+                        GUARDIAN_1.to_string(),
+                        GUARDIAN_2.to_string(),
+                        GUARDIAN_3.to_string(),
+                    ],
+                    quorum: 2,
+                    delay_seconds: DELAY,
+                },
+            )?;
             Ok(())
         }
 
+        fn propose_msg() -> ExecuteMsg {
+            ExecuteMsg::ProposeRecovery {
+                owner: GovernanceDetails::Monarchy {
+                    monarch: NEW_OWNER.to_string(),
+                },
+            }
+        }
+
+        fn execute_at(
+            deps: DepsMut,
+            sender: &str,
+            seconds_passed: u64,
+            msg: ExecuteMsg,
+        ) -> ManagerResult {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds_passed);
+            contract::execute(deps, env, mock_info(sender, &[]), msg)
+        }
+
         #[test]
-        fn only_account_factory_or_owner() -> ManagerTestResult {
+        fn only_owner() -> ManagerTestResult {
+            test_only_owner(ExecuteMsg::UpdateGuardians {
+                guardians: vec![GUARDIAN_1.to_string()],
+                quorum: 1,
+                delay_seconds: DELAY,
+            })?;
+            test_only_owner(ExecuteMsg::CancelRecovery {})
+        }
+
+        #[test]
+        fn validates_guardians() -> ManagerTestResult {
             let mut deps = mock_dependencies();
             mock_init(deps.as_mut())?;
 
-            let action = InternalConfigAction::UpdateModuleAddresses {
-                to_add: None,
-                to_remove: None,
+            let update = |guardians: Vec<&str>, quorum: u64| ExecuteMsg::UpdateGuardians {
+                guardians: guardians.into_iter().map(String::from).collect(),
+                quorum,
+                delay_seconds: DELAY,
             };
-            let msg = ExecuteMsg::UpdateInternalConfig(to_binary(&action).unwrap());
 
-            let res = execute_as(deps.as_mut(), TEST_ACCOUNT_FACTORY, msg.clone());
-            assert_that(&res).is_ok();
+            let res = execute_as_owner(deps.as_mut(), update(vec![GUARDIAN_1, GUARDIAN_2], 0));
+            assert_that!(res)
+                .is_err()
+                .matches(|e| matches!(e, ManagerError::InvalidGuardians(_)));
+            let res = execute_as_owner(deps.as_mut(), update(vec![GUARDIAN_1, GUARDIAN_2], 3));
+            assert_that!(res)
+                .is_err()
+                .matches(|e| matches!(e, ManagerError::InvalidGuardians(_)));
+            let res = execute_as_owner(deps.as_mut(), update(vec![GUARDIAN_1, GUARDIAN_1], 1));
+            assert_that!(res)
+                .is_err()
+                .matches(|e| matches!(e, ManagerError::InvalidGuardians(_)));
 
-            let res = execute_as_owner(deps.as_mut(), msg.clone());
-            assert_that(&res).is_ok();
+            execute_as_owner(deps.as_mut(), update(vec![GUARDIAN_1, GUARDIAN_2], 2))?;
+            assert_that!(GUARDIANS.load(&deps.storage)?.members).has_length(2);
 
-            let res = execute_as(deps.as_mut(), "not_account_factory", msg);
-            assert_that(&res)
+            // empty list removes the guardians
+            execute_as_owner(deps.as_mut(), update(vec![], 0))?;
+            assert_that!(GUARDIANS.may_load(&deps.storage)?).is_none();
+            Ok(())
+        }
+
+        #[test]
+        fn recovers_after_quorum_and_delay() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_guardians(&mut deps)?;
+
+            let res = execute_as(deps.as_mut(), "not_guardian", propose_msg());
+            assert_that!(res)
                 .is_err()
-                .is_equal_to(ManagerError::Ownership(OwnershipError::NotOwner {}));
+                .is_equal_to(ManagerError::NotGuardian("not_guardian".to_string()));
+
+            let res = execute_as(deps.as_mut(), GUARDIAN_1, propose_msg())?;
+            assert_that!(res.events[0].attributes)
+                .contains(cosmwasm_std::Attribute::new("action", "propose_recovery"));
+
+            let res = execute_as(deps.as_mut(), GUARDIAN_1, ExecuteMsg::ExecuteRecovery {});
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryQuorumNotMet {
+                    approvals: 1,
+                    quorum: 2,
+                });
+
+            let res = execute_as(deps.as_mut(), GUARDIAN_1, ExecuteMsg::ApproveRecovery {});
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::AlreadyApprovedRecovery(
+                    GUARDIAN_1.to_string(),
+                ));
+
+            execute_as(deps.as_mut(), GUARDIAN_2, ExecuteMsg::ApproveRecovery {})?;
+            let executable_at = mock_env().block.time.plus_seconds(DELAY);
+            assert_that!(PENDING_RECOVERY.load(&deps.storage)?.executable_at)
+                .is_equal_to(Some(executable_at));
+
+            let res = execute_at(
+                deps.as_mut(),
+                GUARDIAN_3,
+                DELAY - 1,
+                ExecuteMsg::ExecuteRecovery {},
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryNotReady { executable_at });
 
+            execute_at(
+                deps.as_mut(),
+                GUARDIAN_3,
+                DELAY,
+                ExecuteMsg::ExecuteRecovery {},
+            )?;
+            assert_that!(PENDING_RECOVERY.may_load(&deps.storage)?).is_none();
+            let info = INFO.load(&deps.storage)?;
+            assert_that!(info.governance_details.owner_address())
+                .is_equal_to(Some(Addr::unchecked(NEW_OWNER)));
+            // the new owner does not have to accept the ownership
+            let ownership = cw_ownable::get_ownership(&deps.storage)?;
+            assert_that!(ownership.owner).is_equal_to(Some(Addr::unchecked(NEW_OWNER)));
+            assert_that!(ownership.pending_owner).is_none();
             Ok(())
         }
-    }
-
-    mod install_module {
-        use super::*;
 
         #[test]
-        fn only_account_owner() -> ManagerTestResult {
+        fn guardians_can_freeze_and_recover_suspended_account() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            mock_init(deps.as_mut())?;
+            init_guardians(&mut deps)?;
 
-            let msg = ExecuteMsg::InstallModule {
-                module: ModuleInfo::from_id_latest("test:module")?,
-                init_msg: None,
+            let freeze_msg = ExecuteMsg::UpdateStatus {
+                is_suspended: Some(true),
             };
+            // a single guardian can not freeze the Account
+            let res = execute_as(deps.as_mut(), GUARDIAN_1, freeze_msg.clone());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryQuorumNotMet {
+                    approvals: 0,
+                    quorum: 2,
+                });
+            execute_as(deps.as_mut(), GUARDIAN_1, propose_msg())?;
+            let res = execute_as(deps.as_mut(), GUARDIAN_1, freeze_msg.clone());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryQuorumNotMet {
+                    approvals: 1,
+                    quorum: 2,
+                });
 
-            let res = execute_as(deps.as_mut(), "not_owner", msg);
-            assert_that(&res)
+            execute_as(deps.as_mut(), GUARDIAN_2, ExecuteMsg::ApproveRecovery {})?;
+            execute_as(deps.as_mut(), GUARDIAN_1, freeze_msg)?;
+
+            // the owner can not undo the freeze or the approved recovery
+            let res = execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateStatus {
+                    is_suspended: Some(false),
+                },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryApproved {});
+            let res = execute_as_owner(deps.as_mut(), ExecuteMsg::CancelRecovery {});
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryApproved {});
+            let res = execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateGuardians {
+                    guardians: vec![],
+                    quorum: 0,
+                    delay_seconds: 0,
+                },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryApproved {});
+            assert_that!(SUSPENSION_STATUS.load(&deps.storage)?).is_true();
+
+            // guardians can not lift the suspension
+            let res = execute_as(
+                deps.as_mut(),
+                GUARDIAN_1,
+                ExecuteMsg::UpdateStatus {
+                    is_suspended: Some(false),
+                },
+            );
+            assert_that!(res)
                 .is_err()
                 .is_equal_to(ManagerError::Ownership(OwnershipError::NotOwner));
 
+            execute_at(
+                deps.as_mut(),
+                GUARDIAN_1,
+                DELAY,
+                ExecuteMsg::ExecuteRecovery {},
+            )?;
+
+            assert_that!(SUSPENSION_STATUS.load(&deps.storage)?).is_false();
             Ok(())
         }
 
         #[test]
-        fn cannot_reinstall_module() -> ManagerTestResult {
+        fn owner_can_cancel() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            mock_init(deps.as_mut())?;
+            init_guardians(&mut deps)?;
 
-            let msg = ExecuteMsg::InstallModule {
-                module: ModuleInfo::from_id_latest("test:module")?,
-                init_msg: None,
-            };
+            execute_as(deps.as_mut(), GUARDIAN_1, propose_msg())?;
+            let res = execute_as(deps.as_mut(), GUARDIAN_2, propose_msg());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::RecoveryAlreadyProposed {});
 
-            // manual installation
-            ACCOUNT_MODULES.save(
-                &mut deps.storage,
-                "test:module",
-                &Addr::unchecked("test_module_addr"),
+            execute_as_owner(deps.as_mut(), ExecuteMsg::CancelRecovery {})?;
+
+            let res = execute_as(deps.as_mut(), GUARDIAN_2, ExecuteMsg::ApproveRecovery {});
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::NoRecoveryProposed {});
+            Ok(())
+        }
+    }
+
+    mod multisig {
+        use super::*;
+        use cosmwasm_std::testing::MOCK_CONTRACT_ADDR;
+
+        const ALICE: &str = "alice";
+        const BOB: &str = "bob";
+        const CAROL: &str = "carol";
+
+        fn init_multisig(deps: &mut MockDeps) -> ManagerTestResult {
+            init_with_proxy(deps);
+            let msg = ExecuteMsg::SetOwner {
+                owner: GovernanceDetails::Multisig {
+                    members: vec![
+                        (ALICE.to_string(), 1),
+                        (BOB.to_string(), 1),
+                        (CAROL.to_string(), 2),
+                    ],
+                    threshold: 2,
+                },
+            };
+            let res = execute_as_owner(deps.as_mut(), msg)?;
+            // the manager accepts the ownership itself
+            assert_that!(res.messages).has_length(1);
+            execute_as(
+                deps.as_mut(),
+                MOCK_CONTRACT_ADDR,
+                ExecuteMsg::UpdateOwnership(cw_ownable::Action::AcceptOwnership),
             )?;
+            Ok(())
+        }
 
-            let res = execute_as_owner(deps.as_mut(), msg);
-            assert_that(&res).is_err().matches(|e| {
-                let _module_id = String::from("test:module");
-                matches!(e, ManagerError::ModuleAlreadyInstalled(_module_id))
-            });
+        fn update_info_msg() -> ExecuteMsg {
+            ExecuteMsg::UpdateInfo {
+                name: Some("multisig account".to_string()),
+                description: None,
+                link: None,
+            }
+        }
+
+        #[test]
+        fn manager_becomes_owner() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_multisig(&mut deps)?;
+
+            let owner = cw_ownable::get_ownership(&deps.storage)?.owner.unwrap();
+            assert_that!(owner).is_equal_to(Addr::unchecked(MOCK_CONTRACT_ADDR));
 
+            let info = INFO.load(&deps.storage)?;
+            assert_that!(info.governance_details.to_string()).is_equal_to("multisig".to_string());
+            assert_that!(info
+                .governance_details
+                .member_weight(&Addr::unchecked(CAROL)))
+            .is_equal_to(Some(2));
             Ok(())
         }
 
         #[test]
-        fn adds_module_to_account_modules() -> ManagerTestResult {
+        fn only_members_can_propose() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            mock_init(deps.as_mut())?;
+            init_multisig(&mut deps)?;
 
-            let msg = ExecuteMsg::InstallModule {
-                module: ModuleInfo::from_id_latest("test:module")?,
-                init_msg: None,
+            let msg = ExecuteMsg::Propose {
+                description: "rename".to_string(),
+                actions: vec![update_info_msg().into()],
             };
+            let res = execute_as(deps.as_mut(), TEST_OWNER, msg);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::NotMultisigMember(TEST_OWNER.to_string()));
+            Ok(())
+        }
 
-            let res = execute_as_owner(deps.as_mut(), msg);
-            assert_that(&res).is_ok();
+        #[test]
+        fn requires_multisig_governance() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
 
+            let msg = ExecuteMsg::Propose {
+                description: "rename".to_string(),
+                actions: vec![update_info_msg().into()],
+            };
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::NotMultisig {});
             Ok(())
         }
 
         #[test]
-        fn forwards_init_to_module_factory() -> ManagerTestResult {
+        fn rejects_empty_proposal() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            mock_init(deps.as_mut())?;
+            init_multisig(&mut deps)?;
 
-            let new_module = ModuleInfo::from_id_latest("test:module")?;
-            let expected_init = Some(to_binary(&"some init msg")?);
+            let msg = ExecuteMsg::Propose {
+                description: "nothing".to_string(),
+                actions: vec![],
+            };
+            let res = execute_as(deps.as_mut(), ALICE, msg);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::EmptyProposal {});
+            Ok(())
+        }
 
-            let msg = ExecuteMsg::InstallModule {
-                module: new_module.clone(),
-                init_msg: expected_init.clone(),
+        #[test]
+        fn cannot_vote_twice() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_multisig(&mut deps)?;
+
+            let msg = ExecuteMsg::Propose {
+                description: "rename".to_string(),
+                actions: vec![update_info_msg().into()],
             };
+            execute_as(deps.as_mut(), ALICE, msg)?;
 
-            let res = execute_as_owner(deps.as_mut(), msg);
-            assert_that(&res).is_ok();
+            let res = execute_as(deps.as_mut(), ALICE, ExecuteMsg::Vote { proposal_id: 0 });
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::AlreadyVoted(ALICE.to_string(), 0));
+            Ok(())
+        }
 
-            let msgs = res.unwrap().messages;
+        #[test]
+        fn executes_after_threshold() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_multisig(&mut deps)?;
 
-            let msg = &msgs[0];
+            let msg = ExecuteMsg::Propose {
+                description: "rename".to_string(),
+                actions: vec![update_info_msg().into()],
+            };
+            execute_as(deps.as_mut(), ALICE, msg)?;
 
-            let expected_msg: CosmosMsg = wasm_execute(
-                TEST_MODULE_FACTORY,
-                &ModuleFactoryMsg::InstallModule {
-                    module: new_module,
-                    init_msg: expected_init,
-                },
+            let res = execute_as(
+                deps.as_mut(),
+                ALICE,
+                ExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::ProposalThresholdNotMet {
+                    proposal_id: 0,
+                    approved: 1,
+                    threshold: 2,
+                });
+
+            execute_as(deps.as_mut(), BOB, ExecuteMsg::Vote { proposal_id: 0 })?;
+            let res = execute_as(
+                deps.as_mut(),
+                BOB,
+                ExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            )?;
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                MOCK_CONTRACT_ADDR,
+                &update_info_msg(),
                 vec![],
-            )?
-            .into();
-            assert_that(&msgs).has_length(1);
+            )?));
 
-            assert_that(&msg.msg).is_equal_to(&expected_msg);
+            // the dispatched action is authorized as the manager is the owner
+            execute_as(deps.as_mut(), MOCK_CONTRACT_ADDR, update_info_msg())?;
+            let info = INFO.load(&deps.storage)?;
+            assert_that!(info.name).is_equal_to("multisig account".to_string());
 
+            let res = execute_as(
+                deps.as_mut(),
+                BOB,
+                ExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::ProposalExecuted(0));
             Ok(())
         }
-    }
 
-    mod uninstall_module {
-        use super::*;
+        #[test]
+        fn governance_change_invalidates_open_proposals() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_multisig(&mut deps)?;
 
-        use std::collections::HashSet;
+            let msg = ExecuteMsg::Propose {
+                description: "rename".to_string(),
+                actions: vec![update_info_msg().into()],
+            };
+            execute_as(deps.as_mut(), ALICE, msg.clone())?;
+
+            // the multisig changes its members
+            execute_as(
+                deps.as_mut(),
+                MOCK_CONTRACT_ADDR,
+                ExecuteMsg::SetOwner {
+                    owner: GovernanceDetails::Multisig {
+                        members: vec![(ALICE.to_string(), 1), (BOB.to_string(), 1)],
+                        threshold: 1,
+                    },
+                },
+            )?;
+
+            let res = execute_as(deps.as_mut(), BOB, ExecuteMsg::Vote { proposal_id: 0 });
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::ProposalInvalidated(0));
+            let res = execute_as(
+                deps.as_mut(),
+                ALICE,
+                ExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::ProposalInvalidated(0));
+
+            // proposals made under the new governance can be executed
+            execute_as(deps.as_mut(), ALICE, msg)?;
+            execute_as(
+                deps.as_mut(),
+                ALICE,
+                ExecuteMsg::ExecuteProposal { proposal_id: 1 },
+            )?;
+            Ok(())
+        }
+
+        #[test]
+        fn executes_with_funds() -> ManagerTestResult {
+            use cosmwasm_std::coins;
+
+            let mut deps = mock_dependencies();
+            init_multisig(&mut deps)?;
+
+            let msg = ExecuteMsg::Propose {
+                description: "pay".to_string(),
+                actions: vec![ProposalAction {
+                    msg: update_info_msg(),
+                    funds: coins(10, "ujuno"),
+                }],
+            };
+            execute_as(deps.as_mut(), CAROL, msg)?;
+
+            let res = execute_as(
+                deps.as_mut(),
+                CAROL,
+                ExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::ProposalFundsMismatch(0));
+
+            let res = contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(CAROL, &coins(10, "ujuno")),
+                ExecuteMsg::ExecuteProposal { proposal_id: 0 },
+            )?;
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                MOCK_CONTRACT_ADDR,
+                &update_info_msg(),
+                coins(10, "ujuno"),
+            )?));
+            Ok(())
+        }
+    }
+
+    mod update_module_addresses {
+        use super::*;
+        use abstract_core::manager::InternalConfigAction;
+
+        #[test]
+        fn manual_adds_module_to_account_modules() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut()).unwrap();
+
+            let to_add: Vec<(String, String)> = vec![
+                ("test:module1".to_string(), "module1_addr".to_string()),
+                ("test:module2".to_string(), "module2_addr".to_string()),
+            ];
+
+            let res = update_module_addresses(deps.as_mut(), Some(to_add.clone()), Some(vec![]));
+            assert_that(&res).is_ok();
+
+            let actual_modules = load_account_modules(&deps.storage)?;
+
+            speculoos::prelude::VecAssertions::has_length(
+                &mut assert_that(&actual_modules),
+                to_add.len(),
+            );
+            for (module_id, addr) in to_add {
+                speculoos::iter::ContainingIntoIterAssertions::contains(
+                    &mut assert_that(&actual_modules),
+                    &(module_id, Addr::unchecked(addr)),
+                );
+            }
+
+            Ok(())
+        }
+
+        #[test]
+        fn missing_id() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut()).unwrap();
+
+            let to_add: Vec<(String, String)> = vec![("".to_string(), "module1_addr".to_string())];
+
+            let res = update_module_addresses(deps.as_mut(), Some(to_add), Some(vec![]));
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::InvalidModuleName {});
+
+            Ok(())
+        }
+
+        #[test]
+        fn manual_removes_module_from_account_modules() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            // manually add module
+            ACCOUNT_MODULES.save(
+                &mut deps.storage,
+                "test:module",
+                &Addr::unchecked("test_module_addr"),
+            )?;
+
+            let to_remove: Vec<String> = vec!["test:module".to_string()];
+
+            let res = update_module_addresses(deps.as_mut(), Some(vec![]), Some(to_remove));
+            assert_that(&res).is_ok();
+
+            let actual_modules = load_account_modules(&deps.storage)?;
+
+            speculoos::prelude::VecAssertions::is_empty(&mut assert_that(&actual_modules));
+
+            Ok(())
+        }
+
+        #[test]
+        fn indexes_module_ids_by_address() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            let old_addr = Addr::unchecked("module_addr");
+            let new_addr = Addr::unchecked("new_module_addr");
+
+            update_module_addresses(
+                deps.as_mut(),
+                Some(vec![("test:module".to_string(), old_addr.to_string())]),
+                None,
+            )?;
+            assert_that!(MODULE_IDS.load(&deps.storage, &old_addr)?)
+                .is_equal_to("test:module".to_string());
+
+            // a new address replaces the previous one
+            update_module_addresses(
+                deps.as_mut(),
+                Some(vec![("test:module".to_string(), new_addr.to_string())]),
+                None,
+            )?;
+            assert_that!(MODULE_IDS.has(&deps.storage, &old_addr)).is_false();
+            assert_that!(MODULE_IDS.load(&deps.storage, &new_addr)?)
+                .is_equal_to("test:module".to_string());
+
+            update_module_addresses(deps.as_mut(), None, Some(vec!["test:module".to_string()]))?;
+            assert_that!(MODULE_IDS.has(&deps.storage, &new_addr)).is_false();
+            Ok(())
+        }
+
+        #[test]
+        fn disallows_removing_proxy() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let to_remove: Vec<String> = vec![PROXY.to_string()];
+
+            let res = update_module_addresses(deps.as_mut(), Some(vec![]), Some(to_remove));
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::CannotRemoveProxy {});
+
+            Ok(())
+        }
+
+        #[test]
+        fn only_account_factory_or_owner() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let action = InternalConfigAction::UpdateModuleAddresses {
+                to_add: None,
+                to_remove: None,
+            };
+            let msg = ExecuteMsg::UpdateInternalConfig(to_binary(&action).unwrap());
+
+            let res = execute_as(deps.as_mut(), TEST_ACCOUNT_FACTORY, msg.clone());
+            assert_that(&res).is_ok();
+
+            let res = execute_as_owner(deps.as_mut(), msg.clone());
+            assert_that(&res).is_ok();
+
+            let res = execute_as(deps.as_mut(), "not_account_factory", msg);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::Ownership(OwnershipError::NotOwner {}));
+
+            Ok(())
+        }
+    }
+
+    mod install_module {
+        use super::*;
+
+        #[test]
+        fn only_account_owner() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let msg = ExecuteMsg::InstallModule {
+                module: ModuleInfo::from_id_latest("test:module")?,
+                init_msg: None,
+                permissions: None,
+            };
+
+            let res = execute_as(deps.as_mut(), "not_owner", msg);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::Ownership(OwnershipError::NotOwner));
+
+            Ok(())
+        }
+
+        #[test]
+        fn cannot_reinstall_module() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let msg = ExecuteMsg::InstallModule {
+                module: ModuleInfo::from_id_latest("test:module")?,
+                init_msg: None,
+                permissions: None,
+            };
+
+            // manual installation
+            ACCOUNT_MODULES.save(
+                &mut deps.storage,
+                "test:module",
+                &Addr::unchecked("test_module_addr"),
+            )?;
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that(&res).is_err().matches(|e| {
+                let _module_id = String::from("test:module");
+                matches!(e, ManagerError::ModuleAlreadyInstalled(_module_id))
+            });
+
+            Ok(())
+        }
+
+        #[test]
+        fn adds_module_to_account_modules() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_version_control_querier(Monetization::None);
+            mock_init(deps.as_mut())?;
+
+            let msg = ExecuteMsg::InstallModule {
+                module: ModuleInfo::from_id_latest("test:module")?,
+                init_msg: None,
+                permissions: None,
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that(&res).is_ok();
+
+            Ok(())
+        }
+
+        #[test]
+        fn forwards_init_to_module_factory() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_version_control_querier(Monetization::None);
+            mock_init(deps.as_mut())?;
+
+            let new_module = ModuleInfo::from_id_latest("test:module")?;
+            let expected_init = Some(to_binary(&"some init msg")?);
+
+            let msg = ExecuteMsg::InstallModule {
+                module: new_module.clone(),
+                init_msg: expected_init.clone(),
+                permissions: None,
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that(&res).is_ok();
+
+            let msgs = res.unwrap().messages;
+
+            let msg = &msgs[0];
+
+            let expected_msg: CosmosMsg = wasm_execute(
+                TEST_MODULE_FACTORY,
+                &ModuleFactoryMsg::InstallModule {
+                    module: new_module,
+                    init_msg: expected_init,
+                },
+                vec![],
+            )?
+            .into();
+            assert_that(&msgs).has_length(1);
+
+            assert_that(&msg.msg).is_equal_to(&expected_msg);
+
+            Ok(())
+        }
+
+        #[test]
+        fn enforces_install_policy() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_version_control_querier(Monetization::None);
+            mock_init(deps.as_mut())?;
+
+            let denied_module =
+                ModuleInfo::from_id("test:denied", ModuleVersion::Range("^1".into()))?;
+            execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateSettings {
+                    ibc_enabled: None,
+                    install_policy: Some(InstallPolicy {
+                        allowed_namespaces: Some(vec![Namespace::new("test")?]),
+                        denied_modules: vec![denied_module],
+                    }),
+                },
+            )?;
+
+            let install_msg = |module: ModuleInfo| ExecuteMsg::InstallModule {
+                module,
+                init_msg: None,
+                permissions: None,
+            };
+
+            let res = execute_as_owner(
+                deps.as_mut(),
+                install_msg(ModuleInfo::from_id_latest("other:module")?),
+            );
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::ModuleNotAllowed(
+                    "other:module:latest".to_string(),
+                ));
+
+            let res = execute_as_owner(
+                deps.as_mut(),
+                install_msg(ModuleInfo::from_id("test:denied", "1.2.0".into())?),
+            );
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::ModuleNotAllowed(
+                    "test:denied:1.2.0".to_string(),
+                ));
+
+            execute_as_owner(
+                deps.as_mut(),
+                install_msg(ModuleInfo::from_id("test:denied", "2.0.0".into())?),
+            )?;
+            execute_as_owner(
+                deps.as_mut(),
+                install_msg(ModuleInfo::from_id_latest("test:module")?),
+            )?;
+
+            Ok(())
+        }
+
+        #[test]
+        fn warns_about_deprecated_module() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = MockQuerierBuilder::default()
+                .with_smart_handler(TEST_VERSION_CONTROL, |msg| {
+                    let VcQueryMsg::Modules { mut infos } = from_binary(msg).unwrap() else {
+                        panic!("unexpected version control query")
+                    };
+                    let mut config =
+                        ModuleConfiguration::new(Monetization::None, ModuleMetadata::default());
+                    config.deprecation = Some(ModuleDeprecation {
+                        successor: Some(ModuleInfo::from_id_latest("test:new-module").unwrap()),
+                        reason: "unmaintained".to_string(),
+                    });
+                    to_binary(&ModulesResponse {
+                        modules: vec![ModuleResponse {
+                            module: Module {
+                                info: infos.swap_remove(0),
+                                reference: ModuleReference::App(1),
+                            },
+                            config,
+                        }],
+                    })
+                    .map_err(|e| e.to_string())
+                })
+                .build();
+            mock_init(deps.as_mut())?;
+
+            let msg = ExecuteMsg::InstallModule {
+                module: ModuleInfo::from_id("test:module", ModuleVersion::Version("1.0.0".into()))?,
+                init_msg: None,
+                permissions: None,
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg)?;
+            assert_that!(res.events[0].attributes).contains(cosmwasm_std::Attribute::new(
+                "deprecation_warning",
+                "test:module:1.0.0 is deprecated in favor of test:new-module:latest: unmaintained",
+            ));
+
+            Ok(())
+        }
+    }
+
+    mod uninstall_module {
+        use super::*;
+
+        use std::collections::HashSet;
+
+        #[test]
+        fn only_owner() -> ManagerTestResult {
+            let msg = ExecuteMsg::UninstallModule {
+                module_id: "test:module".to_string(),
+            };
+
+            test_only_owner(msg)
+        }
+
+        #[test]
+        fn errors_with_existing_dependents() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let test_module = "test:module";
+            let msg = ExecuteMsg::UninstallModule {
+                module_id: test_module.to_string(),
+            };
+
+            // manually add dependents
+            let dependents = HashSet::from_iter(vec!["test:dependent".to_string()]);
+            DEPENDENTS.save(&mut deps.storage, test_module, &dependents)?;
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::ModuleHasDependents(Vec::from_iter(
+                    dependents,
+                )));
+
+            Ok(())
+        }
+
+        #[test]
+        fn disallows_removing_proxy() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::UninstallModule {
+                module_id: PROXY.to_string(),
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::CannotRemoveProxy {});
+
+            Ok(())
+        }
+
+        // rest should be in integration tests
+    }
+
+    mod uninstall_module_cascade {
+        use super::*;
+
+        #[test]
+        fn only_owner() -> ManagerTestResult {
+            let msg = ExecuteMsg::UninstallModuleCascade {
+                module_id: "test:module".to_string(),
+            };
+
+            test_only_owner(msg)
+        }
+
+        #[test]
+        fn module_must_be_installed() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::UninstallModuleCascade {
+                module_id: "test:module".to_string(),
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::ModuleNotFound("test:module".to_string()));
+            Ok(())
+        }
+
+        #[test]
+        fn cannot_remove_proxy() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::UninstallModuleCascade {
+                module_id: PROXY.to_string(),
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::CannotRemoveProxy {});
+            Ok(())
+        }
+    }
+
+    mod register_module {
+
+        use super::*;
+
+        fn _execute_as_module_factory(deps: DepsMut, msg: ExecuteMsg) -> ManagerResult {
+            execute_as(deps, TEST_MODULE_FACTORY, msg)
+        }
+
+        #[test]
+        fn applies_install_permissions() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_version_control_querier(Monetization::None);
+            init_with_proxy(&mut deps);
+
+            let permissions = ModulePermissionsUnchecked {
+                allowed_msgs: Some(vec![abstract_core::proxy::CosmosMsgKind::Wasm]),
+                allowed_assets: None,
+                spend_limits: vec![],
+            };
+            execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::InstallModule {
+                    module: ModuleInfo::from_id_latest("test:module")?,
+                    init_msg: None,
+                    permissions: Some(permissions.clone()),
+                },
+            )?;
+            assert_that!(PENDING_PERMISSIONS.load(&deps.storage, "test:module")?)
+                .is_equal_to(&permissions);
+
+            let res = execute_as(
+                deps.as_mut(),
+                TEST_MODULE_FACTORY,
+                ExecuteMsg::RegisterModule {
+                    module_addr: "module_addr".to_string(),
+                    module: Module {
+                        info: ModuleInfo::from_id_latest("test:module")?,
+                        reference: ModuleReference::App(1),
+                    },
+                },
+            )?;
+
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                TEST_PROXY_ADDR,
+                &ProxyMsg::AddModule {
+                    module: "module_addr".to_string(),
+                    permissions: Some(permissions),
+                },
+                vec![],
+            )?));
+            assert_that!(PENDING_PERMISSIONS.has(&deps.storage, "test:module")).is_false();
+            Ok(())
+        }
+
+        #[test]
+        fn only_module_factory() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let _info = mock_info("not_module_factory", &[]);
+
+            let msg = ExecuteMsg::RegisterModule {
+                module_addr: "module_addr".to_string(),
+                module: Module {
+                    info: ModuleInfo::from_id_latest("test:module")?,
+                    reference: ModuleReference::App(1),
+                },
+            };
+
+            let res = execute_as(deps.as_mut(), "not_module_factory", msg);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::CallerNotModuleFactory {});
+
+            Ok(())
+        }
+    }
+
+    mod execute_batch {
+        use super::*;
+
+        fn update_info_msg(name: &str) -> ExecuteMsg {
+            ExecuteMsg::UpdateInfo {
+                name: Some(name.to_string()),
+                description: None,
+                link: None,
+            }
+        }
+
+        #[test]
+        fn only_owner() -> ManagerTestResult {
+            let msg = ExecuteMsg::Batch {
+                actions: vec![update_info_msg("batch").into()],
+            };
+
+            test_only_owner(msg)
+        }
+
+        #[test]
+        fn executes_all_actions() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::Batch {
+                actions: vec![
+                    update_info_msg("first").into(),
+                    ExecuteMsg::ExecOnModule {
+                        module_id: PROXY.to_string(),
+                        exec_msg: to_binary(&"some msg")?,
+                    }
+                    .into(),
+                    update_info_msg("second").into(),
+                ],
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg)?;
+            // exec on proxy and the callback
+            assert_that!(res.messages).has_length(2);
+            assert_that!(res.messages[1].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                cosmwasm_std::testing::MOCK_CONTRACT_ADDR,
+                &ExecuteMsg::Callback(CallbackMsg {}),
+                vec![],
+            )?));
+
+            let info = INFO.load(&deps.storage)?;
+            assert_that!(info.name).is_equal_to("second".to_string());
+            assert_that!(INSTALL_CONTEXT.load(&deps.storage)?).is_empty();
+            Ok(())
+        }
+
+        #[test]
+        fn is_atomic() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::Batch {
+                actions: vec![
+                    update_info_msg("first").into(),
+                    ExecuteMsg::ExecOnModule {
+                        module_id: "not_installed".to_string(),
+                        exec_msg: to_binary(&"some msg")?,
+                    }
+                    .into(),
+                ],
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::ModuleNotFound("not_installed".to_string()));
+            Ok(())
+        }
+
+        #[test]
+        fn disallows_nested_batch() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::Batch {
+                actions: vec![ExecuteMsg::Batch {
+                    actions: vec![update_info_msg("nested").into()],
+                }
+                .into()],
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::InvalidBatchAction {});
+            Ok(())
+        }
+
+        #[test]
+        fn splits_funds_across_actions() -> ManagerTestResult {
+            use cosmwasm_std::coins;
+
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::Batch {
+                actions: vec![
+                    BatchAction {
+                        msg: update_info_msg("funded"),
+                        funds: coins(10, "ujuno"),
+                    },
+                    BatchAction {
+                        msg: update_info_msg("funded again"),
+                        funds: coins(5, "ujuno"),
+                    },
+                ],
+            };
+
+            let res = contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_OWNER, &coins(10, "ujuno")),
+                msg.clone(),
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::BatchFundsMismatch {});
+
+            contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_OWNER, &coins(15, "ujuno")),
+                msg,
+            )?;
+            Ok(())
+        }
+
+        #[test]
+        fn defers_install_requirements() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_version_control_querier(Monetization::None);
+            init_with_proxy(&mut deps);
+            INSTALL_CONTEXT.save(deps.as_mut().storage, &vec![])?;
+
+            let msg = ExecuteMsg::RegisterModule {
+                module_addr: "module_addr".to_string(),
+                module: Module {
+                    info: ModuleInfo::from_id_latest("test:module")?,
+                    reference: ModuleReference::App(1),
+                },
+            };
+            execute_as(deps.as_mut(), TEST_MODULE_FACTORY, msg)?;
+
+            assert_that!(INSTALL_CONTEXT.load(&deps.storage)?)
+                .is_equal_to(vec!["test:module".to_string()]);
+            assert_that!(DEPENDENTS.is_empty(&deps.storage)).is_true();
+            Ok(())
+        }
+    }
+
+    mod exec_on_module {
+        use super::*;
+
+        #[test]
+        fn only_owner() -> ManagerTestResult {
+            let msg = ExecuteMsg::ExecOnModule {
+                module_id: "test:module".to_string(),
+                exec_msg: to_binary(&"some msg")?,
+            };
+
+            test_only_owner(msg)
+        }
+
+        #[test]
+        fn fails_with_nonexistent_module() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let missing_module = "test:module".to_string();
+            let msg = ExecuteMsg::ExecOnModule {
+                module_id: missing_module.clone(),
+                exec_msg: to_binary(&"some msg")?,
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ManagerError::ModuleNotFound(missing_module));
+
+            Ok(())
+        }
+
+        #[test]
+        fn forwards_exec_to_module() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let exec_msg = &"some msg";
+
+            let msg = ExecuteMsg::ExecOnModule {
+                module_id: PROXY.to_string(),
+                exec_msg: to_binary(&exec_msg)?,
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg);
+            assert_that!(&res).is_ok();
+
+            let msgs = res.unwrap().messages;
+            assert_that!(&msgs).has_length(1);
+
+            let expected_msg: CosmosMsg = wasm_execute(TEST_PROXY_ADDR, &exec_msg, vec![])?.into();
+
+            let actual_msg = &msgs[0];
+            assert_that!(&actual_msg.msg).is_equal_to(&expected_msg);
+
+            Ok(())
+        }
+    }
+
+    mod subscriptions {
+        use super::*;
+        use abstract_core::manager::{QueryMsg, SubscriptionStatusResponse};
+        use cosmwasm_std::{coin, coins};
+
+        const TEST_MODULE: &str = "test:module";
+
+        fn subscription_monetization() -> Monetization {
+            Monetization::Subscription {
+                per_period: coin(10, "ujuno"),
+                period_seconds: 100,
+            }
+        }
+
+        fn setup() -> Result<MockDeps, ManagerError> {
+            setup_with(subscription_monetization())
+        }
+
+        fn setup_with(monetization: Monetization) -> Result<MockDeps, ManagerError> {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_version_control_querier(monetization);
+            init_with_proxy(&mut deps);
+
+            let msg = ExecuteMsg::RegisterModule {
+                module_addr: "module_addr".to_string(),
+                module: Module {
+                    info: ModuleInfo::from_id_latest(TEST_MODULE)?,
+                    reference: ModuleReference::App(1),
+                },
+            };
+            execute_as(deps.as_mut(), TEST_MODULE_FACTORY, msg)?;
+            Ok(deps)
+        }
+
+        fn exec_msg() -> ExecuteMsg {
+            ExecuteMsg::ExecOnModule {
+                module_id: TEST_MODULE.to_string(),
+                exec_msg: to_binary(&"some msg").unwrap(),
+            }
+        }
+
+        fn pay_msg() -> ExecuteMsg {
+            ExecuteMsg::PaySubscription {
+                module_id: TEST_MODULE.to_string(),
+            }
+        }
+
+        fn env_after(seconds: u64) -> Env {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds);
+            env
+        }
+
+        #[test]
+        fn register_starts_subscription() -> ManagerTestResult {
+            let deps = setup()?;
+
+            let subscription = SUBSCRIPTIONS.load(&deps.storage, TEST_MODULE)?;
+            assert_that!(subscription).is_equal_to(ModuleSubscription {
+                per_period: coin(10, "ujuno"),
+                period_seconds: 100,
+                paid_until: mock_env().block.time.plus_seconds(100),
+            });
+            Ok(())
+        }
+
+        #[test]
+        fn lapsed_subscription_blocks_execution() -> ManagerTestResult {
+            let mut deps = setup()?;
+
+            contract::execute(
+                deps.as_mut(),
+                env_after(99),
+                mock_info(TEST_OWNER, &[]),
+                exec_msg(),
+            )?;
+
+            let res = contract::execute(
+                deps.as_mut(),
+                env_after(100),
+                mock_info(TEST_OWNER, &[]),
+                exec_msg(),
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::SubscriptionLapsed {
+                    module_id: TEST_MODULE.to_string(),
+                    paid_until: mock_env().block.time.plus_seconds(100),
+                });
+            Ok(())
+        }
+
+        #[test]
+        fn payment_renews_subscription() -> ManagerTestResult {
+            let mut deps = setup()?;
+
+            // paying an active subscription extends it
+            let res = contract::execute(
+                deps.as_mut(),
+                env_after(50),
+                mock_info("sponsor", &coins(10, "ujuno")),
+                pay_msg(),
+            )?;
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::Bank(BankMsg::Send {
+                to_address: TEST_PROXY.to_string(),
+                amount: coins(10, "ujuno"),
+            }));
+            let subscription = SUBSCRIPTIONS.load(&deps.storage, TEST_MODULE)?;
+            assert_that!(subscription.paid_until)
+                .is_equal_to(mock_env().block.time.plus_seconds(200));
+
+            // paying a lapsed subscription restarts it
+            contract::execute(
+                deps.as_mut(),
+                env_after(500),
+                mock_info("sponsor", &coins(10, "ujuno")),
+                pay_msg(),
+            )?;
+            let subscription = SUBSCRIPTIONS.load(&deps.storage, TEST_MODULE)?;
+            assert_that!(subscription.paid_until)
+                .is_equal_to(mock_env().block.time.plus_seconds(600));
+            contract::execute(
+                deps.as_mut(),
+                env_after(550),
+                mock_info(TEST_OWNER, &[]),
+                exec_msg(),
+            )?;
+            Ok(())
+        }
 
         #[test]
-        fn only_owner() -> ManagerTestResult {
-            let msg = ExecuteMsg::UninstallModule {
-                module_id: "test:module".to_string(),
-            };
+        fn payment_follows_revenue_split() -> ManagerTestResult {
+            use abstract_core::objects::fee::{FeeSplit, UsageFee};
+            use cosmwasm_std::Decimal;
+
+            let mut deps = setup()?;
+            let api = MockApi::default();
+            let mut config =
+                ModuleConfiguration::new(subscription_monetization(), ModuleMetadata::default());
+            config.revenue_split = Some(FeeSplit::new(vec![
+                UsageFee::new(&api, Decimal::percent(80), "publisher")?,
+                UsageFee::new(&api, Decimal::percent(20), "author")?,
+            ])?);
+            deps.querier = mock_version_control_querier_with_config(config);
 
-            test_only_owner(msg)
+            let res = contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("sponsor", &coins(10, "ujuno")),
+                pay_msg(),
+            )?;
+            assert_that!(res.messages).has_length(2);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "publisher".to_string(),
+                amount: coins(8, "ujuno"),
+            }));
+            assert_that!(res.messages[1].msg).is_equal_to(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "author".to_string(),
+                amount: coins(2, "ujuno"),
+            }));
+            Ok(())
         }
 
         #[test]
-        fn errors_with_existing_dependents() -> ManagerTestResult {
-            let mut deps = mock_dependencies();
-            init_with_proxy(&mut deps);
-
-            let test_module = "test:module";
-            let msg = ExecuteMsg::UninstallModule {
-                module_id: test_module.to_string(),
-            };
-
-            // manually add dependents
-            let dependents = HashSet::from_iter(vec!["test:dependent".to_string()]);
-            DEPENDENTS.save(&mut deps.storage, test_module, &dependents)?;
+        fn payment_must_match_fee() -> ManagerTestResult {
+            let mut deps = setup()?;
 
-            let res = execute_as_owner(deps.as_mut(), msg);
-            assert_that(&res)
+            let res = execute_as(deps.as_mut(), TEST_OWNER, pay_msg());
+            assert_that!(res)
                 .is_err()
-                .is_equal_to(ManagerError::ModuleHasDependents(Vec::from_iter(
-                    dependents,
-                )));
+                .matches(|e| matches!(e, ManagerError::Abstract(_)));
 
+            let res = contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_OWNER, &coins(5, "ujuno")),
+                pay_msg(),
+            );
+            assert_that!(res)
+                .is_err()
+                .matches(|e| matches!(e, ManagerError::Abstract(_)));
             Ok(())
         }
 
         #[test]
-        fn disallows_removing_proxy() -> ManagerTestResult {
-            let mut deps = mock_dependencies();
-            init_with_proxy(&mut deps);
-
-            let msg = ExecuteMsg::UninstallModule {
-                module_id: PROXY.to_string(),
-            };
+        fn fails_without_subscription() -> ManagerTestResult {
+            let mut deps = setup_with(Monetization::None)?;
 
-            let res = execute_as_owner(deps.as_mut(), msg);
-            assert_that(&res)
+            let res = contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_OWNER, &coins(10, "ujuno")),
+                pay_msg(),
+            );
+            assert_that!(res)
                 .is_err()
-                .is_equal_to(ManagerError::CannotRemoveProxy {});
-
+                .is_equal_to(ManagerError::NoSubscription(TEST_MODULE.to_string()));
             Ok(())
         }
 
-        // rest should be in integration tests
-    }
-
-    mod register_module {
+        #[test]
+        fn payment_reprices_subscription() -> ManagerTestResult {
+            let mut deps = setup()?;
+            deps.querier = mock_version_control_querier(Monetization::Subscription {
+                per_period: coin(20, "ujuno"),
+                period_seconds: 50,
+            });
 
-        use super::*;
+            let res = contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_OWNER, &coins(10, "ujuno")),
+                pay_msg(),
+            );
+            assert_that!(res)
+                .is_err()
+                .matches(|e| matches!(e, ManagerError::Abstract(_)));
 
-        fn _execute_as_module_factory(deps: DepsMut, msg: ExecuteMsg) -> ManagerResult {
-            execute_as(deps, TEST_MODULE_FACTORY, msg)
+            contract::execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_OWNER, &coins(20, "ujuno")),
+                pay_msg(),
+            )?;
+            let subscription = SUBSCRIPTIONS.load(&deps.storage, TEST_MODULE)?;
+            assert_that!(subscription).is_equal_to(ModuleSubscription {
+                per_period: coin(20, "ujuno"),
+                period_seconds: 50,
+                paid_until: mock_env().block.time.plus_seconds(150),
+            });
+            Ok(())
         }
 
         #[test]
-        fn only_module_factory() -> ManagerTestResult {
-            let mut deps = mock_dependencies();
-            init_with_proxy(&mut deps);
+        fn free_module_ends_subscription() -> ManagerTestResult {
+            let mut deps = setup()?;
+            deps.querier = mock_version_control_querier(Monetization::None);
 
-            let _info = mock_info("not_module_factory", &[]);
+            let res = contract::execute(
+                deps.as_mut(),
+                env_after(500),
+                mock_info("sponsor", &coins(10, "ujuno")),
+                pay_msg(),
+            )?;
+            // payment is refunded
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::Bank(BankMsg::Send {
+                to_address: "sponsor".to_string(),
+                amount: coins(10, "ujuno"),
+            }));
+            assert_that!(SUBSCRIPTIONS.has(&deps.storage, TEST_MODULE)).is_false();
+            contract::execute(
+                deps.as_mut(),
+                env_after(500),
+                mock_info(TEST_OWNER, &[]),
+                exec_msg(),
+            )?;
+            Ok(())
+        }
 
-            let msg = ExecuteMsg::RegisterModule {
-                module_addr: "module_addr".to_string(),
-                module: Module {
-                    info: ModuleInfo::from_id_latest("test:module")?,
-                    reference: ModuleReference::App(1),
+        #[test]
+        fn upgrade_carries_subscription_over() -> ManagerTestResult {
+            let mut deps = setup()?;
+
+            sync_subscription(
+                deps.as_mut().storage,
+                TEST_MODULE,
+                Monetization::Subscription {
+                    per_period: coin(20, "ujuno"),
+                    period_seconds: 50,
                 },
-            };
+            )?;
+            let subscription = SUBSCRIPTIONS.load(&deps.storage, TEST_MODULE)?;
+            assert_that!(subscription).is_equal_to(ModuleSubscription {
+                per_period: coin(20, "ujuno"),
+                period_seconds: 50,
+                paid_until: mock_env().block.time.plus_seconds(100),
+            });
 
-            let res = execute_as(deps.as_mut(), "not_module_factory", msg);
-            assert_that(&res)
-                .is_err()
-                .is_equal_to(ManagerError::CallerNotModuleFactory {});
+            // a version with a new subscription must be paid before use
+            sync_subscription(
+                deps.as_mut().storage,
+                "test:other",
+                subscription_monetization(),
+            )?;
+            let subscription = SUBSCRIPTIONS.load(&deps.storage, "test:other")?;
+            assert_that!(subscription.is_active(mock_env().block.time)).is_false();
+            Ok(())
+        }
+
+        #[test]
+        fn query_status() -> ManagerTestResult {
+            let deps = setup()?;
+            let query_status = |env: Env| -> StdResult<SubscriptionStatusResponse> {
+                from_binary(&contract::query(
+                    deps.as_ref(),
+                    env,
+                    QueryMsg::SubscriptionStatus {
+                        module_id: TEST_MODULE.to_string(),
+                    },
+                )?)
+            };
 
+            assert_that!(query_status(env_after(99))?.is_active).is_true();
+            let status = query_status(env_after(100))?;
+            assert_that!(status.is_active).is_false();
+            assert_that!(status.subscription).is_some();
             Ok(())
         }
     }
 
-    mod exec_on_module {
+    mod update_module_permissions {
         use super::*;
+        use abstract_core::proxy::{CosmosMsgKind, ModulePermissionsUnchecked};
 
         #[test]
         fn only_owner() -> ManagerTestResult {
-            let msg = ExecuteMsg::ExecOnModule {
+            let msg = ExecuteMsg::UpdateModulePermissions {
                 module_id: "test:module".to_string(),
-                exec_msg: to_binary(&"some msg")?,
+                permissions: None,
             };
 
             test_only_owner(msg)
@@ -1202,44 +3749,56 @@ mod tests {
         #[test]
         fn fails_with_nonexistent_module() -> ManagerTestResult {
             let mut deps = mock_dependencies();
-            mock_init(deps.as_mut())?;
-
-            let missing_module = "test:module".to_string();
-            let msg = ExecuteMsg::ExecOnModule {
-                module_id: missing_module.clone(),
-                exec_msg: to_binary(&"some msg")?,
-            };
+            init_with_proxy(&mut deps);
 
-            let res = execute_as_owner(deps.as_mut(), msg);
+            let res = execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateModulePermissions {
+                    module_id: "test:module".to_string(),
+                    permissions: None,
+                },
+            );
             assert_that(&res)
                 .is_err()
-                .is_equal_to(ManagerError::ModuleNotFound(missing_module));
+                .is_equal_to(ManagerError::ModuleNotFound("test:module".to_string()));
 
             Ok(())
         }
 
         #[test]
-        fn forwards_exec_to_module() -> ManagerTestResult {
+        fn forwards_permissions_to_proxy() -> ManagerTestResult {
             let mut deps = mock_dependencies();
             init_with_proxy(&mut deps);
+            ACCOUNT_MODULES.save(
+                &mut deps.storage,
+                "test:module",
+                &Addr::unchecked("module_addr"),
+            )?;
 
-            let exec_msg = &"some msg";
-
-            let msg = ExecuteMsg::ExecOnModule {
-                module_id: PROXY.to_string(),
-                exec_msg: to_binary(&exec_msg)?,
+            let permissions = ModulePermissionsUnchecked {
+                allowed_msgs: Some(vec![CosmosMsgKind::Bank]),
+                allowed_assets: None,
+                spend_limits: vec![],
             };
+            let res = execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateModulePermissions {
+                    module_id: "test:module".to_string(),
+                    permissions: Some(permissions.clone()),
+                },
+            )?;
 
-            let res = execute_as_owner(deps.as_mut(), msg);
-            assert_that!(&res).is_ok();
-
-            let msgs = res.unwrap().messages;
-            assert_that!(&msgs).has_length(1);
-
-            let expected_msg: CosmosMsg = wasm_execute(TEST_PROXY_ADDR, &exec_msg, vec![])?.into();
-
-            let actual_msg = &msgs[0];
-            assert_that!(&actual_msg.msg).is_equal_to(&expected_msg);
+            let expected_msg: CosmosMsg = wasm_execute(
+                TEST_PROXY_ADDR,
+                &ProxyMsg::UpdateModulePermissions {
+                    module: "module_addr".to_string(),
+                    permissions: Some(permissions),
+                },
+                vec![],
+            )?
+            .into();
+            assert_that!(res.messages).has_length(1);
+            assert_that!(&res.messages[0].msg).is_equal_to(&expected_msg);
 
             Ok(())
         }
@@ -1418,6 +3977,7 @@ mod tests {
         fn only_owner() -> ManagerTestResult {
             let msg = ExecuteMsg::UpdateSettings {
                 ibc_enabled: Some(true),
+                install_policy: None,
             };
 
             test_only_owner(msg)
@@ -1430,6 +3990,7 @@ mod tests {
 
             let msg = ExecuteMsg::UpdateSettings {
                 ibc_enabled: Some(false),
+                install_policy: None,
             };
 
             let res = execute_as_owner(deps.as_mut(), msg);
@@ -1449,6 +4010,7 @@ mod tests {
 
             let msg = ExecuteMsg::UpdateSettings {
                 ibc_enabled: Some(true),
+                install_policy: None,
             };
 
             let res = execute_as_owner(deps.as_mut(), msg);
@@ -1468,6 +4030,7 @@ mod tests {
 
             let msg = ExecuteMsg::UpdateSettings {
                 ibc_enabled: Some(false),
+                install_policy: None,
             };
 
             let res = execute_as_owner(deps.as_mut(), msg);
@@ -1601,6 +4164,220 @@ mod tests {
         }
     }
 
+    mod sub_accounts {
+        use super::*;
+        use abstract_core::account_factory;
+
+        const SUB_MANAGER: &str = "sub_manager";
+
+        fn register_sub_account_msg(account_id: AccountId) -> ExecuteMsg {
+            ExecuteMsg::UpdateInternalConfig(
+                to_binary(&InternalConfigAction::RegisterSubAccount {
+                    account_id,
+                    manager: SUB_MANAGER.to_string(),
+                })
+                .unwrap(),
+            )
+        }
+
+        #[test]
+        fn create_only_owner() -> ManagerTestResult {
+            let msg = ExecuteMsg::CreateSubAccount {
+                name: "sub".to_string(),
+                description: None,
+                link: None,
+            };
+
+            test_only_owner(msg)
+        }
+
+        #[test]
+        fn create_through_account_factory() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let res = execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::CreateSubAccount {
+                    name: "sub".to_string(),
+                    description: None,
+                    link: None,
+                },
+            )?;
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                TEST_ACCOUNT_FACTORY,
+                &account_factory::ExecuteMsg::CreateSubAccount {
+                    name: "sub".to_string(),
+                    description: None,
+                    link: None,
+                },
+                vec![],
+            )?));
+            Ok(())
+        }
+
+        #[test]
+        fn register_only_account_factory() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let res = execute_as_owner(deps.as_mut(), register_sub_account_msg(2));
+            assert_that!(res)
+                .is_err()
+                .matches(|e| matches!(e, ManagerError::Admin(_)));
+
+            execute_as(
+                deps.as_mut(),
+                TEST_ACCOUNT_FACTORY,
+                register_sub_account_msg(2),
+            )?;
+            assert_that!(SUB_ACCOUNTS.load(&deps.storage, 2)?)
+                .is_equal_to(Addr::unchecked(SUB_MANAGER));
+
+            let res = contract::query(
+                deps.as_ref(),
+                mock_env(),
+                abstract_core::manager::QueryMsg::SubAccountIds {
+                    start_after: None,
+                    limit: None,
+                },
+            )?;
+            let ids: abstract_core::manager::SubAccountIdsResponse = from_binary(&res)?;
+            assert_that!(ids.sub_accounts).is_equal_to(vec![2]);
+            Ok(())
+        }
+
+        #[test]
+        fn exec_on_sub_account() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let exec_msg = to_binary(&ExecuteMsg::UpdateInfo {
+                name: Some("renamed".to_string()),
+                description: None,
+                link: None,
+            })?;
+            let msg = ExecuteMsg::ExecOnSubAccount {
+                account_id: 2,
+                exec_msg: exec_msg.clone(),
+            };
+
+            let res = execute_as_owner(deps.as_mut(), msg.clone());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::SubAccountNotFound(2));
+
+            execute_as(
+                deps.as_mut(),
+                TEST_ACCOUNT_FACTORY,
+                register_sub_account_msg(2),
+            )?;
+
+            let res = execute_as_owner(deps.as_mut(), msg)?;
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: SUB_MANAGER.to_string(),
+                msg: exec_msg,
+                funds: vec![],
+            }));
+            Ok(())
+        }
+
+        #[test]
+        fn unregister_only_sub_account_manager() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+            execute_as(
+                deps.as_mut(),
+                TEST_ACCOUNT_FACTORY,
+                register_sub_account_msg(2),
+            )?;
+
+            let msg = ExecuteMsg::UpdateInternalConfig(to_binary(
+                &InternalConfigAction::UnregisterSubAccount { account_id: 2 },
+            )?);
+
+            let res = execute_as_owner(deps.as_mut(), msg.clone());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::NotSubAccountManager(
+                    TEST_OWNER.to_string(),
+                    2,
+                ));
+
+            // sub-accounts can detach from a suspended parent
+            execute_as_owner(
+                deps.as_mut(),
+                ExecuteMsg::UpdateStatus {
+                    is_suspended: Some(true),
+                },
+            )?;
+            execute_as(deps.as_mut(), SUB_MANAGER, msg)?;
+            assert_that!(SUB_ACCOUNTS.has(&deps.storage, 2)).is_false();
+            Ok(())
+        }
+
+        #[test]
+        fn cannot_set_sub_account_governance() -> ManagerTestResult {
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+
+            let owner = GovernanceDetails::SubAccount {
+                manager: "parent_manager".to_string(),
+                proxy: "parent_proxy".to_string(),
+            };
+            let res = execute_as_owner(deps.as_mut(), ExecuteMsg::SetOwner { owner });
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ManagerError::SubAccountGovernance {});
+            Ok(())
+        }
+
+        #[test]
+        fn detaches_from_parent_on_governance_change() -> ManagerTestResult {
+            const PARENT_MANAGER: &str = "parent_manager";
+            let mut deps = mock_dependencies();
+            init_with_proxy(&mut deps);
+            let mut info = INFO.load(&deps.storage)?;
+            info.governance_details = GovernanceDetails::SubAccount {
+                manager: Addr::unchecked(PARENT_MANAGER),
+                proxy: Addr::unchecked("parent_proxy"),
+            };
+            INFO.save(deps.as_mut().storage, &info)?;
+            cw_ownable::initialize_owner(
+                deps.as_mut().storage,
+                deps.as_ref().api,
+                Some(PARENT_MANAGER),
+            )?;
+
+            let res = execute_as(
+                deps.as_mut(),
+                PARENT_MANAGER,
+                ExecuteMsg::SetOwner {
+                    owner: GovernanceDetails::Monarchy {
+                        monarch: TEST_OWNER.to_string(),
+                    },
+                },
+            )?;
+            let account_id = ACCOUNT_ID.load(&deps.storage)?;
+            assert_that!(res.messages).has_length(2);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                PARENT_MANAGER,
+                &ExecuteMsg::UpdateInternalConfig(to_binary(
+                    &InternalConfigAction::UnregisterSubAccount { account_id },
+                )?),
+                vec![],
+            )?));
+            assert_that!(res.messages[1].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                TEST_VERSION_CONTROL,
+                &VcExecuteMsg::RemoveAccountParent { account_id },
+                vec![],
+            )?));
+            Ok(())
+        }
+    }
+
     mod update_internal_config {
         use super::*;
         use abstract_core::manager::InternalConfigAction::UpdateModuleAddresses;
@@ -8,8 +8,11 @@ use crate::{
 
 use abstract_sdk::core::{
     manager::{
-        state::{AccountInfo, Config, ACCOUNT_FACTORY, CONFIG, INFO, SUSPENSION_STATUS},
-        CallbackMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+        state::{
+            AccountInfo, Config, ACCOUNT_FACTORY, ACCOUNT_MODULES, CONFIG, INFO, MODULE_IDS,
+            SUSPENSION_STATUS,
+        },
+        CallbackMsg, ExecuteMsg, InstantiateMsg, InternalConfigAction, MigrateMsg, QueryMsg,
     },
     objects::module_version::assert_contract_upgrade,
     objects::validation::{validate_description, validate_link, validate_name},
@@ -17,7 +20,8 @@ use abstract_sdk::core::{
     MANAGER,
 };
 use cosmwasm_std::{
-    ensure_eq, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    ensure_eq, from_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError,
+    StdResult,
 };
 use cw2::set_contract_version;
 use semver::Version;
@@ -32,6 +36,14 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ManagerResult {
 
     assert_contract_upgrade(deps.storage, MANAGER, version)?;
     set_contract_version(deps.storage, MANAGER, CONTRACT_VERSION)?;
+
+    // index the installed modules by their address
+    let modules = ACCOUNT_MODULES
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (id, address) in modules {
+        MODULE_IDS.save(deps.storage, &address, &id)?;
+    }
     Ok(ManagerResponse::action("migrate"))
 }
 
@@ -59,7 +71,10 @@ pub fn instantiate(
     validate_name(&msg.name)?;
 
     let governance_details = msg.owner.verify(deps.api)?;
-    let owner = governance_details.owner_address();
+    // Multisig governance is enforced by the manager itself
+    let owner = governance_details
+        .owner_address()
+        .unwrap_or_else(|| env.contract.address.clone());
 
     let account_info = AccountInfo {
         name: msg.name,
@@ -86,11 +101,30 @@ pub fn instantiate(
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> ManagerResult {
+pub fn execute(mut deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> ManagerResult {
     match msg {
         ExecuteMsg::UpdateStatus {
             is_suspended: suspension_status,
         } => update_account_status(deps, info, suspension_status),
+        // Guardians can recover a suspended Account
+        ExecuteMsg::UpdateGuardians {
+            guardians,
+            quorum,
+            delay_seconds,
+        } => update_guardians(deps, info, guardians, quorum, delay_seconds),
+        ExecuteMsg::ProposeRecovery { owner } => propose_recovery(deps, env, info, owner),
+        ExecuteMsg::ApproveRecovery {} => approve_recovery(deps, env, info),
+        ExecuteMsg::ExecuteRecovery {} => execute_recovery(deps, env, info),
+        ExecuteMsg::CancelRecovery {} => cancel_recovery(deps, info),
+        // A sub-account that changes its governance detaches itself, even from a suspended parent
+        ExecuteMsg::UpdateInternalConfig(config)
+            if matches!(
+                from_binary(&config),
+                Ok(InternalConfigAction::UnregisterSubAccount { .. })
+            ) =>
+        {
+            update_internal_config(deps, info, config)
+        }
         msg => {
             // Block actions if user is not subscribed
             let is_suspended = SUSPENSION_STATUS.load(deps.storage)?;
@@ -103,13 +137,25 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> M
                     update_internal_config(deps, info, config)
                 }
                 ExecuteMsg::SetOwner { owner } => set_owner(deps, env, info, owner),
-
-                ExecuteMsg::InstallModule { module, init_msg } => {
-                    install_module(deps, info, env, module, init_msg)
-                }
+                ExecuteMsg::ScheduleOwnerTransfer {
+                    owner,
+                    delay_seconds,
+                    expiry_seconds,
+                } => schedule_owner_transfer(deps, env, info, owner, delay_seconds, expiry_seconds),
+                ExecuteMsg::CancelOwnerTransfer {} => cancel_owner_transfer(deps, info),
+                ExecuteMsg::ExecuteOwnerTransfer {} => execute_owner_transfer(deps, env, info),
+
+                ExecuteMsg::InstallModule {
+                    module,
+                    init_msg,
+                    permissions,
+                } => install_module(deps, info, env, module, init_msg, permissions),
                 ExecuteMsg::UninstallModule { module_id } => {
                     uninstall_module(deps, info, module_id)
                 }
+                ExecuteMsg::UninstallModuleCascade { module_id } => {
+                    uninstall_module_cascade(deps, info, module_id)
+                }
                 ExecuteMsg::RegisterModule {
                     module,
                     module_addr,
@@ -117,8 +163,25 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> M
                 ExecuteMsg::ExecOnModule {
                     module_id,
                     exec_msg,
-                } => exec_on_module(deps, info, module_id, exec_msg),
+                } => exec_on_module(deps, env, info, module_id, exec_msg),
+                ExecuteMsg::PaySubscription { module_id } => {
+                    pay_subscription(deps, env, info, module_id)
+                }
+                ExecuteMsg::UpdateModulePermissions {
+                    module_id,
+                    permissions,
+                } => update_module_permissions(deps, info, module_id, permissions),
+                ExecuteMsg::CreateSubAccount {
+                    name,
+                    description,
+                    link,
+                } => create_sub_account(deps, info, name, description, link),
+                ExecuteMsg::ExecOnSubAccount {
+                    account_id,
+                    exec_msg,
+                } => exec_on_sub_account(deps, info, account_id, exec_msg),
                 ExecuteMsg::Upgrade { modules } => upgrade_modules(deps, env, info, modules),
+                ExecuteMsg::Batch { actions } => execute_batch(deps, env, info, actions),
                 ExecuteMsg::UpdateInfo {
                     name,
                     description,
@@ -126,17 +189,35 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> M
                 } => update_info(deps, info, name, description, link),
                 ExecuteMsg::UpdateSettings {
                     ibc_enabled: new_status,
+                    install_policy,
                 } => {
                     let mut response: Response = ManagerResponse::action("update_settings");
 
+                    if new_status.is_none() && install_policy.is_none() {
+                        return Err(ManagerError::NoUpdates {});
+                    }
+                    if let Some(install_policy) = install_policy {
+                        response = update_install_policy(
+                            deps.branch(),
+                            info.clone(),
+                            install_policy,
+                            response,
+                        )?;
+                    }
                     if let Some(ibc_enabled) = new_status {
                         response = update_ibc_status(deps, info, ibc_enabled, response)?;
-                    } else {
-                        return Err(ManagerError::NoUpdates {});
                     }
 
                     Ok(response)
                 }
+                ExecuteMsg::Propose {
+                    description,
+                    actions,
+                } => propose(deps, info, description, actions),
+                ExecuteMsg::Vote { proposal_id } => vote(deps, info, proposal_id),
+                ExecuteMsg::ExecuteProposal { proposal_id } => {
+                    execute_proposal(deps, env, info, proposal_id)
+                }
                 ExecuteMsg::Callback(CallbackMsg {}) => handle_callback(deps, env, info),
                 ExecuteMsg::UpdateOwnership(action) => match action {
                     // Disallow the user from using the TransferOwnership action
@@ -169,6 +250,22 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::Info {} => handle_account_info_query(deps),
         QueryMsg::Config {} => handle_config_query(deps),
+        QueryMsg::PendingOwnerTransfer {} => queries::handle_pending_owner_transfer_query(deps),
+        QueryMsg::Guardians {} => queries::handle_guardians_query(deps),
+        QueryMsg::SubscriptionStatus { module_id } => {
+            queries::handle_subscription_status_query(deps, env, module_id)
+        }
+        QueryMsg::InstallPolicy {} => queries::handle_install_policy_query(deps),
+        QueryMsg::UninstallPlan { module_id } => {
+            queries::handle_uninstall_plan_query(deps, module_id)
+        }
+        QueryMsg::SubAccountIds { start_after, limit } => {
+            queries::handle_sub_accounts_query(deps, start_after, limit)
+        }
+        QueryMsg::Proposal { proposal_id } => queries::handle_proposal_query(deps, proposal_id),
+        QueryMsg::Proposals { start_after, limit } => {
+            queries::handle_proposals_query(deps, start_after, limit)
+        }
         QueryMsg::Ownership {} => abstract_sdk::query_ownership!(deps),
     }
 }
@@ -189,6 +286,16 @@ pub fn handle_callback(mut deps: DepsMut, env: Env, info: MessageInfo) -> Manage
     }
 
     MIGRATE_CONTEXT.save(deps.storage, &vec![])?;
+
+    // Assert the install requirements of the modules installed in a batch
+    if let Some(installed_modules) = INSTALL_CONTEXT.may_load(deps.storage)? {
+        for module_id in installed_modules {
+            let dependencies = versioning::assert_install_requirements(deps.as_ref(), &module_id)?;
+            versioning::set_as_dependent(deps.storage, module_id, dependencies)?;
+        }
+        INSTALL_CONTEXT.remove(deps.storage);
+    }
+
     Ok(Response::new())
 }
 
@@ -293,5 +400,20 @@ mod tests {
                 .is_equal_to(version.to_string());
             Ok(())
         }
+
+        #[test]
+        fn indexes_module_ids() -> ManagerResult<()> {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            set_contract_version(deps.as_mut().storage, MANAGER, "0.0.0")?;
+            let module = cosmwasm_std::Addr::unchecked("module_addr");
+            ACCOUNT_MODULES.save(deps.as_mut().storage, "test:module", &module)?;
+
+            contract::migrate(deps.as_mut(), mock_env(), MigrateMsg {})?;
+
+            assert_that!(MODULE_IDS.load(&deps.storage, &module)?)
+                .is_equal_to("test:module".to_string());
+            Ok(())
+        }
     }
 }
@@ -1,8 +1,9 @@
 use abstract_core::objects::validation::ValidationError;
+use abstract_core::objects::AccountId;
 use abstract_core::AbstractError;
 use abstract_sdk::core::objects::module::ModuleInfo;
 use abstract_sdk::AbstractSdkError;
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Timestamp};
 use cw_controllers::AdminError;
 use thiserror::Error;
 
@@ -29,6 +30,9 @@ pub enum ManagerError {
     #[error("Module with id: {0} is already installed")]
     ModuleAlreadyInstalled(String),
 
+    #[error("Module {0} is not whitelisted on the proxy and can not be given permissions")]
+    NotProxyModule(String),
+
     #[error("Cannot remove module because {0:?} depend(s) on it.")]
     ModuleHasDependents(Vec<String>),
 
@@ -84,4 +88,97 @@ pub enum ManagerError {
 
     #[error("Must use SetOwner to change owner")]
     MustUseSetOwner {},
+
+    #[error("An ownership transfer is already scheduled")]
+    OwnerTransferAlreadyScheduled {},
+
+    #[error("No ownership transfer is scheduled")]
+    NoOwnerTransferScheduled {},
+
+    #[error("The ownership transfer can only be executed after {executable_at}")]
+    OwnerTransferNotReady { executable_at: Timestamp },
+
+    #[error("The ownership transfer expired at {expires_at}")]
+    OwnerTransferExpired { expires_at: Timestamp },
+
+    #[error("Invalid guardian configuration: {0}")]
+    InvalidGuardians(String),
+
+    #[error("{0} is not a guardian of this Account")]
+    NotGuardian(String),
+
+    #[error("A recovery is already pending")]
+    RecoveryAlreadyProposed {},
+
+    #[error("No recovery is pending")]
+    NoRecoveryProposed {},
+
+    #[error("Guardian {0} already approved the recovery")]
+    AlreadyApprovedRecovery(String),
+
+    #[error("Recovery has {approvals} approvals but requires {quorum}")]
+    RecoveryQuorumNotMet { approvals: u64, quorum: u64 },
+
+    #[error("A recovery approved by the guardians is pending")]
+    RecoveryApproved {},
+
+    #[error("Recovery can only be executed after {executable_at}")]
+    RecoveryNotReady { executable_at: Timestamp },
+
+    #[error("Batch and Callback actions can not be part of a batch")]
+    InvalidBatchAction {},
+
+    #[error("Funds sent with the batch do not match the funds of its actions")]
+    BatchFundsMismatch {},
+
+    #[error("Sub-account {0} is not owned by this Account")]
+    SubAccountNotFound(AccountId),
+
+    #[error("{0} is not the manager of sub-account {1}")]
+    NotSubAccountManager(String, AccountId),
+
+    #[error("Sub-account governance can only be set by the account factory")]
+    SubAccountGovernance {},
+
+    #[error("Account is not governed by a multisig")]
+    NotMultisig {},
+
+    #[error("{0} is not a member of the Account's multisig")]
+    NotMultisigMember(String),
+
+    #[error("A proposal must contain at least one action")]
+    EmptyProposal {},
+
+    #[error("{0} already approved proposal {1}")]
+    AlreadyVoted(String, u64),
+
+    #[error("Proposal {0} has already been executed")]
+    ProposalExecuted(u64),
+
+    #[error("Proposal {0} was made under a previous governance of the Account")]
+    ProposalInvalidated(u64),
+
+    #[error("Funds sent to execute proposal {0} don't match the funds of its actions")]
+    ProposalFundsMismatch(u64),
+
+    #[error(
+        "Proposal {proposal_id} has an approved weight of {approved} but requires {threshold}"
+    )]
+    ProposalThresholdNotMet {
+        proposal_id: u64,
+        approved: u64,
+        threshold: u64,
+    },
+
+    #[error("Module {0} is not allowed by the install policy of the Account")]
+    ModuleNotAllowed(String),
+
+    #[error("Module {0} is not monetized with a subscription")]
+    NoSubscription(String),
+
+    #[error("Subscription to module {module_id} lapsed at {paid_until}")]
+    SubscriptionLapsed {
+        module_id: String,
+        paid_until: Timestamp,
+    },
 }
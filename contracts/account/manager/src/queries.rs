@@ -1,8 +1,15 @@
-use abstract_core::manager::state::{Config, SUSPENSION_STATUS};
+use crate::{commands::proposal_weight, versioning};
+use abstract_core::manager::state::{
+    Config, Proposal, FIRST_VALID_PROPOSAL_ID, GUARDIANS, INSTALL_POLICY, PENDING_OWNER_TRANSFER,
+    PENDING_RECOVERY, PROPOSALS, SUBSCRIPTIONS, SUB_ACCOUNTS, SUSPENSION_STATUS,
+};
+use abstract_core::objects::AccountId;
 use abstract_sdk::core::manager::state::{AccountInfo, ACCOUNT_ID, ACCOUNT_MODULES, CONFIG, INFO};
 use abstract_sdk::core::manager::{
-    ConfigResponse, InfoResponse, ManagerModuleInfo, ModuleAddressesResponse, ModuleInfosResponse,
-    ModuleVersionsResponse,
+    ConfigResponse, GuardiansResponse, InfoResponse, InstallPolicyResponse, ManagerModuleInfo,
+    ModuleAddressesResponse, ModuleInfosResponse, ModuleVersionsResponse,
+    PendingOwnerTransferResponse, ProposalResponse, ProposalsResponse, SubAccountIdsResponse,
+    SubscriptionStatusResponse, UninstallPlanResponse,
 };
 use cosmwasm_std::{
     to_binary, Addr, Binary, Deps, Env, Order, QueryRequest, StdError, StdResult, Uint64, WasmQuery,
@@ -76,6 +83,116 @@ pub fn handle_module_info_query(
     })
 }
 
+pub fn handle_pending_owner_transfer_query(deps: Deps) -> StdResult<Binary> {
+    let transfer = PENDING_OWNER_TRANSFER.may_load(deps.storage)?;
+    to_binary(&PendingOwnerTransferResponse { transfer })
+}
+
+pub fn handle_guardians_query(deps: Deps) -> StdResult<Binary> {
+    to_binary(&GuardiansResponse {
+        guardians: GUARDIANS.may_load(deps.storage)?,
+        recovery: PENDING_RECOVERY.may_load(deps.storage)?,
+    })
+}
+
+pub fn handle_subscription_status_query(
+    deps: Deps,
+    env: Env,
+    module_id: String,
+) -> StdResult<Binary> {
+    let subscription = SUBSCRIPTIONS.may_load(deps.storage, &module_id)?;
+    let is_active = subscription
+        .as_ref()
+        .map_or(true, |subscription| subscription.is_active(env.block.time));
+    to_binary(&SubscriptionStatusResponse {
+        subscription,
+        is_active,
+    })
+}
+
+pub fn handle_install_policy_query(deps: Deps) -> StdResult<Binary> {
+    let install_policy = INSTALL_POLICY.may_load(deps.storage)?.unwrap_or_default();
+    to_binary(&InstallPolicyResponse { install_policy })
+}
+
+pub fn handle_uninstall_plan_query(deps: Deps, module_id: String) -> StdResult<Binary> {
+    if !ACCOUNT_MODULES.has(deps.storage, &module_id) {
+        return Err(StdError::generic_err(format!(
+            "module {module_id} is not installed"
+        )));
+    }
+    let modules = versioning::uninstall_order(deps.storage, &module_id)?;
+    to_binary(&UninstallPlanResponse { modules })
+}
+
+pub fn handle_sub_accounts_query(
+    deps: Deps,
+    start_after: Option<AccountId>,
+    limit: Option<u8>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.map(Bound::exclusive);
+
+    let sub_accounts = SUB_ACCOUNTS
+        .keys(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    to_binary(&SubAccountIdsResponse { sub_accounts })
+}
+
+pub fn handle_proposal_query(deps: Deps, proposal_id: u64) -> StdResult<Binary> {
+    let info: AccountInfo = INFO.load(deps.storage)?;
+    let first_valid = FIRST_VALID_PROPOSAL_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    to_binary(&proposal_response(
+        &info,
+        first_valid,
+        proposal_id,
+        proposal,
+    ))
+}
+
+pub fn handle_proposals_query(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u8>,
+) -> StdResult<Binary> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after.map(Bound::exclusive);
+    let info: AccountInfo = INFO.load(deps.storage)?;
+    let first_valid = FIRST_VALID_PROPOSAL_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+
+    let proposals = PROPOSALS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|res| res.map(|(id, proposal)| proposal_response(&info, first_valid, id, proposal)))
+        .collect::<StdResult<_>>()?;
+
+    to_binary(&ProposalsResponse { proposals })
+}
+
+fn proposal_response(
+    info: &AccountInfo,
+    first_valid: u64,
+    id: u64,
+    proposal: Proposal,
+) -> ProposalResponse {
+    let (approved_weight, threshold) =
+        proposal_weight(&info.governance_details, &proposal.approvals);
+    ProposalResponse {
+        id,
+        proposal,
+        approved_weight,
+        threshold,
+        valid: id >= first_valid,
+    }
+}
+
 /// RawQuery the version of an enabled module
 pub fn query_module_cw2(deps: &Deps, module_addr: Addr) -> StdResult<ContractVersion> {
     let req = QueryRequest::Wasm(WasmQuery::Raw {
@@ -2,8 +2,9 @@ use abstract_core::{
     manager::state::{ACCOUNT_MODULES, DEPENDENTS},
     objects::{dependency::Dependency, module_version::MODULE},
 };
-use cosmwasm_std::{Deps, DepsMut, StdError, Storage};
+use cosmwasm_std::{Deps, DepsMut, StdError, StdResult, Storage};
 use cw_semver::{Comparator, Version};
+use std::collections::HashSet;
 
 use crate::{commands::MIGRATE_CONTEXT, contract::ManagerResult, error::ManagerError};
 
@@ -84,6 +85,39 @@ pub fn remove_as_dependent(
     Ok(())
 }
 
+/// Returns the module and all the modules that (transitively) depend on it,
+/// ordered such that every module comes before its dependencies.
+pub fn uninstall_order(storage: &dyn Storage, module_id: &str) -> StdResult<Vec<String>> {
+    let mut order = vec![];
+    let mut visited = HashSet::new();
+    add_dependents_first(storage, module_id, &mut visited, &mut order)?;
+    Ok(order)
+}
+
+/// Depth-first traversal of the dependents, adding a module after all its dependents.
+fn add_dependents_first(
+    storage: &dyn Storage,
+    module_id: &str,
+    visited: &mut HashSet<String>,
+    order: &mut Vec<String>,
+) -> StdResult<()> {
+    if !visited.insert(module_id.to_string()) {
+        return Ok(());
+    }
+    let mut dependents: Vec<String> = DEPENDENTS
+        .may_load(storage, module_id)?
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    // sort for a deterministic order
+    dependents.sort();
+    for dependent in dependents {
+        add_dependents_first(storage, &dependent, visited, order)?;
+    }
+    order.push(module_id.to_string());
+    Ok(())
+}
+
 fn assert_comparators(
     bounds: &[Comparator],
     version: &Version,
@@ -186,8 +220,6 @@ mod test {
 
     use cosmwasm_std::testing::mock_dependencies;
 
-    use std::collections::HashSet;
-
     mod set_as_dependent {
         use super::*;
 
@@ -258,4 +290,39 @@ mod test {
             assert_that(&remaining_dex_dependents).is_empty();
         }
     }
+
+    mod uninstall_order {
+        use super::*;
+
+        fn add_dependents(storage: &mut dyn Storage, module_id: &str, dependents: &[&str]) {
+            let dependents: HashSet<String> = dependents.iter().map(|d| d.to_string()).collect();
+            DEPENDENTS.save(storage, module_id, &dependents).unwrap();
+        }
+
+        #[test]
+        fn without_dependents() {
+            let deps = mock_dependencies();
+            let order = uninstall_order(&deps.storage, "module").unwrap();
+            assert_that!(order).is_equal_to(vec!["module".to_string()]);
+        }
+
+        #[test]
+        fn dependents_before_dependencies() {
+            let mut deps = mock_dependencies();
+            // app depends on both the dex and the staking adapter, staking depends on dex
+            add_dependents(&mut deps.storage, "dex", &["staking", "app"]);
+            add_dependents(&mut deps.storage, "staking", &["app"]);
+            add_dependents(&mut deps.storage, "app", &[]);
+
+            let order = uninstall_order(&deps.storage, "dex").unwrap();
+            assert_that!(order).is_equal_to(vec![
+                "app".to_string(),
+                "staking".to_string(),
+                "dex".to_string(),
+            ]);
+
+            let order = uninstall_order(&deps.storage, "staking").unwrap();
+            assert_that!(order).is_equal_to(vec!["app".to_string(), "staking".to_string()]);
+        }
+    }
 }
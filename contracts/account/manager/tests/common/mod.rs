@@ -65,6 +65,7 @@ pub(crate) fn add_mock_adapter_install_fee(
         TEST_MODULE_NAME.to_string(),
         monetization,
         Namespace::new(TEST_NAMESPACE).unwrap(),
+        None,
     )?;
     Ok(())
 }
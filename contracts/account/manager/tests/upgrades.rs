@@ -6,7 +6,9 @@ use abstract_core::{
     objects::module::{ModuleInfo, ModuleVersion},
     AbstractError,
 };
-use abstract_interface::{Abstract, AbstractAccount, Manager, ManagerExecFns, VCExecFns};
+use abstract_interface::{
+    Abstract, AbstractAccount, Manager, ManagerExecFns, ManagerQueryFns, VCExecFns,
+};
 
 use abstract_manager::error::ManagerError;
 use abstract_testing::addresses::{TEST_ACCOUNT_ID, TEST_NAMESPACE};
@@ -310,6 +312,35 @@ fn uninstall_modules() -> AResult {
     Ok(())
 }
 
+#[test]
+fn uninstall_modules_cascade() -> AResult {
+    let sender = Addr::unchecked(common::OWNER);
+    let chain = Mock::new(&sender);
+    let abstr = Abstract::deploy_on(chain.clone(), Empty {})?;
+    let account = create_default_account(&abstr.account_factory)?;
+    let AbstractAccount { manager, proxy: _ } = &account;
+    abstr
+        .version_control
+        .claim_namespace(TEST_ACCOUNT_ID, TEST_NAMESPACE.to_string())?;
+    deploy_modules(&chain);
+
+    let adapter1 = install_module_version(manager, &abstr, adapter_1::MOCK_ADAPTER_ID, V1)?;
+    let adapter2 = install_module_version(manager, &abstr, adapter_2::MOCK_ADAPTER_ID, V1)?;
+    let app1 = install_module_version(manager, &abstr, app_1::MOCK_APP_ID, V1)?;
+    account.expect_modules(vec![adapter1, adapter2.clone(), app1])?;
+
+    // the app depends on adapter 1 so it is uninstalled first
+    let plan = manager.uninstall_plan(adapter_1::MOCK_ADAPTER_ID.to_string())?;
+    assert_that!(plan.modules).is_equal_to(vec![
+        app_1::MOCK_APP_ID.to_string(),
+        adapter_1::MOCK_ADAPTER_ID.to_string(),
+    ]);
+
+    manager.uninstall_module_cascade(adapter_1::MOCK_ADAPTER_ID.to_string())?;
+    account.expect_modules(vec![adapter2])?;
+    Ok(())
+}
+
 #[test]
 fn update_adapter_with_authorized_addrs() -> AResult {
     let sender = Addr::unchecked(common::OWNER);
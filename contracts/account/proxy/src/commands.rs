@@ -1,19 +1,34 @@
 use crate::contract::{ProxyResponse, ProxyResult, RESPONSE_REPLY_ID};
 use crate::error::ProxyError;
+use crate::permissions::{
+    assert_ibc_outflow_limits, assert_ibc_permissions, assert_module_permissions,
+    assert_outflow_limits, assert_subscription_active,
+};
 use abstract_core::objects::{oracle::Oracle, price_source::UncheckedPriceSource, AssetEntry};
 use abstract_sdk::core::{
     ibc_client::ExecuteMsg as IbcClientMsg,
-    proxy::state::{ADMIN, ANS_HOST, STATE},
+    proxy::{
+        state::{
+            ADMIN, ANS_HOST, INCLUDE_REMOTE_BALANCES, MODULE_PERMISSIONS, MODULE_SPENDING,
+            OUTFLOW_BUCKETS, OUTFLOW_LIMITS, STATE,
+        },
+        ModulePermissionsUnchecked, OutflowLimit,
+    },
     IBC_CLIENT,
 };
-use cosmwasm_std::{wasm_execute, CosmosMsg, DepsMut, Empty, MessageInfo, StdError, SubMsg};
+use abstract_sdk::Resolve;
+use cosmwasm_std::{
+    wasm_execute, Addr, CosmosMsg, DepsMut, Empty, Env, MessageInfo, Order, StdError, StdResult,
+    SubMsg,
+};
 
 const LIST_SIZE_LIMIT: usize = 15;
 
 /// Executes actions forwarded by whitelisted contracts
 /// This contracts acts as a proxy contract for the dApps
 pub fn execute_module_action(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     msgs: Vec<CosmosMsg<Empty>>,
 ) -> ProxyResult {
@@ -21,6 +36,9 @@ pub fn execute_module_action(
     if !state.modules.contains(&msg_info.sender) {
         return Err(ProxyError::SenderNotWhitelisted {});
     }
+    assert_subscription_active(deps.as_ref(), &env, &msg_info.sender)?;
+    assert_module_permissions(deps.branch(), &env, &msg_info.sender, &msgs)?;
+    assert_outflow_limits(deps, &env, &msgs)?;
 
     Ok(ProxyResponse::action("execute_module_action").add_messages(msgs))
 }
@@ -28,7 +46,8 @@ pub fn execute_module_action(
 /// Executes actions forwarded by whitelisted contracts
 /// This contracts acts as a proxy contract for the dApps
 pub fn execute_module_action_response(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     msg: CosmosMsg<Empty>,
 ) -> ProxyResult {
@@ -36,6 +55,10 @@ pub fn execute_module_action_response(
     if !state.modules.contains(&msg_info.sender) {
         return Err(ProxyError::SenderNotWhitelisted {});
     }
+    assert_subscription_active(deps.as_ref(), &env, &msg_info.sender)?;
+    let msgs = std::slice::from_ref(&msg);
+    assert_module_permissions(deps.branch(), &env, &msg_info.sender, msgs)?;
+    assert_outflow_limits(deps, &env, msgs)?;
 
     let submsg = SubMsg::reply_on_success(msg, RESPONSE_REPLY_ID);
 
@@ -45,7 +68,8 @@ pub fn execute_module_action_response(
 /// Executes IBC actions forwarded by whitelisted contracts
 /// Calls the messages on the IBC client (ensuring permission)
 pub fn execute_ibc_action(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     msgs: Vec<IbcClientMsg>,
 ) -> ProxyResult {
@@ -53,6 +77,9 @@ pub fn execute_ibc_action(
     if !state.modules.contains(&msg_info.sender) {
         return Err(ProxyError::SenderNotWhitelisted {});
     }
+    assert_subscription_active(deps.as_ref(), &env, &msg_info.sender)?;
+    assert_ibc_permissions(deps.branch(), &env, &msg_info.sender, &msgs)?;
+    assert_ibc_outflow_limits(deps.branch(), &env, &msgs)?;
     let manager_address = ADMIN.get(deps.as_ref())?.unwrap();
     let ibc_client_address = abstract_sdk::core::manager::state::ACCOUNT_MODULES
         .query(&deps.querier, manager_address, IBC_CLIENT)?
@@ -85,8 +112,61 @@ pub fn update_assets(
     Ok(ProxyResponse::action("update_proxy_assets"))
 }
 
+/// Accumulate the pool ratios of the assets priced by a time-weighted average
+/// Anyone can call this method
+pub fn update_twaps(deps: DepsMut, env: Env) -> ProxyResult {
+    Oracle::new().update_twaps(deps, &env)?;
+    Ok(ProxyResponse::action("update_twaps"))
+}
+
+/// Set whether the remote balances of the Account are included in its total value
+pub fn update_remote_balances(deps: DepsMut, msg_info: MessageInfo, include: bool) -> ProxyResult {
+    // Only Admin can call this method
+    ADMIN.assert_admin(deps.as_ref(), &msg_info.sender)?;
+    INCLUDE_REMOTE_BALANCES.save(deps.storage, &include)?;
+    Ok(ProxyResponse::new(
+        "update_remote_balances",
+        vec![("include", include.to_string())],
+    ))
+}
+
+/// Update the outflow limits applied to assets sent through module actions
+pub fn update_outflow_limits(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    to_add: Vec<(AssetEntry, OutflowLimit)>,
+    to_remove: Vec<AssetEntry>,
+) -> ProxyResult {
+    // Only Admin can call this method
+    ADMIN.assert_admin(deps.as_ref(), &msg_info.sender)?;
+    let ans_host = ANS_HOST.load(deps.storage)?;
+
+    for entry in to_remove {
+        let asset = entry.resolve(&deps.querier, &ans_host)?;
+        OUTFLOW_LIMITS.remove(deps.storage, &asset);
+        OUTFLOW_BUCKETS.remove(deps.storage, &asset);
+    }
+
+    for (entry, limit) in to_add {
+        if limit.window == 0 {
+            return Err(ProxyError::BadUpdate(format!(
+                "outflow limit window of {entry} must be non-zero"
+            )));
+        }
+        let asset = entry.resolve(&deps.querier, &ans_host)?;
+        OUTFLOW_LIMITS.save(deps.storage, &asset, &(entry, limit))?;
+    }
+
+    Ok(ProxyResponse::action("update_outflow_limits"))
+}
+
 /// Add a contract to the whitelist
-pub fn add_module(deps: DepsMut, msg_info: MessageInfo, module: String) -> ProxyResult {
+pub fn add_module(
+    mut deps: DepsMut,
+    msg_info: MessageInfo,
+    module: String,
+    permissions: Option<ModulePermissionsUnchecked>,
+) -> ProxyResult {
     ADMIN.assert_admin(deps.as_ref(), &msg_info.sender)?;
 
     let mut state = STATE.load(deps.storage)?;
@@ -102,6 +182,10 @@ pub fn add_module(deps: DepsMut, msg_info: MessageInfo, module: String) -> Proxy
         return Err(ProxyError::AlreadyWhitelisted(module));
     }
 
+    if let Some(permissions) = permissions {
+        save_module_permissions(deps.branch(), &module_addr, permissions)?;
+    }
+
     // Add contract to whitelist.
     state.modules.push(module_addr);
     STATE.save(deps.storage, &state)?;
@@ -114,9 +198,8 @@ pub fn add_module(deps: DepsMut, msg_info: MessageInfo, module: String) -> Proxy
 pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: String) -> ProxyResult {
     ADMIN.assert_admin(deps.as_ref(), &msg_info.sender)?;
 
+    let module_address = deps.api.addr_validate(&module)?;
     STATE.update(deps.storage, |mut state| {
-        let module_address = deps.api.addr_validate(&module)?;
-
         if !state.modules.contains(&module_address) {
             return Err(ProxyError::NotWhitelisted(module.clone()));
         }
@@ -124,6 +207,8 @@ pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: String) -> Pr
         state.modules.retain(|addr| *addr != module_address);
         Ok(state)
     })?;
+    MODULE_PERMISSIONS.remove(deps.storage, &module_address);
+    clear_module_spending(deps, &module_address)?;
 
     // Respond and note the change
     Ok(ProxyResponse::new(
@@ -132,6 +217,62 @@ pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: String) -> Pr
     ))
 }
 
+/// Set or clear the permission scopes of a whitelisted module
+pub fn update_module_permissions(
+    mut deps: DepsMut,
+    msg_info: MessageInfo,
+    module: String,
+    permissions: Option<ModulePermissionsUnchecked>,
+) -> ProxyResult {
+    ADMIN.assert_admin(deps.as_ref(), &msg_info.sender)?;
+
+    let module_addr = deps.api.addr_validate(&module)?;
+    if !STATE.load(deps.storage)?.modules.contains(&module_addr) {
+        return Err(ProxyError::NotWhitelisted(module));
+    }
+
+    // Spending is tracked against the previous limits, start over.
+    clear_module_spending(deps.branch(), &module_addr)?;
+    match permissions {
+        Some(permissions) => save_module_permissions(deps, &module_addr, permissions)?,
+        None => MODULE_PERMISSIONS.remove(deps.storage, &module_addr),
+    }
+
+    Ok(ProxyResponse::new(
+        "update_module_permissions",
+        vec![("module", module)],
+    ))
+}
+
+fn save_module_permissions(
+    deps: DepsMut,
+    module: &Addr,
+    permissions: ModulePermissionsUnchecked,
+) -> ProxyResult<()> {
+    let permissions = permissions.check(deps.api)?;
+    for (i, limit) in permissions.spend_limits.iter().enumerate() {
+        if permissions.spend_limits[..i]
+            .iter()
+            .any(|other| other.asset == limit.asset)
+        {
+            return Err(ProxyError::DuplicateSpendLimit(limit.asset.to_string()));
+        }
+    }
+    MODULE_PERMISSIONS.save(deps.storage, module, &permissions)?;
+    Ok(())
+}
+
+fn clear_module_spending(deps: DepsMut, module: &Addr) -> StdResult<()> {
+    let assets = MODULE_SPENDING
+        .prefix(module)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for asset in assets {
+        MODULE_SPENDING.remove(deps.storage, (module, &asset));
+    }
+    Ok(())
+}
+
 pub fn set_admin(deps: DepsMut, info: MessageInfo, admin: &String) -> ProxyResult {
     let admin_addr = deps.api.addr_validate(admin)?;
     let previous_admin = ADMIN.get(deps.as_ref())?.unwrap();
@@ -190,6 +331,7 @@ mod test {
 
             let msg = ExecuteMsg::AddModule {
                 module: TEST_MODULE.to_string(),
+                permissions: None,
             };
             let info = mock_info("not_admin", &[]);
 
@@ -206,6 +348,7 @@ mod test {
 
             let msg = ExecuteMsg::AddModule {
                 module: TEST_MODULE.to_string(),
+                permissions: None,
             };
 
             let res = execute_as_admin(&mut deps, msg);
@@ -223,6 +366,7 @@ mod test {
 
             let msg = ExecuteMsg::AddModule {
                 module: TEST_MODULE.to_string(),
+                permissions: None,
             };
 
             let res = execute_as_admin(&mut deps, msg.clone());
@@ -241,11 +385,13 @@ mod test {
 
             let mut msg = ExecuteMsg::AddModule {
                 module: TEST_MODULE.to_string(),
+                permissions: None,
             };
 
             for i in 0..LIST_SIZE_LIMIT {
                 msg = ExecuteMsg::AddModule {
                     module: format!("module_{i}"),
+                    permissions: None,
                 };
                 let res = execute_as_admin(&mut deps, msg.clone());
                 assert_that(&res).is_ok();
@@ -347,6 +493,7 @@ mod test {
         #[test]
         fn forwards_action() -> ProxyTestResult {
             let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER).build();
             mock_init(deps.as_mut());
 
             // stub a module
@@ -385,9 +532,457 @@ mod test {
         }
     }
 
+    mod subscription {
+        use super::*;
+        use abstract_core::manager::state::{ModuleSubscription, MODULE_IDS, SUBSCRIPTIONS};
+        use abstract_core::proxy::state::State;
+        use cosmwasm_std::coin;
+
+        fn execute_as_module(deps: &mut MockDeps, env: Env) -> ProxyResult {
+            execute(
+                deps.as_mut(),
+                env,
+                mock_info(TEST_MODULE, &[]),
+                ExecuteMsg::ModuleAction { msgs: vec![] },
+            )
+        }
+
+        #[test]
+        fn lapsed_subscription_blocks_module_actions() -> ProxyTestResult {
+            let paid_until = mock_env().block.time.plus_seconds(100);
+            let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER)
+                .with_contract_map_entry(
+                    TEST_MANAGER,
+                    MODULE_IDS,
+                    (&Addr::unchecked(TEST_MODULE), "test:module".to_string()),
+                )
+                .with_contract_map_entry(
+                    TEST_MANAGER,
+                    SUBSCRIPTIONS,
+                    (
+                        "test:module",
+                        ModuleSubscription {
+                            per_period: coin(10, "ujuno"),
+                            period_seconds: 100,
+                            paid_until,
+                        },
+                    ),
+                )
+                .build();
+            mock_init(deps.as_mut());
+            STATE.save(
+                &mut deps.storage,
+                &State {
+                    modules: vec![Addr::unchecked(TEST_MODULE)],
+                },
+            )?;
+
+            execute_as_module(&mut deps, mock_env())?;
+
+            let mut env = mock_env();
+            env.block.time = paid_until;
+            let res = execute_as_module(&mut deps, env);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::SubscriptionLapsed {
+                    module: "test:module".to_string(),
+                    paid_until,
+                });
+            Ok(())
+        }
+
+        #[test]
+        fn unknown_manager_blocks_module_actions() -> ProxyTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut());
+            STATE.save(
+                &mut deps.storage,
+                &State {
+                    modules: vec![Addr::unchecked(TEST_MODULE)],
+                },
+            )?;
+
+            // the subscription can't be checked without the manager
+            let res = execute_as_module(&mut deps, mock_env());
+            assert_that(&res).is_err();
+            Ok(())
+        }
+    }
+
+    mod module_permissions {
+        use super::*;
+        use abstract_core::proxy::{CosmosMsgKind, SpendLimitUnchecked};
+        use cosmwasm_std::{coins, BankMsg, Binary, DistributionMsg, Uint128, WasmMsg};
+        use cw_asset::AssetInfoBase;
+
+        fn add_restricted_module(deps: &mut MockDeps, permissions: ModulePermissionsUnchecked) {
+            execute_as_admin(
+                deps,
+                ExecuteMsg::AddModule {
+                    module: TEST_MODULE.to_string(),
+                    permissions: Some(permissions),
+                },
+            )
+            .unwrap();
+        }
+
+        fn send(amount: u128, denom: &str) -> CosmosMsg {
+            BankMsg::Send {
+                to_address: "receiver".to_string(),
+                amount: coins(amount, denom),
+            }
+            .into()
+        }
+
+        fn execute_as_module(deps: &mut MockDeps, env: Env, msgs: Vec<CosmosMsg>) -> ProxyResult {
+            execute(
+                deps.as_mut(),
+                env,
+                mock_info(TEST_MODULE, &[]),
+                ExecuteMsg::ModuleAction { msgs },
+            )
+        }
+
+        fn unrestricted() -> ModulePermissionsUnchecked {
+            ModulePermissionsUnchecked {
+                allowed_msgs: None,
+                allowed_assets: None,
+                spend_limits: vec![],
+            }
+        }
+
+        #[test]
+        fn restricts_msg_kinds() {
+            let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER).build();
+            mock_init(deps.as_mut());
+            add_restricted_module(
+                &mut deps,
+                ModulePermissionsUnchecked {
+                    allowed_msgs: Some(vec![CosmosMsgKind::Bank]),
+                    ..unrestricted()
+                },
+            );
+
+            let res = execute_as_module(&mut deps, mock_env(), vec![send(100, "uatom")]);
+            assert_that(&res).is_ok();
+
+            let wasm_msg: CosmosMsg = wasm_execute(
+                MOCK_CONTRACT_ADDR,
+                &ExecuteMsg::SetAdmin {
+                    admin: TEST_MANAGER.to_string(),
+                },
+                vec![],
+            )
+            .unwrap()
+            .into();
+            let res = execute_as_module(&mut deps, mock_env(), vec![send(100, "uatom"), wasm_msg]);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::MsgNotPermitted(TEST_MODULE.to_string()));
+        }
+
+        #[test]
+        fn restricts_assets() {
+            let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER).build();
+            mock_init(deps.as_mut());
+            add_restricted_module(
+                &mut deps,
+                ModulePermissionsUnchecked {
+                    allowed_assets: Some(vec![AssetInfoBase::native("uatom")]),
+                    ..unrestricted()
+                },
+            );
+
+            let res = execute_as_module(&mut deps, mock_env(), vec![send(100, "uatom")]);
+            assert_that(&res).is_ok();
+
+            let res = execute_as_module(&mut deps, mock_env(), vec![send(100, "ujuno")]);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::AssetNotPermitted {
+                    module: TEST_MODULE.to_string(),
+                    asset: "native:ujuno".to_string(),
+                });
+
+            let cw20_transfer: CosmosMsg = wasm_execute(
+                "cw20_token",
+                &cw20::Cw20ExecuteMsg::Transfer {
+                    recipient: "receiver".to_string(),
+                    amount: Uint128::new(100),
+                },
+                vec![],
+            )
+            .unwrap()
+            .into();
+            let res = execute_as_module(&mut deps, mock_env(), vec![cw20_transfer]);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::AssetNotPermitted {
+                    module: TEST_MODULE.to_string(),
+                    asset: "cw20:cw20_token".to_string(),
+                });
+
+            let community_pool: CosmosMsg = DistributionMsg::FundCommunityPool {
+                amount: coins(100, "ujuno"),
+            }
+            .into();
+            let res = execute_as_module(&mut deps, mock_env(), vec![community_pool]);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::AssetNotPermitted {
+                    module: TEST_MODULE.to_string(),
+                    asset: "native:ujuno".to_string(),
+                });
+
+            let instantiate2: CosmosMsg = WasmMsg::Instantiate2 {
+                admin: None,
+                code_id: 1,
+                label: "label".to_string(),
+                msg: Binary::default(),
+                funds: coins(100, "ujuno"),
+                salt: Binary::from(b"salt"),
+            }
+            .into();
+            let res = execute_as_module(&mut deps, mock_env(), vec![instantiate2]);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::AssetNotPermitted {
+                    module: TEST_MODULE.to_string(),
+                    asset: "native:ujuno".to_string(),
+                });
+        }
+
+        #[test]
+        fn rejects_unknown_outflows_of_restricted_modules() {
+            let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER).build();
+            mock_init(deps.as_mut());
+            let stargate = || CosmosMsg::Stargate {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: Binary::default(),
+            };
+
+            add_restricted_module(&mut deps, unrestricted());
+            let res = execute_as_module(&mut deps, mock_env(), vec![stargate()]);
+            assert_that(&res).is_ok();
+
+            execute_as_admin(
+                &mut deps,
+                ExecuteMsg::RemoveModule {
+                    module: TEST_MODULE.to_string(),
+                },
+            )
+            .unwrap();
+            add_restricted_module(
+                &mut deps,
+                ModulePermissionsUnchecked {
+                    allowed_assets: Some(vec![AssetInfoBase::native("uatom")]),
+                    ..unrestricted()
+                },
+            );
+            let res = execute_as_module(&mut deps, mock_env(), vec![stargate()]);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::UnknownOutflows(TEST_MODULE.to_string()));
+        }
+
+        #[test]
+        fn enforces_spend_limit_per_period() {
+            let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER).build();
+            mock_init(deps.as_mut());
+            add_restricted_module(
+                &mut deps,
+                ModulePermissionsUnchecked {
+                    spend_limits: vec![SpendLimitUnchecked {
+                        asset: AssetInfoBase::native("uatom"),
+                        amount: Uint128::new(100),
+                        period: 3600,
+                    }],
+                    ..unrestricted()
+                },
+            );
+
+            let mut env = mock_env();
+            let res = execute_as_module(&mut deps, env.clone(), vec![send(60, "uatom")]);
+            assert_that(&res).is_ok();
+
+            let res = execute_as_module(&mut deps, env.clone(), vec![send(50, "uatom")]);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::SpendLimitExceeded {
+                    module: TEST_MODULE.to_string(),
+                    asset: "native:uatom".to_string(),
+                    limit: Uint128::new(100),
+                    spent: Uint128::new(110),
+                });
+
+            // other assets are not limited
+            let res = execute_as_module(&mut deps, env.clone(), vec![send(1000, "ujuno")]);
+            assert_that(&res).is_ok();
+
+            // limit resets in the next period
+            env.block.time = env.block.time.plus_seconds(3600);
+            let res = execute_as_module(&mut deps, env, vec![send(100, "uatom")]);
+            assert_that(&res).is_ok();
+        }
+
+        fn ibc_action(msg: IbcClientMsg) -> ExecuteMsg {
+            ExecuteMsg::IbcAction { msgs: vec![msg] }
+        }
+
+        #[test]
+        fn restricts_ibc_actions() {
+            let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER).build();
+            mock_init(deps.as_mut());
+            add_restricted_module(
+                &mut deps,
+                ModulePermissionsUnchecked {
+                    allowed_msgs: Some(vec![CosmosMsgKind::Bank]),
+                    ..unrestricted()
+                },
+            );
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_MODULE, &[]),
+                ibc_action(IbcClientMsg::Register {
+                    host_chain: "juno".into(),
+                }),
+            );
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::MsgNotPermitted(TEST_MODULE.to_string()));
+        }
+
+        #[test]
+        fn ibc_funds_count_against_spend_limit() {
+            let mut deps = mock_dependencies();
+            deps.querier = manager_querier(TEST_MANAGER).build();
+            mock_init(deps.as_mut());
+            add_restricted_module(
+                &mut deps,
+                ModulePermissionsUnchecked {
+                    spend_limits: vec![SpendLimitUnchecked {
+                        asset: AssetInfoBase::native("uatom"),
+                        amount: Uint128::new(100),
+                        period: 3600,
+                    }],
+                    ..unrestricted()
+                },
+            );
+
+            let res = execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_MODULE, &[]),
+                ibc_action(IbcClientMsg::SendFunds {
+                    host_chain: "juno".into(),
+                    funds: coins(150, "uatom"),
+                    timeout: None,
+                }),
+            );
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::SpendLimitExceeded {
+                    module: TEST_MODULE.to_string(),
+                    asset: "native:uatom".to_string(),
+                    limit: Uint128::new(100),
+                    spent: Uint128::new(150),
+                });
+        }
+
+        #[test]
+        fn rejects_duplicate_spend_limits() {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut());
+
+            let limit = SpendLimitUnchecked {
+                asset: AssetInfoBase::native("uatom"),
+                amount: Uint128::new(100),
+                period: 3600,
+            };
+            let res = execute_as_admin(
+                &mut deps,
+                ExecuteMsg::AddModule {
+                    module: TEST_MODULE.to_string(),
+                    permissions: Some(ModulePermissionsUnchecked {
+                        spend_limits: vec![limit.clone(), limit],
+                        ..unrestricted()
+                    }),
+                },
+            );
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::DuplicateSpendLimit("native:uatom".to_string()));
+        }
+
+        #[test]
+        fn update_and_clear_permissions() {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut());
+
+            let msg = ExecuteMsg::UpdateModulePermissions {
+                module: TEST_MODULE.to_string(),
+                permissions: Some(unrestricted()),
+            };
+            let res = execute_as_admin(&mut deps, msg.clone());
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::NotWhitelisted(TEST_MODULE.to_string()));
+
+            add_restricted_module(
+                &mut deps,
+                ModulePermissionsUnchecked {
+                    allowed_msgs: Some(vec![]),
+                    ..unrestricted()
+                },
+            );
+            let res = execute_as_module(&mut deps, mock_env(), vec![send(1, "uatom")]);
+            assert_that(&res).is_err();
+
+            let res = execute_as_admin(
+                &mut deps,
+                ExecuteMsg::UpdateModulePermissions {
+                    module: TEST_MODULE.to_string(),
+                    permissions: None,
+                },
+            );
+            assert_that(&res).is_ok();
+            assert_that(&MODULE_PERMISSIONS.has(&deps.storage, &Addr::unchecked(TEST_MODULE)))
+                .is_false();
+
+            let res = execute_as_module(&mut deps, mock_env(), vec![send(1, "uatom")]);
+            assert_that(&res).is_ok();
+        }
+
+        #[test]
+        fn remove_module_clears_permissions() {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut());
+            add_restricted_module(&mut deps, unrestricted());
+
+            execute_as_admin(
+                &mut deps,
+                ExecuteMsg::RemoveModule {
+                    module: TEST_MODULE.to_string(),
+                },
+            )
+            .unwrap();
+
+            assert_that(&MODULE_PERMISSIONS.has(&deps.storage, &Addr::unchecked(TEST_MODULE)))
+                .is_false();
+        }
+    }
+
     mod execute_ibc {
         use abstract_core::{manager, proxy::state::State};
-        use abstract_testing::{prelude::TEST_MANAGER, MockQuerierBuilder};
+        use abstract_testing::prelude::TEST_MANAGER;
         use cosmwasm_std::{to_binary, SubMsg};
 
         use super::*;
@@ -419,7 +1014,7 @@ mod test {
             // ibc not enabled
             execute(deps.as_mut(), mock_env(), manager_info.clone(), msg.clone()).unwrap_err();
             // mock enabling ibc
-            deps.querier = MockQuerierBuilder::default()
+            deps.querier = manager_querier(TEST_MANAGER)
                 .with_contract_map_entry(
                     TEST_MANAGER,
                     manager::state::ACCOUNT_MODULES,
@@ -441,4 +1036,59 @@ mod test {
             )));
         }
     }
+
+    mod update_twaps {
+        use super::*;
+
+        #[test]
+        fn permissionless() -> ProxyTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut());
+
+            let msg = ExecuteMsg::UpdateTwaps {};
+            let info = mock_info("not_admin", &[]);
+
+            execute(deps.as_mut(), mock_env(), info, msg)?;
+            Ok(())
+        }
+    }
+
+    mod update_remote_balances {
+        use cw_controllers::AdminError;
+
+        use super::*;
+
+        #[test]
+        fn only_admin() {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut());
+
+            let msg = ExecuteMsg::UpdateRemoteBalances { include: true };
+            let info = mock_info("not_admin", &[]);
+
+            let res = execute(deps.as_mut(), mock_env(), info, msg);
+            assert_that(&res)
+                .is_err()
+                .is_equal_to(ProxyError::Admin(AdminError::NotAdmin {}))
+        }
+
+        #[test]
+        fn sets_include_remote_balances() -> ProxyTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut());
+
+            execute_as_admin(
+                &mut deps,
+                ExecuteMsg::UpdateRemoteBalances { include: true },
+            )?;
+            assert_that!(INCLUDE_REMOTE_BALANCES.load(&deps.storage)?).is_true();
+
+            execute_as_admin(
+                &mut deps,
+                ExecuteMsg::UpdateRemoteBalances { include: false },
+            )?;
+            assert_that!(INCLUDE_REMOTE_BALANCES.load(&deps.storage)?).is_false();
+            Ok(())
+        }
+    }
 }
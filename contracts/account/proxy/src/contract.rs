@@ -57,17 +57,31 @@ pub fn instantiate(
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: ExecuteMsg) -> ProxyResult {
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> ProxyResult {
     match msg {
-        ExecuteMsg::ModuleAction { msgs } => execute_module_action(deps, info, msgs),
-        ExecuteMsg::ModuleActionWithData { msg } => execute_module_action_response(deps, info, msg),
-        ExecuteMsg::IbcAction { msgs } => execute_ibc_action(deps, info, msgs),
+        ExecuteMsg::ModuleAction { msgs } => execute_module_action(deps, env, info, msgs),
+        ExecuteMsg::ModuleActionWithData { msg } => {
+            execute_module_action_response(deps, env, info, msg)
+        }
+        ExecuteMsg::IbcAction { msgs } => execute_ibc_action(deps, env, info, msgs),
         ExecuteMsg::SetAdmin { admin } => set_admin(deps, info, &admin),
-        ExecuteMsg::AddModule { module } => add_module(deps, info, module),
+        ExecuteMsg::AddModule {
+            module,
+            permissions,
+        } => add_module(deps, info, module, permissions),
+        ExecuteMsg::UpdateModulePermissions {
+            module,
+            permissions,
+        } => update_module_permissions(deps, info, module, permissions),
         ExecuteMsg::RemoveModule { module } => remove_module(deps, info, module),
         ExecuteMsg::UpdateAssets { to_add, to_remove } => {
             update_assets(deps, info, to_add, to_remove)
         }
+        ExecuteMsg::UpdateOutflowLimits { to_add, to_remove } => {
+            update_outflow_limits(deps, info, to_add, to_remove)
+        }
+        ExecuteMsg::UpdateRemoteBalances { include } => update_remote_balances(deps, info, include),
+        ExecuteMsg::UpdateTwaps {} => update_twaps(deps, env),
     }
 }
 
@@ -101,6 +115,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> ProxyResult<Binary> {
             to_binary(&query_oracle_asset_info(deps, start_after, limit)?)
         }
         QueryMsg::BaseAsset {} => to_binary(&query_base_asset(deps)?),
+        QueryMsg::ModulePermissions { module } => {
+            to_binary(&query_module_permissions(deps, module)?)
+        }
+        QueryMsg::OutflowUsage { asset } => to_binary(&query_outflow_usage(deps, env, asset)?),
     }
     .map_err(Into::into)
 }
@@ -1,6 +1,6 @@
 use abstract_core::AbstractError;
 use abstract_sdk::AbstractSdkError;
-use cosmwasm_std::{StdError, Uint128};
+use cosmwasm_std::{StdError, Timestamp, Uint128};
 use cw_asset::AssetError;
 use cw_utils::ParseReplyError;
 use thiserror::Error;
@@ -34,6 +34,39 @@ pub enum ProxyError {
     #[error("Sender is not whitelisted")]
     SenderNotWhitelisted {},
 
+    #[error("Subscription to module {module} lapsed at {paid_until}")]
+    SubscriptionLapsed {
+        module: String,
+        paid_until: Timestamp,
+    },
+
+    #[error("Module {0} is not permitted to execute this message type")]
+    MsgNotPermitted(String),
+
+    #[error("Module {0} is not permitted to execute messages that move unknown assets")]
+    UnknownOutflows(String),
+
+    #[error("Module {module} is not permitted to send asset {asset}")]
+    AssetNotPermitted { module: String, asset: String },
+
+    #[error("Module {module} exceeded its spend limit of {limit} for asset {asset}, {spent} spent in the current period")]
+    SpendLimitExceeded {
+        module: String,
+        asset: String,
+        limit: Uint128,
+        spent: Uint128,
+    },
+
+    #[error("Outflow limit of {limit} for asset {asset} exceeded, {used} would be spent within the window")]
+    OutflowLimitExceeded {
+        asset: String,
+        limit: Uint128,
+        used: Uint128,
+    },
+
+    #[error("Multiple spend limits set for asset {0}")]
+    DuplicateSpendLimit(String),
+
     #[error("Max amount of assets registered")]
     AssetsLimitReached,
 
@@ -43,6 +76,9 @@ pub enum ProxyError {
     #[error("no base asset registered on proxy")]
     MissingBaseAsset,
 
+    #[error("no manager set as admin of the proxy")]
+    MissingManager,
+
     #[error("The proposed update resulted in a bad configuration: {0}")]
     BadUpdate(String),
 
@@ -1,6 +1,7 @@
 mod commands;
 pub mod contract;
 mod error;
+mod permissions;
 mod queries;
 pub mod reply;
 
@@ -10,7 +11,7 @@ mod test_common {
     use abstract_core::proxy::InstantiateMsg;
     use abstract_testing::prelude::*;
     use cosmwasm_std::testing::{mock_env, mock_info, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::DepsMut;
+    use cosmwasm_std::{Binary, DepsMut};
 
     pub fn mock_init(deps: DepsMut) {
         let info = mock_info(TEST_MANAGER, &[]);
@@ -20,4 +21,9 @@ mod test_common {
         };
         let _res = contract::instantiate(deps, mock_env(), info, msg).unwrap();
     }
+
+    /// Querier of a manager without module ids or subscriptions
+    pub fn manager_querier(manager: &str) -> MockQuerierBuilder {
+        MockQuerierBuilder::default().with_raw_handler(manager, |_| Ok(Binary::default()))
+    }
 }
@@ -0,0 +1,283 @@
+use crate::contract::ProxyResult;
+use crate::error::ProxyError;
+use abstract_core::ibc_client::ExecuteMsg as IbcClientMsg;
+use abstract_core::manager::state::{MODULE_IDS, SUBSCRIPTIONS};
+use abstract_core::proxy::{
+    state::{ADMIN, MODULE_PERMISSIONS, MODULE_SPENDING, OUTFLOW_BUCKETS, OUTFLOW_LIMITS},
+    CosmosMsgKind, ModulePermissions, SpendingPeriod,
+};
+use cosmwasm_std::{
+    from_binary, Addr, BankMsg, CosmosMsg, Deps, DepsMut, DistributionMsg, Empty, Env, IbcMsg,
+    StakingMsg, StdError, StdResult, Storage, Timestamp, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_asset::Asset;
+use cw_asset::AssetInfo;
+
+/// Kind of the provided message, `None` if it is not recognized
+pub(crate) fn msg_kind(msg: &CosmosMsg<Empty>) -> Option<CosmosMsgKind> {
+    match msg {
+        CosmosMsg::Bank(_) => Some(CosmosMsgKind::Bank),
+        CosmosMsg::Wasm(_) => Some(CosmosMsgKind::Wasm),
+        CosmosMsg::Staking(_) => Some(CosmosMsgKind::Staking),
+        CosmosMsg::Distribution(_) => Some(CosmosMsgKind::Distribution),
+        CosmosMsg::Stargate { .. } => Some(CosmosMsgKind::Stargate),
+        CosmosMsg::Ibc(_) => Some(CosmosMsgKind::Ibc),
+        CosmosMsg::Gov(_) => Some(CosmosMsgKind::Gov),
+        _ => None,
+    }
+}
+
+/// Assets that leave the proxy when the provided message is executed.
+/// `None` if they can't be known, as for the opaque Stargate messages.
+pub(crate) fn outflows(msg: &CosmosMsg<Empty>) -> Option<Vec<Asset>> {
+    let assets = match msg {
+        CosmosMsg::Bank(BankMsg::Send { amount, .. })
+        | CosmosMsg::Bank(BankMsg::Burn { amount }) => amount.iter().map(Asset::from).collect(),
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) => {
+            let mut assets: Vec<Asset> = funds.iter().map(Asset::from).collect();
+            match from_binary(msg) {
+                Ok(Cw20ExecuteMsg::Transfer { amount, .. })
+                | Ok(Cw20ExecuteMsg::Send { amount, .. })
+                | Ok(Cw20ExecuteMsg::IncreaseAllowance { amount, .. })
+                | Ok(Cw20ExecuteMsg::Burn { amount }) => {
+                    assets.push(Asset::cw20(Addr::unchecked(contract_addr), amount))
+                }
+                _ => (),
+            }
+            assets
+        }
+        CosmosMsg::Wasm(WasmMsg::Instantiate { funds, .. })
+        | CosmosMsg::Wasm(WasmMsg::Instantiate2 { funds, .. }) => {
+            funds.iter().map(Asset::from).collect()
+        }
+        CosmosMsg::Distribution(DistributionMsg::FundCommunityPool { amount }) => {
+            amount.iter().map(Asset::from).collect()
+        }
+        CosmosMsg::Staking(StakingMsg::Delegate { amount, .. }) => vec![Asset::from(amount)],
+        CosmosMsg::Ibc(IbcMsg::Transfer { amount, .. }) => vec![Asset::from(amount)],
+        CosmosMsg::Stargate { .. } => return None,
+        _ => vec![],
+    };
+    Some(assets)
+}
+
+/// Asserts that the subscription of the module, kept by the manager, did not lapse.
+/// Modules are identified through the manager's [`MODULE_IDS`], modules without a subscription are always active.
+pub(crate) fn assert_subscription_active(deps: Deps, env: &Env, module: &Addr) -> ProxyResult<()> {
+    let Some(manager) = ADMIN.get(deps)? else {
+        return Ok(());
+    };
+    let Some(module_id) = MODULE_IDS.query(&deps.querier, manager.clone(), module)? else {
+        return Ok(());
+    };
+    match SUBSCRIPTIONS.query(&deps.querier, manager, &module_id)? {
+        Some(subscription) if !subscription.is_active(env.block.time) => {
+            Err(ProxyError::SubscriptionLapsed {
+                module: module_id,
+                paid_until: subscription.paid_until,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Asserts that the messages are within the permission scopes of the module and records its spending.
+/// Modules without permission scopes are unrestricted.
+pub(crate) fn assert_module_permissions(
+    deps: DepsMut,
+    env: &Env,
+    module: &Addr,
+    msgs: &[CosmosMsg<Empty>],
+) -> ProxyResult<()> {
+    let Some(permissions) = MODULE_PERMISSIONS.may_load(deps.storage, module)? else {
+        return Ok(());
+    };
+
+    // assets of restricted modules must be known
+    let restricts_assets =
+        permissions.allowed_assets.is_some() || !permissions.spend_limits.is_empty();
+    for msg in msgs {
+        assert_msg_kind_permitted(module, &permissions, msg_kind(msg))?;
+        let Some(assets) = outflows(msg) else {
+            if restricts_assets {
+                return Err(ProxyError::UnknownOutflows(module.to_string()));
+            }
+            continue;
+        };
+        for asset in assets {
+            record_module_outflow(deps.storage, env, module, &permissions, asset)?;
+        }
+    }
+    Ok(())
+}
+
+/// Asserts that the module is allowed to execute IBC actions and records the funds it sends to remote chains.
+/// Modules without permission scopes are unrestricted.
+pub(crate) fn assert_ibc_permissions(
+    deps: DepsMut,
+    env: &Env,
+    module: &Addr,
+    msgs: &[IbcClientMsg],
+) -> ProxyResult<()> {
+    let Some(permissions) = MODULE_PERMISSIONS.may_load(deps.storage, module)? else {
+        return Ok(());
+    };
+
+    assert_msg_kind_permitted(module, &permissions, Some(CosmosMsgKind::Ibc))?;
+    for asset in msgs.iter().flat_map(ibc_outflows) {
+        record_module_outflow(deps.storage, env, module, &permissions, asset)?;
+    }
+    Ok(())
+}
+
+/// Assets that leave the proxy when the provided IBC client message is executed.
+fn ibc_outflows(msg: &IbcClientMsg) -> Vec<Asset> {
+    match msg {
+        IbcClientMsg::SendFunds { funds, .. } => funds.iter().map(Asset::from).collect(),
+        _ => vec![],
+    }
+}
+
+fn assert_msg_kind_permitted(
+    module: &Addr,
+    permissions: &ModulePermissions,
+    kind: Option<CosmosMsgKind>,
+) -> ProxyResult<()> {
+    match (&permissions.allowed_msgs, kind) {
+        (None, _) => Ok(()),
+        (Some(allowed_msgs), Some(kind)) if allowed_msgs.contains(&kind) => Ok(()),
+        _ => Err(ProxyError::MsgNotPermitted(module.to_string())),
+    }
+}
+
+/// Asserts that the module can move the asset out of the proxy and records it against its spend limit.
+fn record_module_outflow(
+    storage: &mut dyn Storage,
+    env: &Env,
+    module: &Addr,
+    permissions: &ModulePermissions,
+    asset: Asset,
+) -> ProxyResult<()> {
+    if let Some(allowed_assets) = &permissions.allowed_assets {
+        if !allowed_assets.contains(&asset.info) {
+            return Err(ProxyError::AssetNotPermitted {
+                module: module.to_string(),
+                asset: asset.info.to_string(),
+            });
+        }
+    }
+
+    let Some(limit) = permissions
+        .spend_limits
+        .iter()
+        .find(|limit| limit.asset == asset.info)
+    else {
+        return Ok(());
+    };
+
+    let key = (module, &asset.info);
+    let mut period = MODULE_SPENDING
+        .may_load(storage, key)?
+        .filter(|period| env.block.time < period.start.plus_seconds(limit.period))
+        .unwrap_or(SpendingPeriod {
+            start: env.block.time,
+            spent: Uint128::zero(),
+        });
+    period.spent = period
+        .spent
+        .checked_add(asset.amount)
+        .map_err(StdError::from)?;
+    if period.spent > limit.amount {
+        return Err(ProxyError::SpendLimitExceeded {
+            module: module.to_string(),
+            asset: asset.info.to_string(),
+            limit: limit.amount,
+            spent: period.spent,
+        });
+    }
+    MODULE_SPENDING.save(storage, key, &period)?;
+    Ok(())
+}
+
+/// Number of buckets the outflows of an asset are grouped in over the window of its limit
+const BUCKETS_PER_WINDOW: u64 = 24;
+
+/// Asserts that the messages stay within the account's outflow limits and records the sent amounts.
+pub(crate) fn assert_outflow_limits(
+    deps: DepsMut,
+    env: &Env,
+    msgs: &[CosmosMsg<Empty>],
+) -> ProxyResult<()> {
+    assert_asset_outflow_limits(deps, env, msgs.iter().filter_map(outflows).flatten())
+}
+
+/// Asserts that the funds sent over IBC stay within the account's outflow limits and records them.
+pub(crate) fn assert_ibc_outflow_limits(
+    deps: DepsMut,
+    env: &Env,
+    msgs: &[IbcClientMsg],
+) -> ProxyResult<()> {
+    assert_asset_outflow_limits(deps, env, msgs.iter().flat_map(ibc_outflows))
+}
+
+fn assert_asset_outflow_limits(
+    deps: DepsMut,
+    env: &Env,
+    assets: impl IntoIterator<Item = Asset>,
+) -> ProxyResult<()> {
+    for asset in assets {
+        let Some((entry, limit)) = OUTFLOW_LIMITS.may_load(deps.storage, &asset.info)? else {
+            continue;
+        };
+
+        let (mut buckets, used) = outflow_in_window(deps.storage, env, &asset.info, limit.window)?;
+        let used = used.checked_add(asset.amount).map_err(StdError::from)?;
+        if used > limit.limit {
+            return Err(ProxyError::OutflowLimitExceeded {
+                asset: entry.to_string(),
+                limit: limit.limit,
+                used,
+            });
+        }
+        // add to the latest bucket until it spans its share of the window
+        match buckets.last_mut() {
+            Some((start, amount))
+                if start.plus_seconds(bucket_span(limit.window)) > env.block.time =>
+            {
+                *amount += asset.amount;
+            }
+            _ => buckets.push((env.block.time, asset.amount)),
+        }
+        OUTFLOW_BUCKETS.save(deps.storage, &asset.info, &buckets)?;
+    }
+    Ok(())
+}
+
+fn bucket_span(window: u64) -> u64 {
+    (window / BUCKETS_PER_WINDOW).max(1)
+}
+
+/// Outflow buckets of the asset within the window ending at the current block, and their total.
+/// A bucket leaves the window once the window passed since the bucket's span ended.
+pub(crate) fn outflow_in_window(
+    storage: &dyn Storage,
+    env: &Env,
+    asset: &AssetInfo,
+    window: u64,
+) -> StdResult<(Vec<(Timestamp, Uint128)>, Uint128)> {
+    let mut buckets = OUTFLOW_BUCKETS
+        .may_load(storage, asset)?
+        .unwrap_or_default();
+    buckets.retain(|(start, _)| start.plus_seconds(bucket_span(window) + window) > env.block.time);
+    let used = buckets
+        .iter()
+        .try_fold(Uint128::zero(), |used, (_, amount)| {
+            used.checked_add(*amount)
+        })?;
+    Ok((buckets, used))
+}
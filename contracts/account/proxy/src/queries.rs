@@ -1,14 +1,24 @@
 use crate::contract::ProxyResult;
+use crate::error::ProxyError;
+use crate::permissions::outflow_in_window;
 
+use abstract_core::ans_host::state::ASSET_ADDRESSES;
+use abstract_core::ibc_client::{QueryMsg as IbcClientQuery, RemoteBalancesResponse};
 use abstract_core::objects::oracle::{AccountValue, Oracle};
 use abstract_core::proxy::{
-    AssetsConfigResponse, BaseAssetResponse, HoldingAmountResponse, OracleAsset, TokenValueResponse,
+    AssetsConfigResponse, BaseAssetResponse, HoldingAmountResponse, ModulePermissionsResponse,
+    OracleAsset, OutflowUsageResponse, TokenValueResponse,
 };
+use abstract_sdk::core::objects::account_id::ACCOUNT_ID;
 use abstract_sdk::core::objects::AssetEntry;
-use abstract_sdk::core::proxy::state::{ANS_HOST, STATE};
+use abstract_sdk::core::proxy::state::{
+    ADMIN, ANS_HOST, INCLUDE_REMOTE_BALANCES, MODULE_PERMISSIONS, MODULE_SPENDING, OUTFLOW_LIMITS,
+    STATE,
+};
 use abstract_sdk::core::proxy::{AssetsInfoResponse, ConfigResponse};
+use abstract_sdk::core::{manager::state::ACCOUNT_MODULES, IBC_CLIENT};
 use abstract_sdk::Resolve;
-use cosmwasm_std::{Addr, Deps, Env, StdResult};
+use cosmwasm_std::{Addr, Deps, Env, Order, StdError, StdResult, Uint128};
 use cw_asset::{Asset, AssetInfo};
 
 /// get the assets pricing information
@@ -69,8 +79,8 @@ pub fn query_token_value(
     let oracle = Oracle::new();
     let ans_host = ANS_HOST.load(deps.storage)?;
     let asset_info = asset_entry.resolve(&deps.querier, &ans_host)?;
-    let balance = asset_info.query_balance(&deps.querier, env.contract.address)?;
-    let value = oracle.asset_value(deps, Asset::new(asset_info, balance))?;
+    let balance = asset_info.query_balance(&deps.querier, &env.contract.address)?;
+    let value = oracle.asset_value(deps, &env, Asset::new(asset_info, balance))?;
 
     Ok(TokenValueResponse { value })
 }
@@ -78,11 +88,52 @@ pub fn query_token_value(
 /// Computes the total value locked in this contract
 pub fn query_total_value(deps: Deps, env: Env) -> ProxyResult<AccountValue> {
     let mut oracle = Oracle::new();
+    let remote_balances = query_remote_balances(deps)?;
     oracle
-        .account_value(deps, &env.contract.address)
+        .account_value_with_balances(deps, &env, &env.contract.address, remote_balances)
         .map_err(Into::into)
 }
 
+/// Balances of the Account on remote chains, as last synced by the IBC client.
+/// Empty unless enabled through `UpdateRemoteBalances`.
+/// A remote coin is resolved through the ans host entry `{host_chain}>{denom}`, unresolved coins are ignored.
+fn query_remote_balances(deps: Deps) -> ProxyResult<Vec<Asset>> {
+    if !INCLUDE_REMOTE_BALANCES
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+    {
+        return Ok(vec![]);
+    }
+    let manager_address = ADMIN.get(deps)?.ok_or(ProxyError::MissingManager)?;
+    let ibc_client_address = ACCOUNT_MODULES
+        .query(&deps.querier, manager_address, IBC_CLIENT)?
+        .ok_or_else(|| {
+            StdError::generic_err(format!(
+                "ibc_client not found on manager. Add it under the {IBC_CLIENT} name."
+            ))
+        })?;
+    let RemoteBalancesResponse { balances } = deps.querier.query_wasm_smart(
+        ibc_client_address,
+        &IbcClientQuery::RemoteBalances {
+            account_id: ACCOUNT_ID.load(deps.storage)?,
+        },
+    )?;
+    let ans_host = ANS_HOST.load(deps.storage)?;
+
+    let mut assets = vec![];
+    for (host_chain, coins) in balances {
+        for coin in coins {
+            let entry = AssetEntry::new(&format!("{host_chain}>{}", coin.denom));
+            if let Some(info) =
+                ASSET_ADDRESSES.query(&deps.querier, ans_host.address.clone(), &entry)?
+            {
+                assets.push(Asset::new(info, coin.amount));
+            }
+        }
+    }
+    Ok(assets)
+}
+
 pub fn query_base_asset(deps: Deps) -> ProxyResult<BaseAssetResponse> {
     let oracle = Oracle::new();
     let base_asset = oracle.base_asset(deps)?;
@@ -101,6 +152,44 @@ pub fn query_holding_amount(
     })
 }
 
+pub fn query_outflow_usage(
+    deps: Deps,
+    env: Env,
+    asset: AssetEntry,
+) -> ProxyResult<OutflowUsageResponse> {
+    let ans_host = ANS_HOST.load(deps.storage)?;
+    let asset_info = asset.resolve(&deps.querier, &ans_host)?;
+    let Some((_, limit)) = OUTFLOW_LIMITS.may_load(deps.storage, &asset_info)? else {
+        return Ok(OutflowUsageResponse {
+            limit: None,
+            used: Uint128::zero(),
+        });
+    };
+    let (_, used) = outflow_in_window(deps.storage, &env, &asset_info, limit.window)?;
+    Ok(OutflowUsageResponse {
+        limit: Some(limit),
+        used,
+    })
+}
+
+pub fn query_module_permissions(
+    deps: Deps,
+    module: String,
+) -> StdResult<ModulePermissionsResponse> {
+    let module = deps.api.addr_validate(&module)?;
+    let permissions = MODULE_PERMISSIONS
+        .may_load(deps.storage, &module)?
+        .map(Into::into);
+    let spending = MODULE_SPENDING
+        .prefix(&module)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    Ok(ModulePermissionsResponse {
+        permissions,
+        spending,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use abstract_core::objects::price_source::{PriceSource, UncheckedPriceSource};
@@ -108,13 +197,16 @@ mod test {
     use abstract_testing::{prelude::*, MockAnsHost};
     use cosmwasm_std::testing::{mock_dependencies, MOCK_CONTRACT_ADDR};
     use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
-    use cosmwasm_std::{coin, Decimal, DepsMut, OwnedDeps};
+    use cosmwasm_std::{coin, coins, Decimal, DepsMut, OwnedDeps};
 
     use abstract_core::proxy::{
-        AssetConfigResponse, ExecuteMsg, InstantiateMsg, TokenValueResponse,
+        AssetConfigResponse, ExecuteMsg, InstantiateMsg, OutflowLimit, TokenValueResponse,
     };
 
+    use crate::error::ProxyError;
+
     use crate::contract::{execute, instantiate, query};
+    use crate::test_common::manager_querier;
 
     use super::*;
 
@@ -180,6 +272,56 @@ mod test {
         );
     }
 
+    #[test]
+    fn total_value_resolves_remote_balances() -> Result<(), ProxyError> {
+        const IBC_CLIENT_ADDR: &str = "ibc_client";
+        let mut ans = MockAnsHost::new().with_defaults();
+        // usd held on osmosis
+        ans.assets
+            .push((AssetEntry::new("osmosis>uusd"), AssetInfo::native(USD)));
+        let mut deps = mock_dependencies();
+        deps.querier = ans
+            .insert_into(MockQuerierBuilder::default())
+            .with_contract_map_entry(
+                TEST_CREATOR,
+                ACCOUNT_MODULES,
+                (IBC_CLIENT, Addr::unchecked(IBC_CLIENT_ADDR)),
+            )
+            .with_smart_handler(IBC_CLIENT_ADDR, |_| {
+                cosmwasm_std::to_binary(&RemoteBalancesResponse {
+                    balances: vec![(
+                        "osmosis".to_string(),
+                        vec![coin(100, "uusd"), coin(50, "unknown")],
+                    )],
+                })
+                .map_err(|e| e.to_string())
+            })
+            .build();
+        deps.querier
+            .update_balance(MOCK_CONTRACT_ADDR, coins(1000, USD));
+        mock_init(deps.as_mut());
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::UpdateAssets {
+                to_add: vec![base_asset()],
+                to_remove: vec![],
+            },
+        )?;
+
+        // remote balances are excluded by default
+        let value = query_total_value(deps.as_ref(), mock_env())?;
+        assert_eq!(value.total_value.amount.u128(), 1000u128);
+
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::UpdateRemoteBalances { include: true },
+        )?;
+        let value = query_total_value(deps.as_ref(), mock_env())?;
+        // the unknown remote denom is ignored
+        assert_eq!(value.total_value.amount.u128(), 1100u128);
+        Ok(())
+    }
+
     #[test]
     fn query_config() {
         let mut deps = mock_dependencies();
@@ -189,6 +331,7 @@ mod test {
             &mut deps,
             ExecuteMsg::AddModule {
                 module: "test_module".to_string(),
+                permissions: None,
             },
         )
         .unwrap();
@@ -375,4 +518,250 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn outflow_limits() {
+        let mut deps = mock_dependencies();
+        deps.querier = MockAnsHost::new()
+            .with_defaults()
+            .insert_into(manager_querier(TEST_CREATOR))
+            .build();
+        mock_init(deps.as_mut());
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::AddModule {
+                module: "test_module".to_string(),
+                permissions: None,
+            },
+        )
+        .unwrap();
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::UpdateOutflowLimits {
+                to_add: vec![(
+                    AssetEntry::from(EUR),
+                    OutflowLimit {
+                        limit: Uint128::new(100),
+                        window: 3600,
+                    },
+                )],
+                to_remove: vec![],
+            },
+        )
+        .unwrap();
+
+        let send = |amount: u128, denom: &str| ExecuteMsg::ModuleAction {
+            msgs: vec![cosmwasm_std::BankMsg::Send {
+                to_address: "receiver".to_string(),
+                amount: coins(amount, denom),
+            }
+            .into()],
+        };
+        let usage = |deps: &MockDeps, env: Env| -> OutflowUsageResponse {
+            from_binary(
+                &query(
+                    deps.as_ref(),
+                    env,
+                    abstract_core::proxy::QueryMsg::OutflowUsage {
+                        asset: AssetEntry::from(EUR),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        let module_info = mock_info("test_module", &[]);
+
+        let mut env = mock_env();
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            module_info.clone(),
+            send(60, EUR),
+        )
+        .unwrap();
+        // assets without a limit are not tracked
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            module_info.clone(),
+            send(500, USD),
+        )
+        .unwrap();
+        assert_eq!(usage(&deps, env.clone()).used, Uint128::new(60));
+
+        env.block.time = env.block.time.plus_seconds(1800);
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            module_info.clone(),
+            send(50, EUR),
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            ProxyError::OutflowLimitExceeded {
+                asset: EUR.to_string(),
+                limit: Uint128::new(100),
+                used: Uint128::new(110),
+            }
+        );
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            module_info.clone(),
+            send(40, EUR),
+        )
+        .unwrap();
+        assert_eq!(usage(&deps, env.clone()).used, Uint128::new(100));
+
+        // the first spend leaves the window once its bucket span ended
+        env.block.time = env.block.time.plus_seconds(1800);
+        assert_eq!(usage(&deps, env.clone()).used, Uint128::new(100));
+        env.block.time = env.block.time.plus_seconds(3600 / 24);
+        assert_eq!(usage(&deps, env.clone()).used, Uint128::new(40));
+        execute(deps.as_mut(), env.clone(), module_info, send(60, EUR)).unwrap();
+
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::UpdateOutflowLimits {
+                to_add: vec![],
+                to_remove: vec![AssetEntry::from(EUR)],
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            usage(&deps, env),
+            OutflowUsageResponse {
+                limit: None,
+                used: Uint128::zero(),
+            }
+        );
+    }
+
+    #[test]
+    fn ibc_funds_count_against_outflow_limits() {
+        let mut deps = mock_dependencies();
+        deps.querier = MockAnsHost::new()
+            .with_defaults()
+            .insert_into(manager_querier(TEST_CREATOR).with_contract_map_entry(
+                TEST_CREATOR,
+                abstract_core::manager::state::ACCOUNT_MODULES,
+                (
+                    abstract_core::IBC_CLIENT,
+                    Addr::unchecked("ibc_client_addr"),
+                ),
+            ))
+            .build();
+        mock_init(deps.as_mut());
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::AddModule {
+                module: "test_module".to_string(),
+                permissions: None,
+            },
+        )
+        .unwrap();
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::UpdateOutflowLimits {
+                to_add: vec![(
+                    AssetEntry::from(EUR),
+                    OutflowLimit {
+                        limit: Uint128::new(100),
+                        window: 3600,
+                    },
+                )],
+                to_remove: vec![],
+            },
+        )
+        .unwrap();
+
+        let send_funds = |amount: u128| ExecuteMsg::IbcAction {
+            msgs: vec![abstract_core::ibc_client::ExecuteMsg::SendFunds {
+                host_chain: "juno".to_string(),
+                funds: coins(amount, EUR),
+                timeout: None,
+            }],
+        };
+        let module_info = mock_info("test_module", &[]);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            module_info.clone(),
+            send_funds(60),
+        )
+        .unwrap();
+        let res = execute(deps.as_mut(), mock_env(), module_info, send_funds(50));
+        assert_eq!(
+            res.unwrap_err(),
+            ProxyError::OutflowLimitExceeded {
+                asset: EUR.to_string(),
+                limit: Uint128::new(100),
+                used: Uint128::new(110),
+            }
+        );
+    }
+
+    #[test]
+    fn outflows_are_bucketed() {
+        let mut deps = mock_dependencies();
+        deps.querier = MockAnsHost::new()
+            .with_defaults()
+            .insert_into(manager_querier(TEST_CREATOR))
+            .build();
+        mock_init(deps.as_mut());
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::AddModule {
+                module: "test_module".to_string(),
+                permissions: None,
+            },
+        )
+        .unwrap();
+        execute_as_admin(
+            &mut deps,
+            ExecuteMsg::UpdateOutflowLimits {
+                to_add: vec![(
+                    AssetEntry::from(EUR),
+                    OutflowLimit {
+                        limit: Uint128::new(1000),
+                        // buckets span 100 seconds
+                        window: 2400,
+                    },
+                )],
+                to_remove: vec![],
+            },
+        )
+        .unwrap();
+
+        let mut env = mock_env();
+        for _ in 0..3 {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info("test_module", &[]),
+                ExecuteMsg::ModuleAction {
+                    msgs: vec![cosmwasm_std::BankMsg::Send {
+                        to_address: "receiver".to_string(),
+                        amount: coins(10, EUR),
+                    }
+                    .into()],
+                },
+            )
+            .unwrap();
+            env.block.time = env.block.time.plus_seconds(50);
+        }
+
+        let (buckets, used) =
+            outflow_in_window(&deps.storage, &env, &AssetInfo::native(EUR), 2400).unwrap();
+        assert_eq!(
+            buckets,
+            vec![
+                (mock_env().block.time, Uint128::new(20)),
+                (mock_env().block.time.plus_seconds(100), Uint128::new(10)),
+            ]
+        );
+        assert_eq!(used, Uint128::new(30));
+    }
 }
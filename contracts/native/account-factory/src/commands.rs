@@ -1,4 +1,4 @@
-use abstract_core::objects::ABSTRACT_ACCOUNT_ID;
+use abstract_core::objects::{AccountId, ABSTRACT_ACCOUNT_ID};
 use abstract_core::{manager::ExecuteMsg, objects::module::assert_module_data_validity};
 use cosmwasm_std::{
     to_binary, wasm_execute, Addr, CosmosMsg, DepsMut, Empty, Env, MessageInfo, QuerierWrapper,
@@ -8,14 +8,17 @@ use protobuf::Message;
 
 use abstract_sdk::{
     core::{
-        manager::{InstantiateMsg as ManagerInstantiateMsg, InternalConfigAction},
+        manager::{
+            state::ACCOUNT_ID, InstantiateMsg as ManagerInstantiateMsg, InternalConfigAction,
+        },
         objects::{
             gov_type::GovernanceDetails, module::Module, module::ModuleInfo,
             module_reference::ModuleReference,
         },
         proxy::{ExecuteMsg as ProxyExecMsg, InstantiateMsg as ProxyInstantiateMsg},
         version_control::{
-            AccountBase, ExecuteMsg as VCExecuteMsg, ModulesResponse, QueryMsg as VCQuery,
+            AccountBase, AccountBaseResponse, ExecuteMsg as VCExecuteMsg, ModulesResponse,
+            QueryMsg as VCQuery,
         },
         AbstractResult, MANAGER, PROXY,
     },
@@ -32,6 +35,7 @@ pub const CREATE_ACCOUNT_MANAGER_MSG_ID: u64 = 1u64;
 pub const CREATE_ACCOUNT_PROXY_MSG_ID: u64 = 2u64;
 
 /// Function that starts the creation of the Account
+#[allow(clippy::too_many_arguments)]
 pub fn execute_create_account(
     deps: DepsMut,
     env: Env,
@@ -40,6 +44,7 @@ pub fn execute_create_account(
     name: String,
     description: Option<String>,
     link: Option<String>,
+    parent_account: Option<(AccountId, Addr)>,
 ) -> AccountFactoryResult {
     let config = CONFIG.load(deps.storage)?;
 
@@ -58,6 +63,7 @@ pub fn execute_create_account(
             account_manager_address: None,
             manager_module: Some(module.clone()),
             proxy_module: None,
+            parent_account,
         },
     )?;
 
@@ -97,6 +103,51 @@ pub fn execute_create_account(
     }
 }
 
+/// Creates an Account that is owned by the calling Account's manager
+pub fn execute_create_sub_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    description: Option<String>,
+    link: Option<String>,
+) -> AccountFactoryResult {
+    let config = CONFIG.load(deps.storage)?;
+
+    // Verify that the caller is the manager of a registered Account
+    let parent_account_id = ACCOUNT_ID
+        .query(&deps.querier, info.sender.clone())
+        .map_err(|_| AccountFactoryError::SubAccountCreatorNotManager {
+            caller: info.sender.to_string(),
+        })?;
+    let AccountBaseResponse { account_base } = deps.querier.query(&wasm_smart_query(
+        config.version_control_contract.to_string(),
+        &VCQuery::AccountBase {
+            account_id: parent_account_id,
+        },
+    )?)?;
+    if account_base.manager != info.sender {
+        return Err(AccountFactoryError::SubAccountCreatorNotManager {
+            caller: info.sender.to_string(),
+        });
+    }
+
+    let governance = GovernanceDetails::SubAccount {
+        manager: account_base.manager.clone(),
+        proxy: account_base.proxy,
+    };
+    execute_create_account(
+        deps,
+        env,
+        info,
+        governance,
+        name,
+        description,
+        link,
+        Some((parent_account_id, account_base.manager)),
+    )
+}
+
 /// instantiates the Treasury contract of the newly created DAO
 pub fn after_manager_create_proxy(deps: DepsMut, result: SubMsgResult) -> AccountFactoryResult {
     let config = CONFIG.load(deps.storage)?;
@@ -212,6 +263,7 @@ pub fn after_proxy_add_to_manager_and_set_admin(
         msg: to_binary(&VCExecuteMsg::AddAccount {
             account_id: config.next_account_id,
             account_base,
+            parent_account_id: context.parent_account.as_ref().map(|(id, _)| *id),
         })?,
     });
 
@@ -221,6 +273,7 @@ pub fn after_proxy_add_to_manager_and_set_admin(
         funds: vec![],
         msg: to_binary(&ProxyExecMsg::AddModule {
             module: manager_address.to_string(),
+            permissions: None,
         })?,
     });
 
@@ -237,6 +290,23 @@ pub fn after_proxy_add_to_manager_and_set_admin(
         admin: manager_address.to_string(),
     });
 
+    // Register the sub-account on its parent
+    let register_sub_account_msg = context
+        .parent_account
+        .map(|(_, parent_manager)| {
+            wasm_execute(
+                parent_manager,
+                &ExecuteMsg::UpdateInternalConfig(to_binary(
+                    &InternalConfigAction::RegisterSubAccount {
+                        account_id: config.next_account_id,
+                        manager: manager_address.to_string(),
+                    },
+                )?),
+                vec![],
+            )
+        })
+        .transpose()?;
+
     // Update id sequence
     config.next_account_id += 1;
     CONFIG.save(deps.storage, &config)?;
@@ -261,7 +331,8 @@ pub fn after_proxy_add_to_manager_and_set_admin(
     .add_message(add_proxy_address_msg)
     .add_message(whitelist_manager)
     .add_message(set_proxy_admin_msg)
-    .add_message(set_manager_admin_msg))
+    .add_message(set_manager_admin_msg)
+    .add_messages(register_sub_account_msg))
 }
 
 // Only owner can execute it
@@ -1,7 +1,9 @@
 use crate::{commands, error::AccountFactoryError, state::*};
 use abstract_core::objects::module_version::assert_contract_upgrade;
 use abstract_macros::abstract_response;
-use abstract_sdk::core::{account_factory::*, ACCOUNT_FACTORY};
+use abstract_sdk::core::{
+    account_factory::*, objects::gov_type::GovernanceDetails, ACCOUNT_FACTORY,
+};
 use cosmwasm_std::{
     to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
 };
@@ -65,8 +67,25 @@ pub fn execute(
             description,
         } => {
             let gov_details = governance.verify(deps.api)?;
-            commands::execute_create_account(deps, env, info, gov_details, name, description, link)
+            if let GovernanceDetails::SubAccount { .. } = gov_details {
+                return Err(AccountFactoryError::MustUseCreateSubAccount {});
+            }
+            commands::execute_create_account(
+                deps,
+                env,
+                info,
+                gov_details,
+                name,
+                description,
+                link,
+                None,
+            )
         }
+        ExecuteMsg::CreateSubAccount {
+            name,
+            description,
+            link,
+        } => commands::execute_create_sub_account(deps, env, info, name, description, link),
         ExecuteMsg::UpdateOwnership(action) => {
             execute_update_ownership!(AccountFactoryResponse, deps, env, info, action)
         }
@@ -36,4 +36,10 @@ pub enum AccountFactoryError {
 
     #[error("No payment received")]
     NoPaymentReceived {},
+
+    #[error("Sub-accounts can only be created by the manager of an Account, not {caller}")]
+    SubAccountCreatorNotManager { caller: String },
+
+    #[error("Must use CreateSubAccount to create a sub-account")]
+    MustUseCreateSubAccount {},
 }
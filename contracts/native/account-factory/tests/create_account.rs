@@ -228,3 +228,61 @@ fn sender_is_not_admin_external() -> AResult {
 
     Ok(())
 }
+
+#[test]
+fn create_sub_account() -> AResult {
+    let sender = Addr::unchecked(common::OWNER);
+    let chain = Mock::new(&sender);
+    let deployment = Abstract::deploy_on(chain, Empty {})?;
+
+    let factory = &deployment.account_factory;
+    let version_control = &deployment.version_control;
+    factory.create_account(
+        GovernanceDetails::Monarchy {
+            monarch: sender.to_string(),
+        },
+        String::from("parent_account"),
+        None,
+        None,
+    )?;
+    let parent = AbstractAccount::new(&deployment, Some(TEST_ACCOUNT_ID));
+
+    parent
+        .manager
+        .create_sub_account(String::from("sub_account"), None, None)?;
+    let sub_account_id = TEST_ACCOUNT_ID + 1;
+
+    let sub_accounts = parent.manager.sub_account_ids(None, None)?;
+    assert_that!(sub_accounts.sub_accounts).is_equal_to(vec![sub_account_id]);
+
+    let sub_account = AbstractAccount::new(&deployment, Some(sub_account_id));
+    let sub_account_info = sub_account.manager.info()?.info;
+    assert_that!(sub_account_info.governance_details).is_equal_to(GovernanceDetails::SubAccount {
+        manager: parent.manager.address()?,
+        proxy: parent.proxy.address()?,
+    });
+
+    // parent manager owns the sub-account
+    let ownership = sub_account.manager.ownership()?;
+    assert_that!(ownership.owner).is_equal_to(Some(parent.manager.address()?));
+
+    let account_base = version_control.account_base(sub_account_id)?.account_base;
+    assert_that!(account_base.manager).is_equal_to(sub_account.manager.address()?);
+
+    Ok(())
+}
+
+#[test]
+fn sub_account_only_created_by_manager() -> AResult {
+    let sender = Addr::unchecked(common::OWNER);
+    let chain = Mock::new(&sender);
+    let deployment = Abstract::deploy_on(chain, Empty {})?;
+
+    let res =
+        deployment
+            .account_factory
+            .create_sub_account(String::from("sub_account"), None, None);
+    assert_that!(res).is_err();
+
+    Ok(())
+}
@@ -1,17 +1,21 @@
 use crate::{
-    contract::{IbcClientResponse, IbcClientResult, MAX_RETRIES},
+    contract::{IbcClientResponse, IbcClientResult, MAX_RETRIES, MAX_STORED_PACKETS},
     error::IbcClientError,
-    ibc::PACKET_LIFETIME,
+    ibc::{DEFAULT_PACKET_LIFETIME, MAX_PACKET_LIFETIME},
+    queries::{DEFAULT_LIMIT, MAX_LIMIT},
 };
 use abstract_sdk::AccountAction;
 use abstract_sdk::{
     core::{
         ibc_client::{
-            state::{AccountData, ACCOUNTS, ADMIN, ANS_HOST, CHANNELS, CONFIG, LATEST_QUERIES},
-            CallbackInfo,
+            state::{
+                AccountData, ACCOUNTS, ADMIN, ANS_HOST, CHANNELS, CONFIG, FAILED_PACKETS,
+                HOST_TIMEOUTS, PACKET_RETRIES, QUERY_HISTORY, QUERY_RESULTS, RETRY_QUEUE,
+            },
+            CallbackInfo, FailedPacket, PacketRetry,
         },
         ibc_host::{HostAction, InternalAction, PacketMsg},
-        objects::{ans_host::AnsHost, ChannelEntry},
+        objects::{ans_host::AnsHost, AccountId, ChannelEntry},
         ICS20,
     },
     feature_objects::VersionControlContract,
@@ -19,8 +23,11 @@ use abstract_sdk::{
     AccountVerification, Execution, Resolve,
 };
 use cosmwasm_std::{
-    to_binary, Coin, CosmosMsg, DepsMut, Env, IbcMsg, MessageInfo, StdError, Storage,
+    to_binary, Coin, CosmosMsg, DepsMut, Env, IbcMsg, MessageInfo, Order, StdError, StdResult,
+    Storage, Timestamp,
 };
+use cw_storage_plus::{Bound, Map};
+use serde::{de::DeserializeOwned, Serialize};
 
 pub fn execute_update_config(
     deps: DepsMut,
@@ -54,16 +61,265 @@ pub fn execute_update_config(
 // allows admins to clear host if needed
 pub fn execute_remove_host(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     host_chain: String,
 ) -> IbcClientResult {
     // auth check
     ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
-    CHANNELS.remove(deps.storage, &host_chain);
+    if let Some(channel_id) = CHANNELS.may_load(deps.storage, &host_chain)? {
+        CHANNELS.remove(deps.storage, &host_chain);
+        fail_queued_retries(deps.storage, &env, &channel_id)?;
+    }
 
     Ok(IbcClientResponse::action("remove_host"))
 }
 
+// allows admins to configure how long packets to a host live
+pub fn execute_update_host_timeout(
+    deps: DepsMut,
+    info: MessageInfo,
+    host_chain: String,
+    timeout: Option<u64>,
+) -> IbcClientResult {
+    // auth check
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+
+    match timeout {
+        Some(timeout) => {
+            HOST_TIMEOUTS.save(deps.storage, &host_chain, &validate_timeout(timeout)?)?
+        }
+        None => HOST_TIMEOUTS.remove(deps.storage, &host_chain),
+    }
+
+    Ok(IbcClientResponse::new(
+        "update_host_timeout",
+        vec![("host_chain", host_chain)],
+    ))
+}
+
+// allows admins to refresh the balances of the remote accounts
+pub fn execute_sync_balances(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_after: Option<(String, AccountId)>,
+    limit: Option<u8>,
+) -> IbcClientResult {
+    // auth check
+    ADMIN.assert_admin(deps.as_ref(), &info.sender)?;
+    let cfg = CONFIG.load(deps.storage)?;
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after
+        .as_ref()
+        .map(|(channel, account_id)| Bound::exclusive((channel.as_str(), *account_id)));
+    let accounts = ACCOUNTS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+    // (host_chain, channel-id) of the connected hosts
+    let hosts = CHANNELS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut msgs = vec![];
+    for ((channel_id, account_id), account) in accounts {
+        // only accounts with a proxy on the host hold remote balances
+        if account.remote_addr.is_none() {
+            continue;
+        }
+        let Some((host_chain, _)) = hosts.iter().find(|(_, channel)| channel == &channel_id) else {
+            continue;
+        };
+        let timeout = packet_lifetime(deps.storage, host_chain, None)?;
+        let packet =
+            HostAction::Balances {}.into_packet(account_id, 0, timeout, cfg.chain.clone(), None);
+        msgs.push(IbcMsg::SendPacket {
+            channel_id,
+            data: to_binary(&packet)?,
+            timeout: env.block.time.plus_seconds(timeout).into(),
+        });
+    }
+
+    Ok(
+        IbcClientResponse::new("sync_balances", vec![("accounts", msgs.len().to_string())])
+            .add_messages(msgs),
+    )
+}
+
+/// Lifetime in seconds of a packet sent to `host_chain`.
+/// Uses the provided `timeout`, otherwise the one configured for the host or the default lifetime.
+pub(crate) fn packet_lifetime(
+    storage: &dyn Storage,
+    host_chain: &str,
+    timeout: Option<u64>,
+) -> IbcClientResult<u64> {
+    match timeout {
+        Some(timeout) => validate_timeout(timeout),
+        None => Ok(HOST_TIMEOUTS
+            .may_load(storage, host_chain)?
+            .unwrap_or(DEFAULT_PACKET_LIFETIME)),
+    }
+}
+
+fn validate_timeout(timeout: u64) -> IbcClientResult<u64> {
+    if timeout == 0 || timeout > MAX_PACKET_LIFETIME {
+        return Err(IbcClientError::InvalidTimeout {});
+    }
+    Ok(timeout)
+}
+
+// resends the errored packets that waited for their retry backoff
+pub fn execute_resend_retries(deps: DepsMut, env: Env, limit: Option<u8>) -> IbcClientResult {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    // queue is ordered by resend time
+    let due = RETRY_QUEUE
+        .range(deps.storage, None, None, Order::Ascending)
+        .take_while(|r| {
+            r.as_ref().map_or(true, |((resend_at, _, _), _)| {
+                *resend_at <= env.block.time.seconds()
+            })
+        })
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let open_channels = CHANNELS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|r| r.map(|(_, channel_id)| channel_id))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut msgs = vec![];
+    let mut failed = 0;
+    for ((resend_at, channel_id, sequence), packet) in due {
+        RETRY_QUEUE.remove(deps.storage, (resend_at, &channel_id, sequence));
+        // sending on a removed or closed channel would fail the whole resend
+        if !open_channels.contains(&channel_id) {
+            fail_queued_retry(deps.storage, &env, &channel_id, sequence, packet)?;
+            failed += 1;
+            continue;
+        }
+        msgs.push(IbcMsg::SendPacket {
+            channel_id,
+            data: to_binary(&packet)?,
+            timeout: env.block.time.plus_seconds(packet.timeout).into(),
+        });
+    }
+
+    Ok(IbcClientResponse::new(
+        "resend_retries",
+        vec![
+            ("packets", msgs.len().to_string()),
+            ("failed", failed.to_string()),
+        ],
+    )
+    .add_messages(msgs))
+}
+
+/// Moves the queued retries of a removed or closed channel to the failed packets
+pub(crate) fn fail_queued_retries(
+    storage: &mut dyn Storage,
+    env: &Env,
+    channel_id: &str,
+) -> StdResult<()> {
+    let queued = RETRY_QUEUE
+        .range(storage, None, None, Order::Ascending)
+        .filter(|r| {
+            r.as_ref()
+                .map_or(true, |((_, channel, _), _)| channel == channel_id)
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    for ((resend_at, channel_id, sequence), packet) in queued {
+        RETRY_QUEUE.remove(storage, (resend_at, &channel_id, sequence));
+        fail_queued_retry(storage, env, &channel_id, sequence, packet)?;
+    }
+    Ok(())
+}
+
+/// Records a queued retry that can't be resent as a failed packet
+fn fail_queued_retry(
+    storage: &mut dyn Storage,
+    env: &Env,
+    channel_id: &str,
+    sequence: u64,
+    packet: PacketMsg,
+) -> StdResult<()> {
+    PACKET_RETRIES.remove(storage, (packet.account_id, channel_id, sequence));
+    save_failed_packet(
+        storage,
+        packet.account_id,
+        &FailedPacket {
+            channel_id: channel_id.to_string(),
+            sequence,
+            timed_out_at: env.block.time,
+            action: packet.action,
+            callback_info: packet.callback_info,
+        },
+    )
+}
+
+/// Stores the failed packet, dropping the oldest failed packets of the Account over [`MAX_STORED_PACKETS`]
+pub(crate) fn save_failed_packet(
+    storage: &mut dyn Storage,
+    account_id: AccountId,
+    packet: &FailedPacket,
+) -> StdResult<()> {
+    FAILED_PACKETS.save(
+        storage,
+        (account_id, &packet.channel_id, packet.sequence),
+        packet,
+    )?;
+    let expired = oldest_packets(storage, &FAILED_PACKETS, account_id, |packet| {
+        packet.timed_out_at
+    })?;
+    for (channel_id, sequence) in expired {
+        FAILED_PACKETS.remove(storage, (account_id, &channel_id, sequence));
+    }
+    Ok(())
+}
+
+/// Stores the packet retry, dropping the oldest retries of the Account over [`MAX_STORED_PACKETS`]
+pub(crate) fn save_packet_retry(
+    storage: &mut dyn Storage,
+    account_id: AccountId,
+    retry: &PacketRetry,
+) -> StdResult<()> {
+    PACKET_RETRIES.save(
+        storage,
+        (account_id, &retry.channel_id, retry.sequence),
+        retry,
+    )?;
+    let expired = oldest_packets(storage, &PACKET_RETRIES, account_id, |retry| {
+        retry.retried_at
+    })?;
+    for (channel_id, sequence) in expired {
+        PACKET_RETRIES.remove(storage, (account_id, &channel_id, sequence));
+    }
+    Ok(())
+}
+
+/// (channel-id, sequence) of the oldest packets of the Account that exceed [`MAX_STORED_PACKETS`]
+fn oldest_packets<T: Serialize + DeserializeOwned>(
+    storage: &dyn Storage,
+    packets: &Map<(AccountId, &str, u64), T>,
+    account_id: AccountId,
+    stored_at: fn(&T) -> Timestamp,
+) -> StdResult<Vec<(String, u64)>> {
+    let mut stored = packets
+        .sub_prefix(account_id)
+        .range(storage, None, None, Order::Ascending)
+        .map(|r| {
+            r.map(|((channel_id, sequence), packet)| (stored_at(&packet), channel_id, sequence))
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+    let expired = stored.len().saturating_sub(MAX_STORED_PACKETS);
+    stored.sort();
+    Ok(stored
+        .drain(..expired)
+        .map(|(_, channel_id, sequence)| (channel_id, sequence))
+        .collect())
+}
+
 pub fn execute_send_packet(
     deps: DepsMut,
     env: Env,
@@ -72,6 +328,7 @@ pub fn execute_send_packet(
     action: HostAction,
     callback_info: Option<CallbackInfo>,
     mut retries: u8,
+    timeout: Option<u64>,
 ) -> IbcClientResult {
     let cfg = CONFIG.load(deps.storage)?;
     let version_control = VersionControlContract::new(cfg.version_control_address);
@@ -88,21 +345,17 @@ pub fn execute_send_packet(
     // Set max retries
     retries = retries.min(MAX_RETRIES);
 
+    let timeout = packet_lifetime(deps.storage, &host_chain, timeout)?;
+
     // get account_id
     let account_id = account_base.account_id(deps.as_ref())?;
     // ensure the channel exists and loads it.
     let channel = CHANNELS.load(deps.storage, &host_chain)?;
-    let packet = PacketMsg {
-        retries,
-        client_chain: cfg.chain,
-        account_id,
-        callback_info,
-        action,
-    };
+    let packet = action.into_packet(account_id, retries, timeout, cfg.chain, callback_info);
     let msg = IbcMsg::SendPacket {
         channel_id: channel,
         data: to_binary(&packet)?,
-        timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+        timeout: env.block.time.plus_seconds(timeout).into(),
     };
 
     Ok(IbcClientResponse::action("handle_send_msgs").add_message(msg))
@@ -127,10 +380,13 @@ pub fn execute_register_os(
     // ensure the channel exists (not found if not registered)
     let channel_id = CHANNELS.load(deps.storage, &host_chain)?;
     let account_id = account_base.account_id(deps.as_ref())?;
+    let timeout = packet_lifetime(deps.storage, &host_chain, None)?;
 
     // construct a packet to send
     let packet = PacketMsg {
         retries: 0u8,
+        retried: 0u8,
+        timeout,
         client_chain: cfg.chain,
         account_id,
         callback_info: None,
@@ -146,18 +402,61 @@ pub fn execute_register_os(
     let msg = IbcMsg::SendPacket {
         channel_id,
         data: to_binary(&packet)?,
-        timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+        timeout: env.block.time.plus_seconds(timeout).into(),
     };
 
     Ok(IbcClientResponse::action("handle_register").add_message(msg))
 }
 
+pub fn execute_create_remote_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    host_chain: String,
+) -> IbcClientResult {
+    let cfg = CONFIG.load(deps.storage)?;
+    // Verify that the sender is a proxy contract
+    let version_control = VersionControlContract::new(cfg.version_control_address);
+    let account_base = version_control
+        .account_registry(deps.as_ref())
+        .assert_proxy(&info.sender)?;
+
+    // ensure the channel exists (not found if not registered)
+    let channel_id = CHANNELS.load(deps.storage, &host_chain)?;
+    let account_id = account_base.account_id(deps.as_ref())?;
+    // the Account must be registered on the host first
+    if !ACCOUNTS.has(deps.storage, (&channel_id, account_id)) {
+        return Err(IbcClientError::UnregisteredChannel(channel_id));
+    }
+    let timeout = packet_lifetime(deps.storage, &host_chain, None)?;
+
+    // construct a packet to send
+    let packet = PacketMsg {
+        retries: 0u8,
+        retried: 0u8,
+        timeout,
+        client_chain: cfg.chain,
+        account_id,
+        callback_info: None,
+        action: HostAction::Internal(InternalAction::CreateAccount),
+    };
+
+    let msg = IbcMsg::SendPacket {
+        channel_id,
+        data: to_binary(&packet)?,
+        timeout: env.block.time.plus_seconds(timeout).into(),
+    };
+
+    Ok(IbcClientResponse::action("handle_create_remote_account").add_message(msg))
+}
+
 pub fn execute_send_funds(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     host_chain: String,
     funds: Vec<Coin>,
+    timeout: Option<u64>,
 ) -> IbcClientResult {
     let cfg = CONFIG.load(deps.storage)?;
     let mem = ANS_HOST.load(deps.storage)?;
@@ -168,6 +467,8 @@ pub fn execute_send_funds(
         .account_registry(deps.as_ref())
         .assert_proxy(&info.sender)?;
 
+    let timeout = packet_lifetime(deps.storage, &host_chain, timeout)?;
+
     // get account_id of Account
     let account_id = account_base.account_id(deps.as_ref())?;
     // get channel used to communicate to host chain
@@ -199,7 +500,7 @@ pub fn execute_send_funds(
                 channel_id: ics20_channel_id.clone(),
                 to_address: remote_addr.clone(),
                 amount,
-                timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+                timeout: env.block.time.plus_seconds(timeout).into(),
             }
             .into(),
         );
@@ -215,7 +516,11 @@ pub fn execute_send_funds(
 
 fn clear_accounts(store: &mut dyn Storage) {
     ACCOUNTS.clear(store);
-    LATEST_QUERIES.clear(store);
+    QUERY_RESULTS.clear(store);
+    QUERY_HISTORY.clear(store);
+    FAILED_PACKETS.clear(store);
+    PACKET_RETRIES.clear(store);
+    RETRY_QUEUE.clear(store);
 }
 
 #[cfg(test)]
@@ -337,17 +642,25 @@ mod test {
                     last_update_time: Timestamp::from_nanos(5u64),
                     remote_addr: None,
                     remote_balance: vec![],
+                    remote_account: None,
                 },
             )?;
 
-            LATEST_QUERIES.save(
+            QUERY_RESULTS.save(
                 deps.as_mut().storage,
-                ("channel", 5u32),
-                &LatestQueryResponse {
-                    last_update_time: Timestamp::from_nanos(5u64),
+                (5u32, "channel", "query"),
+                &QueryResult {
+                    channel_id: "channel".into(),
+                    query_id: "query".into(),
+                    received_at: Timestamp::from_nanos(5u64),
                     response: StdAck::Result(to_binary(&Empty {})?),
                 },
             )?;
+            QUERY_HISTORY.save(
+                deps.as_mut().storage,
+                5u32,
+                &vec![("channel".into(), "query".into())],
+            )?;
 
             let new_version_control = "new_version_control".to_string();
 
@@ -360,7 +673,243 @@ mod test {
             assert_that!(res.messages).is_empty();
 
             assert_that!(ACCOUNTS.is_empty(&deps.storage)).is_true();
-            assert_that!(LATEST_QUERIES.is_empty(&deps.storage)).is_true();
+            assert_that!(QUERY_RESULTS.is_empty(&deps.storage)).is_true();
+            assert_that!(QUERY_HISTORY.is_empty(&deps.storage)).is_true();
+
+            Ok(())
+        }
+    }
+
+    mod update_host_timeout {
+        use super::*;
+
+        #[test]
+        fn only_admin() -> IbcClientTestResult {
+            test_only_admin(ExecuteMsg::UpdateHostTimeout {
+                host_chain: TEST_CHAIN.to_string(),
+                timeout: Some(60),
+            })
+        }
+
+        #[test]
+        fn sets_and_removes_timeout() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::UpdateHostTimeout {
+                    host_chain: TEST_CHAIN.to_string(),
+                    timeout: Some(60),
+                },
+            )?;
+            assert_that!(packet_lifetime(&deps.storage, TEST_CHAIN, None)?).is_equal_to(60);
+            // the timeout of a call takes precedence
+            assert_that!(packet_lifetime(&deps.storage, TEST_CHAIN, Some(30))?).is_equal_to(30);
+
+            execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::UpdateHostTimeout {
+                    host_chain: TEST_CHAIN.to_string(),
+                    timeout: None,
+                },
+            )?;
+            assert_that!(packet_lifetime(&deps.storage, TEST_CHAIN, None)?)
+                .is_equal_to(DEFAULT_PACKET_LIFETIME);
+
+            Ok(())
+        }
+
+        #[test]
+        fn rejects_invalid_timeout() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+
+            let res = execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::UpdateHostTimeout {
+                    host_chain: TEST_CHAIN.to_string(),
+                    timeout: Some(0),
+                },
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(IbcClientError::InvalidTimeout {});
+
+            let res = packet_lifetime(&deps.storage, TEST_CHAIN, Some(0));
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(IbcClientError::InvalidTimeout {});
+
+            // would overflow the block time
+            let res = packet_lifetime(&deps.storage, TEST_CHAIN, Some(u64::MAX));
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(IbcClientError::InvalidTimeout {});
+
+            Ok(())
+        }
+    }
+
+    mod sync_balances {
+        use super::*;
+        use crate::queries::query_balance_staleness;
+        use cosmwasm_std::Timestamp;
+
+        #[test]
+        fn only_admin() -> IbcClientTestResult {
+            test_only_admin(ExecuteMsg::SyncBalances {
+                start_after: None,
+                limit: None,
+            })
+        }
+
+        #[test]
+        fn requests_balances_of_registered_accounts() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            CHANNELS.save(deps.as_mut().storage, TEST_CHAIN, &"channel".into())?;
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                ("channel", 1u32),
+                &AccountData {
+                    remote_addr: Some("remote_proxy".into()),
+                    ..Default::default()
+                },
+            )?;
+            // not registered on the host yet
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                ("channel", 2u32),
+                &AccountData::default(),
+            )?;
+
+            let res = execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::SyncBalances {
+                    start_after: None,
+                    limit: None,
+                },
+            )?;
+
+            let packet = HostAction::Balances {}.into_packet(
+                1u32,
+                0,
+                DEFAULT_PACKET_LIFETIME,
+                TEST_CHAIN.into(),
+                None,
+            );
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id: "channel".into(),
+                data: to_binary(&packet)?,
+                timeout: mock_env()
+                    .block
+                    .time
+                    .plus_seconds(DEFAULT_PACKET_LIFETIME)
+                    .into(),
+            }));
+
+            Ok(())
+        }
+
+        #[test]
+        fn reports_staleness() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            let env = mock_env();
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                ("channel", 1u32),
+                &AccountData {
+                    last_update_time: env.block.time.minus_seconds(100),
+                    ..Default::default()
+                },
+            )?;
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                ("channel", 2u32),
+                &AccountData::default(),
+            )?;
+
+            let res = query_balance_staleness(deps.as_ref(), env.clone(), None, None)?;
+
+            assert_that!(res.accounts).is_equal_to(vec![
+                BalanceStaleness {
+                    channel_id: "channel".into(),
+                    account_id: 1u32,
+                    last_update_time: env.block.time.minus_seconds(100),
+                    seconds_since_update: Some(100),
+                },
+                BalanceStaleness {
+                    channel_id: "channel".into(),
+                    account_id: 2u32,
+                    last_update_time: Timestamp::default(),
+                    seconds_since_update: None,
+                },
+            ]);
+
+            Ok(())
+        }
+    }
+
+    mod resend_retries {
+        use super::*;
+
+        #[test]
+        fn resends_due_packets() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            let env = mock_env();
+            let now = env.block.time.seconds();
+
+            CHANNELS.save(deps.as_mut().storage, TEST_CHAIN, &"channel".into())?;
+            let packet = HostAction::Balances {}.into_packet(1u32, 1, 120, TEST_CHAIN.into(), None);
+            RETRY_QUEUE.save(deps.as_mut().storage, (now, "channel", 1), &packet)?;
+            // backoff not elapsed yet
+            RETRY_QUEUE.save(deps.as_mut().storage, (now + 60, "channel", 2), &packet)?;
+
+            // callable by anyone
+            let res = execute_as(
+                deps.as_mut(),
+                "anyone",
+                ExecuteMsg::ResendRetries { limit: None },
+            )?;
+
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::Ibc(IbcMsg::SendPacket {
+                channel_id: "channel".into(),
+                data: to_binary(&packet)?,
+                timeout: env.block.time.plus_seconds(120).into(),
+            }));
+            assert_that!(RETRY_QUEUE.has(&deps.storage, (now, "channel", 1))).is_false();
+            assert_that!(RETRY_QUEUE.has(&deps.storage, (now + 60, "channel", 2))).is_true();
+
+            Ok(())
+        }
+
+        #[test]
+        fn fails_packets_of_removed_hosts() -> IbcClientTestResult {
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            let now = mock_env().block.time.seconds();
+
+            CHANNELS.save(deps.as_mut().storage, TEST_CHAIN, &"channel".into())?;
+            let packet = HostAction::Balances {}.into_packet(1u32, 1, 120, TEST_CHAIN.into(), None);
+            // channel of a removed host, ordered first
+            RETRY_QUEUE.save(deps.as_mut().storage, (now, "removed", 1), &packet)?;
+            RETRY_QUEUE.save(deps.as_mut().storage, (now, "channel", 2), &packet)?;
+
+            let res = execute_as(
+                deps.as_mut(),
+                "anyone",
+                ExecuteMsg::ResendRetries { limit: None },
+            )?;
+
+            assert_that!(res.messages).has_length(1);
+            assert_that!(RETRY_QUEUE.is_empty(&deps.storage)).is_true();
+            let failed = FAILED_PACKETS.load(&deps.storage, (1u32, "removed", 1))?;
+            assert_that!(failed.action).is_equal_to(HostAction::Balances {});
 
             Ok(())
         }
@@ -382,6 +931,8 @@ mod test {
             mock_init(deps.as_mut())?;
 
             CHANNELS.save(deps.as_mut().storage, TEST_CHAIN, &"test_channel".into())?;
+            let packet = HostAction::Balances {}.into_packet(1u32, 1, 120, TEST_CHAIN.into(), None);
+            RETRY_QUEUE.save(deps.as_mut().storage, (1, "test_channel", 1), &packet)?;
 
             let msg = ExecuteMsg::RemoveHost {
                 host_chain: TEST_CHAIN.to_string(),
@@ -391,6 +942,9 @@ mod test {
             assert_that!(res.messages).is_empty();
 
             assert_that!(CHANNELS.is_empty(&deps.storage)).is_true();
+            // queued retries of the host are failed
+            assert_that!(RETRY_QUEUE.is_empty(&deps.storage)).is_true();
+            assert_that!(FAILED_PACKETS.has(&deps.storage, (1u32, "test_channel", 1))).is_true();
 
             Ok(())
         }
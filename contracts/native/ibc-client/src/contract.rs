@@ -16,6 +16,10 @@ use cw_semver::Version;
 
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub(crate) const MAX_RETRIES: u8 = 5;
+/// Maximum amount of query results stored per Account
+pub(crate) const MAX_QUERY_RESULTS: usize = 50;
+/// Maximum amount of failed packets and of packet retries stored per Account
+pub(crate) const MAX_STORED_PACKETS: usize = 50;
 
 pub(crate) type IbcClientResult<T = Response> = Result<T, IbcClientError>;
 
@@ -72,6 +76,7 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> I
             action,
             callback_info,
             retries,
+            timeout,
         } => commands::execute_send_packet(
             deps,
             env,
@@ -80,31 +85,89 @@ pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> I
             action,
             callback_info,
             retries,
+            timeout,
         ),
-        ExecuteMsg::SendFunds { host_chain, funds } => {
-            commands::execute_send_funds(deps, env, info, host_chain, funds).map_err(Into::into)
-        }
+        ExecuteMsg::SendFunds {
+            host_chain,
+            funds,
+            timeout,
+        } => commands::execute_send_funds(deps, env, info, host_chain, funds, timeout)
+            .map_err(Into::into),
         ExecuteMsg::Register { host_chain } => {
             commands::execute_register_os(deps, env, info, host_chain)
         }
+        ExecuteMsg::CreateRemoteAccount { host_chain } => {
+            commands::execute_create_remote_account(deps, env, info, host_chain)
+        }
         ExecuteMsg::RemoveHost { host_chain } => {
-            commands::execute_remove_host(deps, info, host_chain).map_err(Into::into)
+            commands::execute_remove_host(deps, env, info, host_chain).map_err(Into::into)
         }
+        ExecuteMsg::UpdateHostTimeout {
+            host_chain,
+            timeout,
+        } => commands::execute_update_host_timeout(deps, info, host_chain, timeout),
+        ExecuteMsg::SyncBalances { start_after, limit } => {
+            commands::execute_sync_balances(deps, env, info, start_after, limit)
+        }
+        ExecuteMsg::ResendRetries { limit } => commands::execute_resend_retries(deps, env, limit),
     }
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<QueryResponse> {
     match msg {
         QueryMsg::Config {} => to_binary(&queries::query_config(deps)?),
         QueryMsg::Account { chain, account_id } => {
             to_binary(&queries::query_account(deps, chain, account_id)?)
         }
         QueryMsg::ListAccounts {} => to_binary(&queries::query_list_accounts(deps)?),
-        QueryMsg::LatestQueryResult { chain, account_id } => to_binary(
-            &queries::query_latest_ibc_query_result(deps, chain, account_id)?,
-        ),
+        QueryMsg::QueryResult {
+            chain,
+            account_id,
+            query_id,
+        } => to_binary(&queries::query_ibc_query_result(
+            deps, chain, account_id, query_id,
+        )?),
+        QueryMsg::QueryResults {
+            account_id,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_ibc_query_results(
+            deps,
+            account_id,
+            start_after,
+            limit,
+        )?),
         QueryMsg::ListChannels {} => to_binary(&queries::query_list_channels(deps)?),
+        QueryMsg::HostTimeout { host_chain } => {
+            to_binary(&queries::query_host_timeout(deps, host_chain)?)
+        }
+        QueryMsg::PacketRetries {
+            account_id,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_packet_retries(
+            deps,
+            account_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::FailedPackets {
+            account_id,
+            start_after,
+            limit,
+        } => to_binary(&queries::query_failed_packets(
+            deps,
+            account_id,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::BalanceStaleness { start_after, limit } => to_binary(
+            &queries::query_balance_staleness(deps, env, start_after, limit)?,
+        ),
+        QueryMsg::RemoteBalances { account_id } => {
+            to_binary(&queries::query_remote_balances(deps, account_id)?)
+        }
     }
 }
 
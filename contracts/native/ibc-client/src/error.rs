@@ -32,4 +32,10 @@ pub enum IbcClientError {
 
     #[error("The host you are trying to connect is already connected")]
     HostAlreadyExists {},
+
+    #[error(
+        "packet timeout must be between 1 and {} seconds",
+        crate::ibc::MAX_PACKET_LIFETIME
+    )]
+    InvalidTimeout {},
 }
@@ -1,24 +1,34 @@
-use crate::error::IbcClientError;
+use crate::{
+    commands::{fail_queued_retries, save_failed_packet, save_packet_retry},
+    contract::{MAX_QUERY_RESULTS, MAX_RETRIES},
+    error::IbcClientError,
+};
 use abstract_core::objects::AccountId;
 use abstract_sdk::core::{
     abstract_ica::{
-        check_order, check_version, BalancesResponse, RegisterResponse, StdAck, WhoAmIResponse,
+        check_order, check_version, BalancesResponse, CreateAccountResponse, RegisterResponse,
+        StdAck, WhoAmIResponse,
     },
     ibc_client::{
-        state::{AccountData, ACCOUNTS, CHANNELS, CONFIG, LATEST_QUERIES},
-        CallbackInfo, LatestQueryResponse,
+        state::{
+            AccountData, ACCOUNTS, CHANNELS, CONFIG, QUERY_HISTORY, QUERY_RESULTS, RETRY_QUEUE,
+        },
+        CallbackInfo, FailedPacket, PacketRetry, QueryResult, RemoteAccount,
     },
     ibc_host::{HostAction, InternalAction, PacketMsg},
 };
 use cosmwasm_std::{
     from_slice, to_binary, DepsMut, Env, Ibc3ChannelOpenResponse, IbcBasicResponse,
     IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg, IbcMsg, IbcPacketAckMsg,
-    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, IbcTimeout, StdResult,
+    IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, Order, StdResult,
 };
 
-// TODO: make configurable?
-/// packets live one hour
-pub const PACKET_LIFETIME: u64 = 60 * 60;
+/// packets live one hour unless configured otherwise for the host
+pub const DEFAULT_PACKET_LIFETIME: u64 = 60 * 60;
+/// packets can live at most one week
+pub const MAX_PACKET_LIFETIME: u64 = 7 * 24 * 60 * 60;
+/// delay before the first retry of an errored packet, doubled on every following retry
+pub const RETRY_BACKOFF: u64 = 60;
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
 /// enforces ordering and versioing constraints
@@ -57,12 +67,14 @@ pub fn ibc_channel_connect(
         account_id: 0,
         callback_info: None,
         retries: 0,
+        retried: 0,
+        timeout: DEFAULT_PACKET_LIFETIME,
     };
 
     let msg = IbcMsg::SendPacket {
         channel_id: channel_id.clone(),
         data: to_binary(&packet)?,
-        timeout: env.block.time.plus_seconds(PACKET_LIFETIME).into(),
+        timeout: env.block.time.plus_seconds(DEFAULT_PACKET_LIFETIME).into(),
     };
 
     Ok(IbcBasicResponse::new()
@@ -72,16 +84,28 @@ pub fn ibc_channel_connect(
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-/// On closed channel, simply delete the account from our local store
+/// On closed channel, remove the channel and fail the packets queued for it
 pub fn ibc_channel_close(
-    _deps: DepsMut,
-    _env: Env,
+    deps: DepsMut,
+    env: Env,
     msg: IbcChannelCloseMsg,
 ) -> StdResult<IbcBasicResponse> {
     let channel = msg.channel();
 
     // remove the channel
     let channel_id = &channel.endpoint.channel_id;
+    let hosts = CHANNELS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|r| {
+            r.as_ref()
+                .map_or(true, |(_, channel)| channel == channel_id)
+        })
+        .map(|r| r.map(|(host_chain, _)| host_chain))
+        .collect::<StdResult<Vec<_>>>()?;
+    for host_chain in hosts {
+        CHANNELS.remove(deps.storage, &host_chain);
+    }
+    fail_queued_retries(deps.storage, &env, channel_id)?;
 
     Ok(IbcBasicResponse::new()
         .add_attribute("action", "ibc_close")
@@ -112,15 +136,41 @@ pub fn ibc_packet_ack(
     let mut original_packet: PacketMsg = from_slice(&msg.original_packet.data)?;
     let res: StdAck = from_slice(&msg.acknowledgement.data)?;
     // retry if error
-    if let StdAck::Error(_) = res {
+    if let StdAck::Error(error) = &res {
         if original_packet.retries > 0 {
             original_packet.retries -= 1;
-            // retry sending the packet
-            return Ok(IbcBasicResponse::new().add_message(IbcMsg::SendPacket {
-                channel_id: msg.original_packet.src.channel_id,
-                data: to_binary(&original_packet)?,
-                timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(PACKET_LIFETIME)),
-            }));
+            original_packet.retried += 1;
+            // wait before resending, doubling the delay on every retry
+            let resend_at = env
+                .block
+                .time
+                .plus_seconds(RETRY_BACKOFF << (original_packet.retried - 1).min(MAX_RETRIES));
+            // keep track of the retry
+            let sequence = msg.original_packet.sequence;
+            save_packet_retry(
+                deps.storage,
+                original_packet.account_id,
+                &PacketRetry {
+                    channel_id: channel_id.clone(),
+                    sequence,
+                    retry: original_packet.retried,
+                    retries_left: original_packet.retries,
+                    error: error.clone(),
+                    retried_at: env.block.time,
+                    resend_at,
+                    action: original_packet.action.clone(),
+                },
+            )?;
+            // queue the packet, it is resent through `ResendRetries`
+            RETRY_QUEUE.save(
+                deps.storage,
+                (resend_at.seconds(), &channel_id, sequence),
+                &original_packet,
+            )?;
+            return Ok(IbcBasicResponse::new()
+                .add_attribute("action", "queue_retry")
+                .add_attribute("retry", original_packet.retried.to_string())
+                .add_attribute("resend_at", resend_at.to_string()));
         }
     }
 
@@ -132,9 +182,15 @@ pub fn ibc_packet_ack(
     } = original_packet;
     match action {
         HostAction::Dispatch { .. } => acknowledge_dispatch(deps, env, callback_info, msg),
-        HostAction::Query { .. } => {
-            acknowledge_query(deps, env, channel_id, account_id, callback_info, msg)
-        }
+        HostAction::Query { query_id, .. } => acknowledge_query(
+            deps,
+            env,
+            channel_id,
+            account_id,
+            query_id,
+            callback_info,
+            msg,
+        ),
         HostAction::Balances { .. } => acknowledge_balances(deps, env, channel_id, account_id, res),
         HostAction::App { msg: _ } => {
             let response = IbcBasicResponse::new().add_attribute("action", "acknowledge_app");
@@ -145,10 +201,17 @@ pub fn ibc_packet_ack(
                 IbcBasicResponse::new().add_attribute("action", "acknowledge_send_all_back");
             maybe_add_callback(response, callback_info, msg).map_err(Into::into)
         }
+        HostAction::Manager(_) => {
+            let response = IbcBasicResponse::new().add_attribute("action", "acknowledge_manager");
+            maybe_add_callback(response, callback_info, msg).map_err(Into::into)
+        }
         HostAction::Internal(InternalAction::WhoAmI) => acknowledge_who_am_i(deps, channel_id, res),
         HostAction::Internal(InternalAction::Register { .. }) => {
             acknowledge_register(deps, channel_id, account_id, res)
         }
+        HostAction::Internal(InternalAction::CreateAccount) => {
+            acknowledge_create_account(deps, channel_id, account_id, res)
+        }
     }
 }
 
@@ -185,20 +248,39 @@ fn acknowledge_query(
     env: Env,
     channel_id: String,
     account_id: AccountId,
+    query_id: String,
     callback_info: Option<CallbackInfo>,
     ack: IbcPacketAckMsg,
 ) -> Result<IbcBasicResponse, IbcClientError> {
     let msg: StdAck = from_slice(&ack.acknowledgement.data)?;
-    let res = IbcBasicResponse::new().add_attribute("action", "acknowledge_ibc_query");
-    // store IBC response for later querying from the smart contract?
-    LATEST_QUERIES.save(
+    let res = IbcBasicResponse::new()
+        .add_attribute("action", "acknowledge_ibc_query")
+        .add_attribute("query_id", &query_id);
+    // store IBC response for later querying from the smart contract
+    QUERY_RESULTS.save(
         deps.storage,
-        (&channel_id, account_id),
-        &LatestQueryResponse {
-            last_update_time: env.block.time,
+        (account_id, &channel_id, &query_id),
+        &QueryResult {
+            channel_id: channel_id.clone(),
+            query_id: query_id.clone(),
+            received_at: env.block.time,
             response: msg,
         },
     )?;
+
+    // keep a bounded history of results, dropping the oldest ones
+    let entry = (channel_id, query_id);
+    let mut history = QUERY_HISTORY
+        .may_load(deps.storage, account_id)?
+        .unwrap_or_default();
+    history.retain(|stored| stored != &entry);
+    history.push(entry);
+    let expired = history.len().saturating_sub(MAX_QUERY_RESULTS);
+    for (channel_id, query_id) in history.drain(..expired) {
+        QUERY_RESULTS.remove(deps.storage, (account_id, &channel_id, &query_id));
+    }
+    QUERY_HISTORY.save(deps.storage, account_id, &history)?;
+
     maybe_add_callback(res, callback_info, ack).map_err(Into::into)
 }
 
@@ -262,6 +344,40 @@ fn acknowledge_register(
     Ok(IbcBasicResponse::new().add_attribute("action", "acknowledge_register"))
 }
 
+// receive PacketMsg::CreateAccount response
+// store the remote Abstract Account in accounts info
+fn acknowledge_create_account(
+    deps: DepsMut,
+    channel_id: String,
+    account_id: AccountId,
+    ack: StdAck,
+) -> Result<IbcBasicResponse, IbcClientError> {
+    // ignore errors (but mention in log)
+    let CreateAccountResponse { manager, proxy } = match ack {
+        StdAck::Result(res) => from_slice(&res)?,
+        StdAck::Error(e) => {
+            return Ok(IbcBasicResponse::new()
+                .add_attribute("action", "acknowledge_create_account")
+                .add_attribute("error", e))
+        }
+    };
+
+    ACCOUNTS.update(deps.storage, (&channel_id, account_id), |acct| match acct {
+        Some(mut acct) => {
+            acct.remote_account = Some(RemoteAccount {
+                manager: manager.clone(),
+                proxy,
+            });
+            Ok(acct)
+        }
+        None => Err(IbcClientError::UnregisteredChannel(channel_id.clone())),
+    })?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("action", "acknowledge_create_account")
+        .add_attribute("remote_manager", manager))
+}
+
 // receive PacketMsg::Balances response
 fn acknowledge_balances(
     deps: DepsMut,
@@ -291,6 +407,7 @@ fn acknowledge_balances(
                 last_update_time: env.block.time,
                 remote_addr: Some(account),
                 remote_balance: balances,
+                remote_account: acct.remote_account,
             })
         }
         None => Err(IbcClientError::UnregisteredChannel(channel_id.clone())),
@@ -300,30 +417,64 @@ fn acknowledge_balances(
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-/// we just ignore these now. shall we store some info?
+/// Store the timed-out packet and let the module that sent it know through its callback
 pub fn ibc_packet_timeout(
-    _deps: DepsMut,
-    _env: Env,
-    _msg: IbcPacketTimeoutMsg,
-) -> StdResult<IbcBasicResponse> {
-    Ok(IbcBasicResponse::new().add_attribute("action", "ibc_packet_timeout"))
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, IbcClientError> {
+    let packet = msg.packet;
+    let channel_id = packet.src.channel_id;
+    let PacketMsg {
+        account_id,
+        callback_info,
+        action,
+        ..
+    } = from_slice(&packet.data)?;
+
+    save_failed_packet(
+        deps.storage,
+        account_id,
+        &FailedPacket {
+            channel_id: channel_id.clone(),
+            sequence: packet.sequence,
+            timed_out_at: env.block.time,
+            action,
+            callback_info: callback_info.clone(),
+        },
+    )?;
+
+    let response = IbcBasicResponse::new()
+        .add_attribute("action", "ibc_packet_timeout")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("sequence", packet.sequence.to_string());
+    match callback_info {
+        Some(info) => Ok(response.add_message(info.to_timeout_msg()?)),
+        None => Ok(response),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::contract::instantiate;
-    use abstract_sdk::core::ibc_client::InstantiateMsg;
+    use crate::contract::{instantiate, MAX_STORED_PACKETS};
+    use abstract_sdk::core::ibc_client::{
+        state::{FAILED_PACKETS, PACKET_RETRIES},
+        InstantiateMsg,
+    };
 
     use abstract_sdk::core::abstract_ica::{APP_ORDER, BAD_APP_ORDER, IBC_APP_VERSION};
-    use abstract_testing::addresses::{TEST_ADMIN, TEST_ANS_HOST, TEST_VERSION_CONTROL};
+    use abstract_testing::addresses::{
+        TEST_ACCOUNT_ID, TEST_ADMIN, TEST_ANS_HOST, TEST_VERSION_CONTROL,
+    };
     use cosmwasm_std::{
         testing::{
-            mock_dependencies, mock_env, mock_ibc_channel_open_try, mock_info, MockApi,
-            MockQuerier, MockStorage,
+            mock_dependencies, mock_env, mock_ibc_channel_open_try, mock_ibc_packet_timeout,
+            mock_info, MockApi, MockQuerier, MockStorage,
         },
         OwnedDeps,
     };
+    use speculoos::prelude::*;
 
     // type IbcClientTestResult = Result<(), IbcClientError>;
 
@@ -390,6 +541,298 @@ mod tests {
         ibc_channel_open(deps.as_mut(), mock_env(), valid_handshake).unwrap();
     }
 
+    mod packet_timeout {
+        use super::*;
+        use cosmwasm_std::{CosmosMsg, Empty};
+
+        fn timed_out_packet(callback_info: Option<CallbackInfo>) -> IbcPacketTimeoutMsg {
+            let packet = PacketMsg {
+                client_chain: TEST_CHAIN.into(),
+                retries: 0,
+                retried: 0,
+                timeout: DEFAULT_PACKET_LIFETIME,
+                account_id: TEST_ACCOUNT_ID,
+                callback_info,
+                action: HostAction::Dispatch {
+                    msgs: vec![CosmosMsg::<Empty>::Bank(cosmwasm_std::BankMsg::Burn {
+                        amount: vec![],
+                    })],
+                },
+            };
+            mock_ibc_packet_timeout("channel-1", &packet).unwrap()
+        }
+
+        #[test]
+        fn stores_failed_packet() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            let msg = timed_out_packet(None);
+            let sequence = msg.packet.sequence;
+
+            let res = ibc_packet_timeout(deps.as_mut(), mock_env(), msg)?;
+            assert_that!(res.messages).is_empty();
+
+            let failed =
+                FAILED_PACKETS.load(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", sequence))?;
+            assert_that!(failed.sequence).is_equal_to(sequence);
+            assert_that!(failed.timed_out_at).is_equal_to(mock_env().block.time);
+            assert_that!(failed.callback_info).is_none();
+            Ok(())
+        }
+
+        #[test]
+        fn sends_timeout_callback() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            let callback_info = CallbackInfo {
+                id: "callback".into(),
+                receiver: "module".into(),
+            };
+            let msg = timed_out_packet(Some(callback_info.clone()));
+
+            let res = ibc_packet_timeout(deps.as_mut(), mock_env(), msg)?;
+
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].msg).is_equal_to(callback_info.to_timeout_msg()?);
+            Ok(())
+        }
+
+        #[test]
+        fn drops_oldest_failed_packets() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            let mut env = mock_env();
+
+            for sequence in 0..=MAX_STORED_PACKETS as u64 {
+                let mut msg = timed_out_packet(None);
+                msg.packet.sequence = sequence;
+                ibc_packet_timeout(deps.as_mut(), env.clone(), msg)?;
+                env.block.time = env.block.time.plus_seconds(1);
+            }
+
+            let stored = FAILED_PACKETS
+                .sub_prefix(TEST_ACCOUNT_ID)
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count();
+            assert_that!(stored).is_equal_to(MAX_STORED_PACKETS);
+            assert_that!(FAILED_PACKETS.has(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", 0)))
+                .is_false();
+            assert_that!(FAILED_PACKETS.has(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", 1)))
+                .is_true();
+            Ok(())
+        }
+    }
+
+    mod packet_retry {
+        use super::*;
+        use cosmwasm_std::{testing::mock_ibc_packet_ack, IbcAcknowledgement};
+
+        fn errored_packet(retries: u8) -> (PacketMsg, IbcPacketAckMsg) {
+            let packet = HostAction::Balances {}.into_packet(
+                TEST_ACCOUNT_ID,
+                retries,
+                120,
+                TEST_CHAIN.into(),
+                None,
+            );
+            let ack = IbcAcknowledgement::new(StdAck::fail("host error".into()));
+            let msg = mock_ibc_packet_ack("channel-1", &packet, ack).unwrap();
+            (packet, msg)
+        }
+
+        #[test]
+        fn queues_retry_with_backoff() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            let (packet, msg) = errored_packet(2);
+            let sequence = msg.original_packet.sequence;
+            let env = mock_env();
+
+            let res = ibc_packet_ack(deps.as_mut(), env.clone(), msg)?;
+            // not resent right away
+            assert_that!(res.messages).is_empty();
+
+            let resend_at = env.block.time.plus_seconds(RETRY_BACKOFF);
+            let mut expected_packet = packet;
+            expected_packet.retries = 1;
+            expected_packet.retried = 1;
+            let queued =
+                RETRY_QUEUE.load(&deps.storage, (resend_at.seconds(), "channel-1", sequence))?;
+            assert_that!(queued).is_equal_to(expected_packet);
+
+            let retry =
+                PACKET_RETRIES.load(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", sequence))?;
+            assert_that!(retry.retry).is_equal_to(1);
+            assert_that!(retry.retries_left).is_equal_to(1);
+            assert_that!(retry.error).is_equal_to("host error".to_string());
+            assert_that!(retry.resend_at).is_equal_to(resend_at);
+            Ok(())
+        }
+
+        #[test]
+        fn doubles_backoff_on_every_retry() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            let (mut packet, _) = errored_packet(2);
+            // second attempt of the packet
+            packet.retried = 1;
+            let ack = IbcAcknowledgement::new(StdAck::fail("host error".into()));
+            let msg = mock_ibc_packet_ack("channel-1", &packet, ack)?;
+            let sequence = msg.original_packet.sequence;
+            let env = mock_env();
+
+            ibc_packet_ack(deps.as_mut(), env.clone(), msg)?;
+
+            let resend_at = env.block.time.plus_seconds(2 * RETRY_BACKOFF);
+            assert_that!(
+                RETRY_QUEUE.has(&deps.storage, (resend_at.seconds(), "channel-1", sequence))
+            )
+            .is_true();
+            Ok(())
+        }
+
+        #[test]
+        fn no_retry_without_retries_left() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            let (_, msg) = errored_packet(0);
+
+            let res = ibc_packet_ack(deps.as_mut(), mock_env(), msg)?;
+
+            assert_that!(res.messages).is_empty();
+            assert_that!(PACKET_RETRIES.is_empty(&deps.storage)).is_true();
+            assert_that!(RETRY_QUEUE.is_empty(&deps.storage)).is_true();
+            Ok(())
+        }
+
+        #[test]
+        fn drops_oldest_retries() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            let mut env = mock_env();
+
+            for sequence in 0..=MAX_STORED_PACKETS as u64 {
+                let (_, mut msg) = errored_packet(1);
+                msg.original_packet.sequence = sequence;
+                ibc_packet_ack(deps.as_mut(), env.clone(), msg)?;
+                env.block.time = env.block.time.plus_seconds(1);
+            }
+
+            let stored = PACKET_RETRIES
+                .sub_prefix(TEST_ACCOUNT_ID)
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count();
+            assert_that!(stored).is_equal_to(MAX_STORED_PACKETS);
+            assert_that!(PACKET_RETRIES.has(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", 0)))
+                .is_false();
+            // the dropped retry is still resent
+            assert_that!(RETRY_QUEUE
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count())
+            .is_equal_to(MAX_STORED_PACKETS + 1);
+            Ok(())
+        }
+    }
+
+    mod query_results {
+        use super::*;
+        use cosmwasm_std::{testing::mock_ibc_packet_ack, Empty, IbcAcknowledgement};
+
+        fn query_ack(query_id: &str) -> IbcPacketAckMsg {
+            let packet = HostAction::Query {
+                msgs: vec![],
+                query_id: query_id.into(),
+            }
+            .into_packet(
+                TEST_ACCOUNT_ID,
+                0,
+                DEFAULT_PACKET_LIFETIME,
+                TEST_CHAIN.into(),
+                None,
+            );
+            let ack = IbcAcknowledgement::new(StdAck::success(Empty {}));
+            mock_ibc_packet_ack("channel-1", &packet, ack).unwrap()
+        }
+
+        #[test]
+        fn stores_results_by_query_id() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+
+            ibc_packet_ack(deps.as_mut(), mock_env(), query_ack("first"))?;
+            ibc_packet_ack(deps.as_mut(), mock_env(), query_ack("second"))?;
+
+            for query_id in ["first", "second"] {
+                let result =
+                    QUERY_RESULTS.load(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", query_id))?;
+                assert_that!(result.query_id).is_equal_to(query_id.to_string());
+                assert_that!(result.received_at).is_equal_to(mock_env().block.time);
+                assert_that!(result.response).is_equal_to(StdAck::success(Empty {}));
+            }
+            Ok(())
+        }
+
+        #[test]
+        fn drops_oldest_results() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+
+            for i in 0..=MAX_QUERY_RESULTS {
+                ibc_packet_ack(deps.as_mut(), mock_env(), query_ack(&i.to_string()))?;
+            }
+
+            let history = QUERY_HISTORY.load(&deps.storage, TEST_ACCOUNT_ID)?;
+            assert_that!(history).has_length(MAX_QUERY_RESULTS);
+            assert_that!(history[0]).is_equal_to(("channel-1".to_string(), "1".to_string()));
+            assert_that!(QUERY_RESULTS.has(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", "0")))
+                .is_false();
+            assert_that!(QUERY_RESULTS.has(&deps.storage, (TEST_ACCOUNT_ID, "channel-1", "1")))
+                .is_true();
+            Ok(())
+        }
+    }
+
+    mod create_account_ack {
+        use super::*;
+        use cosmwasm_std::{testing::mock_ibc_packet_ack, IbcAcknowledgement};
+
+        fn create_account_ack() -> IbcPacketAckMsg {
+            let packet = HostAction::Internal(InternalAction::CreateAccount).into_packet(
+                TEST_ACCOUNT_ID,
+                0,
+                DEFAULT_PACKET_LIFETIME,
+                TEST_CHAIN.into(),
+                None,
+            );
+            let ack = IbcAcknowledgement::new(StdAck::success(CreateAccountResponse {
+                manager: "remote_manager".into(),
+                proxy: "remote_proxy".into(),
+            }));
+            mock_ibc_packet_ack("channel-1", &packet, ack).unwrap()
+        }
+
+        #[test]
+        fn stores_remote_account() -> Result<(), IbcClientError> {
+            let mut deps = mock_init();
+            ACCOUNTS.save(
+                deps.as_mut().storage,
+                ("channel-1", TEST_ACCOUNT_ID),
+                &AccountData::default(),
+            )?;
+
+            ibc_packet_ack(deps.as_mut(), mock_env(), create_account_ack())?;
+
+            let account = ACCOUNTS.load(&deps.storage, ("channel-1", TEST_ACCOUNT_ID))?;
+            assert_that!(account.remote_account).is_equal_to(Some(RemoteAccount {
+                manager: "remote_manager".into(),
+                proxy: "remote_proxy".into(),
+            }));
+            Ok(())
+        }
+
+        #[test]
+        fn requires_registered_account() {
+            let mut deps = mock_init();
+
+            let res = ibc_packet_ack(deps.as_mut(), mock_env(), create_account_ack());
+
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(IbcClientError::UnregisteredChannel("channel-1".into()));
+        }
+    }
+
     // #[test]
     // fn proper_handshake_flow() -> IbcClientTestResult {
     //     // setup and connect handshake
@@ -1,20 +1,50 @@
+use crate::commands;
 use abstract_core::{
     ibc_client::{
-        state::{Config, ACCOUNTS, ADMIN, CHANNELS, CONFIG, LATEST_QUERIES},
-        AccountInfo, AccountResponse, ConfigResponse, LatestQueryResponse, ListAccountsResponse,
-        ListChannelsResponse,
+        state::{
+            Config, ACCOUNTS, ADMIN, CHANNELS, CONFIG, FAILED_PACKETS, PACKET_RETRIES,
+            QUERY_RESULTS,
+        },
+        AccountInfo, AccountResponse, BalanceStaleness, BalanceStalenessResponse, ConfigResponse,
+        FailedPacketsResponse, HostTimeoutResponse, ListAccountsResponse, ListChannelsResponse,
+        PacketRetriesResponse, QueryResult, QueryResultsResponse, RemoteBalancesResponse,
     },
     objects::AccountId,
 };
-use cosmwasm_std::{Deps, Order, StdResult};
+use cosmwasm_std::{Deps, Env, Order, StdError, StdResult};
+use cw_storage_plus::Bound;
 
-pub fn query_latest_ibc_query_result(
+pub(crate) const DEFAULT_LIMIT: u8 = 10;
+pub(crate) const MAX_LIMIT: u8 = 20;
+
+pub fn query_ibc_query_result(
     deps: Deps,
     host_chain: String,
     account_id: AccountId,
-) -> StdResult<LatestQueryResponse> {
+    query_id: String,
+) -> StdResult<QueryResult> {
     let channel = CHANNELS.load(deps.storage, &host_chain)?;
-    LATEST_QUERIES.load(deps.storage, (&channel, account_id))
+    QUERY_RESULTS.load(deps.storage, (account_id, &channel, &query_id))
+}
+
+pub fn query_ibc_query_results(
+    deps: Deps,
+    account_id: AccountId,
+    start_after: Option<(String, String)>,
+    limit: Option<u8>,
+) -> StdResult<QueryResultsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after
+        .as_ref()
+        .map(|(channel, query_id)| Bound::exclusive((channel.as_str(), query_id.as_str())));
+
+    let results = QUERY_RESULTS
+        .sub_prefix(account_id)
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|r| r.map(|(_, result)| result))
+        .collect::<StdResult<_>>()?;
+    Ok(QueryResultsResponse { results })
 }
 
 // TODO: paging
@@ -36,6 +66,103 @@ pub fn query_list_channels(deps: Deps) -> StdResult<ListChannelsResponse> {
     Ok(ListChannelsResponse { channels })
 }
 
+pub fn query_failed_packets(
+    deps: Deps,
+    account_id: AccountId,
+    start_after: Option<(String, u64)>,
+    limit: Option<u8>,
+) -> StdResult<FailedPacketsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after
+        .as_ref()
+        .map(|(channel, sequence)| Bound::exclusive((channel.as_str(), *sequence)));
+
+    let packets = FAILED_PACKETS
+        .sub_prefix(account_id)
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|r| r.map(|(_, packet)| packet))
+        .collect::<StdResult<_>>()?;
+    Ok(FailedPacketsResponse { packets })
+}
+
+pub fn query_host_timeout(deps: Deps, host_chain: String) -> StdResult<HostTimeoutResponse> {
+    let timeout = commands::packet_lifetime(deps.storage, &host_chain, None)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    Ok(HostTimeoutResponse { timeout })
+}
+
+pub fn query_packet_retries(
+    deps: Deps,
+    account_id: AccountId,
+    start_after: Option<(String, u64)>,
+    limit: Option<u8>,
+) -> StdResult<PacketRetriesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after
+        .as_ref()
+        .map(|(channel, sequence)| Bound::exclusive((channel.as_str(), *sequence)));
+
+    let retries = PACKET_RETRIES
+        .sub_prefix(account_id)
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|r| r.map(|(_, retry)| retry))
+        .collect::<StdResult<_>>()?;
+    Ok(PacketRetriesResponse { retries })
+}
+
+pub fn query_balance_staleness(
+    deps: Deps,
+    env: Env,
+    start_after: Option<(String, AccountId)>,
+    limit: Option<u8>,
+) -> StdResult<BalanceStalenessResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_bound = start_after
+        .as_ref()
+        .map(|(channel, account_id)| Bound::exclusive((channel.as_str(), *account_id)));
+
+    let accounts = ACCOUNTS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|r| {
+            let ((channel_id, account_id), account) = r?;
+            // a zero timestamp means the balances were never synced
+            let seconds_since_update = (account.last_update_time.nanos() != 0).then(|| {
+                env.block
+                    .time
+                    .seconds()
+                    .saturating_sub(account.last_update_time.seconds())
+            });
+            Ok(BalanceStaleness {
+                channel_id,
+                account_id,
+                last_update_time: account.last_update_time,
+                seconds_since_update,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(BalanceStalenessResponse { accounts })
+}
+
+pub fn query_remote_balances(
+    deps: Deps,
+    account_id: AccountId,
+) -> StdResult<RemoteBalancesResponse> {
+    let balances = CHANNELS
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|r| {
+            r.and_then(|(host_chain, channel)| {
+                let account = ACCOUNTS.may_load(deps.storage, (&channel, account_id))?;
+                Ok(account.map(|account| (host_chain, account.remote_balance)))
+            })
+            .transpose()
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(RemoteBalancesResponse { balances })
+}
+
 pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     let Config {
         chain,
@@ -1,4 +1,4 @@
-use abstract_core::objects::module;
+use abstract_core::objects::{account_id::ACCOUNT_ID, fee::FixedFee, module, AccountId};
 
 use crate::contract::ModuleFactoryResponse;
 use crate::{
@@ -8,16 +8,20 @@ use crate::{
 use abstract_sdk::{
     core::{
         manager::ExecuteMsg as ManagerMsg,
-        objects::{module::ModuleInfo, module_reference::ModuleReference},
+        objects::{
+            module::{Module, ModuleInfo},
+            module_reference::ModuleReference,
+        },
     },
     feature_objects::VersionControlContract,
     *,
 };
 use cosmwasm_std::{
-    wasm_execute, Addr, BankMsg, Binary, CosmosMsg, DepsMut, Empty, Env, MessageInfo, ReplyOn,
-    StdError, StdResult, SubMsg, SubMsgResult, WasmMsg,
+    instantiate2_address, wasm_execute, Addr, BankMsg, Binary, CosmosMsg, Deps, DepsMut, Empty,
+    Env, MessageInfo, ReplyOn, StdError, StdResult, Storage, SubMsg, SubMsgResult, WasmMsg,
 };
 use protobuf::Message;
+use sha2::{Digest, Sha256};
 
 pub const CREATE_APP_RESPONSE_ID: u64 = 1u64;
 pub const CREATE_STANDALONE_RESPONSE_ID: u64 = 4u64;
@@ -41,10 +45,9 @@ pub fn execute_create_module(
     let account_base = account_registry.assert_manager(&info.sender)?;
 
     let new_module = version_registry.query_module(module_info.clone())?;
-    let new_module_monetization = version_registry
+    let new_module_config = version_registry
         .query_all_module_config(module_info)?
-        .config
-        .monetization;
+        .config;
 
     // TODO: check if this can be generalized for some contracts
     // aka have default values for each kind of module that only get overwritten if a specific init_msg is saved.
@@ -56,23 +59,33 @@ pub fn execute_create_module(
     // .format()?;
 
     // We validate the fee if it was required by the version control to install this module
-    let mut fee_msgs = vec![];
-    match new_module_monetization {
-        module::Monetization::InstallFee(f) => {
-            let fee = f.assert_payment(&info)?;
-            // We transfer that fee to the namespace owner if there is
+    let fee = match new_module_config.monetization {
+        module::Monetization::InstallFee(f) => Some(f.assert_payment(&info)?),
+        // The first period of a subscription is paid on install
+        module::Monetization::Subscription { per_period, .. } => {
+            Some(FixedFee::new(&per_period).assert_payment(&info)?)
+        }
+        module::Monetization::None => None,
+        // The monetization must be known to the factory for a module to be installed
+        _ => return Err(ModuleFactoryError::ModuleNotInstallable {}),
+    };
+    let fee_msgs = match (fee, new_module_config.revenue_split) {
+        // We split the fee between the recipients configured by the namespace owner
+        (Some(fee), Some(split)) => split.msgs(fee),
+        // Or transfer it to the namespace owner
+        (Some(fee), None) => {
             let namespace_account =
                 version_registry.query_namespace(new_module.info.namespace.clone())?;
-            fee_msgs.push(CosmosMsg::Bank(BankMsg::Send {
+            vec![CosmosMsg::Bank(BankMsg::Send {
                 to_address: namespace_account.account_base.proxy.to_string(),
                 amount: vec![fee],
-            }));
+            })]
         }
-        abstract_core::objects::module::Monetization::None => {}
-        // The monetization must be known to the factory for a module to be installed
-        _ => return Err(ModuleFactoryError::ModuleNotInstallable {}),
+        (None, _) => vec![],
     };
 
+    let account_id = ACCOUNT_ID.query(&deps.querier, account_base.manager.clone())?;
+
     // Set context for after init
     CONTEXT.save(
         deps.storage,
@@ -82,6 +95,12 @@ pub fn execute_create_module(
         },
     )?;
     let block_height = env.block.height;
+    let salt = match &new_module.reference {
+        ModuleReference::App(_) | ModuleReference::Standalone(_) => {
+            next_module_salt(deps.storage, account_id, &new_module.info.id())?
+        }
+        _ => Binary::default(),
+    };
     let resp = match &new_module.reference {
         ModuleReference::App(code_id) => instantiate_contract(
             block_height,
@@ -90,6 +109,7 @@ pub fn execute_create_module(
             Some(account_base.manager),
             CREATE_APP_RESPONSE_ID,
             new_module.info,
+            salt,
         ),
         ModuleReference::Adapter(addr) => {
             let module_id = new_module.info.id_with_version();
@@ -114,12 +134,60 @@ pub fn execute_create_module(
             Some(account_base.manager),
             CREATE_STANDALONE_RESPONSE_ID,
             new_module.info,
+            salt,
         ),
         _ => Err(ModuleFactoryError::ModuleNotInstallable {}),
     }?;
     Ok(resp.add_messages(fee_msgs))
 }
 
+/// Salt used to instantiate a module on an Account.
+/// Derived from the Account id, the module id and the number of previous installs of the module on the Account,
+/// so a reinstalled module gets a new address. The address also depends on the checksum of the module's code.
+pub fn module_salt(account_id: AccountId, module_id: &str, install_nonce: u32) -> Binary {
+    let mut hasher = Sha256::new();
+    hasher.update(account_id.to_be_bytes());
+    hasher.update(module_id.as_bytes());
+    hasher.update(install_nonce.to_be_bytes());
+    Binary::from(hasher.finalize().to_vec())
+}
+
+/// Salt for the next instantiation of the module on the Account, bumps the install nonce of the module.
+fn next_module_salt(
+    storage: &mut dyn Storage,
+    account_id: AccountId,
+    module_id: &str,
+) -> StdResult<Binary> {
+    let install_nonce = INSTALL_NONCES
+        .may_load(storage, (account_id, module_id))?
+        .unwrap_or_default();
+    INSTALL_NONCES.save(storage, (account_id, module_id), &(install_nonce + 1))?;
+    Ok(module_salt(account_id, module_id, install_nonce))
+}
+
+/// Address the module will have once it is installed (again) on the Account.
+/// Adapters are shared between Accounts so their registered address is returned.
+pub fn predicted_module_address(
+    deps: Deps,
+    env: &Env,
+    account_id: AccountId,
+    module: &Module,
+    install_nonce: u32,
+) -> ModuleFactoryResult<Addr> {
+    match &module.reference {
+        ModuleReference::App(code_id) | ModuleReference::Standalone(code_id) => {
+            let checksum = deps.querier.query_wasm_code_info(*code_id)?.checksum;
+            let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+            let salt = module_salt(account_id, &module.info.id(), install_nonce);
+            let address = instantiate2_address(checksum.as_slice(), &creator, &salt)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            Ok(deps.api.addr_humanize(&address)?)
+        }
+        ModuleReference::Adapter(addr) => Ok(addr.clone()),
+        _ => Err(ModuleFactoryError::ModuleNotInstallable {}),
+    }
+}
+
 fn instantiate_contract(
     block_height: u64,
     code_id: u64,
@@ -127,6 +195,7 @@ fn instantiate_contract(
     admin: Option<Addr>,
     reply_id: u64,
     module_info: ModuleInfo,
+    salt: Binary,
 ) -> ModuleFactoryResult {
     let response = ModuleFactoryResponse::new(
         "execute_create_module",
@@ -135,12 +204,13 @@ fn instantiate_contract(
     Ok(response.add_submessage(SubMsg {
         id: reply_id,
         gas_limit: None,
-        msg: WasmMsg::Instantiate {
+        msg: WasmMsg::Instantiate2 {
             code_id,
             funds: vec![],
             admin: admin.map(Into::into),
             label: format!("Module: {module_info}, Height {block_height}"),
             msg: init_msg,
+            salt,
         }
         .into(),
         reply_on: ReplyOn::Success,
@@ -152,6 +222,7 @@ pub fn register_contract(deps: DepsMut, result: SubMsgResult) -> ModuleFactoryRe
     let module = context.module.unwrap();
 
     // Get address of the new contract
+    // `MsgInstantiateContract2Response` shares its layout with `MsgInstantiateContractResponse`
     let res: MsgInstantiateContractResponse =
         Message::parse_from_bytes(result.unwrap().data.unwrap().as_slice()).map_err(|_| {
             StdError::parse_err("MsgInstantiateContractResponse", "failed to parse data")
@@ -336,6 +407,7 @@ mod test {
                     .unwrap();
 
             let some_block_height = 500;
+            let expected_salt = module_salt(1, &expected_module_info.id(), 0);
             let actual = instantiate_contract(
                 some_block_height,
                 expected_code_id,
@@ -343,14 +415,16 @@ mod test {
                 None,
                 expected_reply_id,
                 expected_module_info.clone(),
+                expected_salt.clone(),
             );
 
-            let expected_init_msg = WasmMsg::Instantiate {
+            let expected_init_msg = WasmMsg::Instantiate2 {
                 code_id: expected_code_id,
                 funds: vec![],
                 admin: None,
                 label: format!("Module: {expected_module_info}, Height {some_block_height}"),
                 msg: expected_module_init_msg,
+                salt: expected_salt,
             };
 
             assert_that!(actual).is_ok();
@@ -373,6 +447,72 @@ mod test {
         }
     }
 
+    mod module_salt {
+        use super::*;
+
+        #[test]
+        fn unique_per_account_module_and_install() {
+            let salt = module_salt(1, "test:module", 0);
+
+            assert_that!(salt.len()).is_equal_to(32);
+            assert_that!(module_salt(1, "test:module", 0)).is_equal_to(&salt);
+            assert_that!(module_salt(2, "test:module", 0)).is_not_equal_to(&salt);
+            assert_that!(module_salt(1, "test:other", 0)).is_not_equal_to(&salt);
+            assert_that!(module_salt(1, "test:module", 1)).is_not_equal_to(&salt);
+        }
+
+        #[test]
+        fn reinstall_uses_new_salt() -> StdResult<()> {
+            let mut deps = mock_dependencies();
+
+            let first_install = next_module_salt(deps.as_mut().storage, 1, "test:module")?;
+            let reinstall = next_module_salt(deps.as_mut().storage, 1, "test:module")?;
+            let other_account = next_module_salt(deps.as_mut().storage, 2, "test:module")?;
+
+            assert_that!(first_install).is_equal_to(module_salt(1, "test:module", 0));
+            assert_that!(reinstall).is_equal_to(module_salt(1, "test:module", 1));
+            assert_that!(other_account).is_equal_to(module_salt(2, "test:module", 0));
+            assert_that!(INSTALL_NONCES.load(&deps.storage, (1, "test:module"))?).is_equal_to(2);
+            Ok(())
+        }
+    }
+
+    mod predicted_module_address {
+        use super::*;
+        use abstract_core::objects::module::ModuleVersion;
+
+        fn test_module(reference: ModuleReference) -> Module {
+            Module {
+                info: ModuleInfo::from_id("test:module", ModuleVersion::Version("1.2.3".into()))
+                    .unwrap(),
+                reference,
+            }
+        }
+
+        #[test]
+        fn adapter_keeps_its_address() -> ModuleFactoryTestResult {
+            let deps = mock_dependencies();
+            let module = test_module(ModuleReference::Adapter(Addr::unchecked("adapter")));
+
+            let address = predicted_module_address(deps.as_ref(), &mock_env(), 1, &module, 0)?;
+
+            assert_that!(address).is_equal_to(Addr::unchecked("adapter"));
+            Ok(())
+        }
+
+        #[test]
+        fn rejects_non_installable_modules() {
+            let deps = mock_dependencies();
+            let module = test_module(ModuleReference::AccountBase(1));
+
+            let res = predicted_module_address(deps.as_ref(), &mock_env(), 1, &module, 0);
+
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(ModuleFactoryError::ModuleNotInstallable {});
+        }
+    }
+
     use cosmwasm_std::to_binary;
 
     mod update_factory_binaries {
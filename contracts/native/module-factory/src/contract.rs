@@ -1,9 +1,15 @@
 use crate::{commands, error::ModuleFactoryError, state::*};
-use abstract_core::objects::module_version::assert_contract_upgrade;
+use abstract_core::objects::{
+    module::ModuleInfo, module_version::assert_contract_upgrade, AccountId,
+};
 use abstract_macros::abstract_response;
-use abstract_sdk::core::{module_factory::*, MODULE_FACTORY};
+use abstract_sdk::{
+    core::{module_factory::*, MODULE_FACTORY},
+    feature_objects::VersionControlContract,
+    ModuleRegistryInterface,
+};
 use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult,
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult,
 };
 use cw2::set_contract_version;
 use semver::Version;
@@ -84,10 +90,13 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> ModuleFactoryResult {
 }
 
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::Config {} => to_binary(&query_config(deps)?),
         QueryMsg::Context {} => to_binary(&query_context(deps)?),
+        QueryMsg::PredictModuleAddress { account_id, module } => {
+            to_binary(&query_module_address(deps, &env, account_id, module)?)
+        }
         QueryMsg::Ownership {} => abstract_sdk::query_ownership!(deps),
     }
 }
@@ -102,6 +111,28 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
     Ok(resp)
 }
 
+pub fn query_module_address(
+    deps: Deps,
+    env: &Env,
+    account_id: AccountId,
+    module: ModuleInfo,
+) -> StdResult<ModuleAddressResponse> {
+    let config: Config = CONFIG.load(deps.storage)?;
+    let module = VersionControlContract::new(config.version_control_address)
+        .module_registry(deps)
+        .query_module(module)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    let install_nonce = INSTALL_NONCES
+        .may_load(deps.storage, (account_id, &module.info.id()))?
+        .unwrap_or_default();
+    let address = commands::predicted_module_address(deps, env, account_id, &module, install_nonce)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    Ok(ModuleAddressResponse {
+        address,
+        install_nonce,
+    })
+}
+
 pub fn query_context(deps: Deps) -> StdResult<ContextResponse> {
     let Context {
         account_base,
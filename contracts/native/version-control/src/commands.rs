@@ -1,12 +1,13 @@
 use abstract_core::objects::{
-    fee::FixedFee,
+    fee::{FeeSplit, FixedFee},
     module::{self, Module, ModuleMetadata, Monetization},
-    validation::validate_link,
+    validation::{validate_description, validate_link},
 };
 use cosmwasm_std::{
-    ensure, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, MessageInfo, Order,
+    ensure, Addr, Attribute, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order,
     QuerierWrapper, Response, StdResult, Storage,
 };
+use cw_storage_plus::Map;
 
 use abstract_sdk::{
     core::{
@@ -17,7 +18,10 @@ use abstract_sdk::{
             namespace::Namespace,
             AccountId,
         },
-        version_control::{namespaces_info, state::*, AccountBase, Config},
+        version_control::{
+            namespaces_info, state::*, AccountBase, Config, ModuleDeprecation, ModuleReview,
+            NamespaceOffer, ReviewerSet,
+        },
     },
     cw_helpers::wasm_raw_query,
 };
@@ -32,24 +36,63 @@ pub fn add_account(
     msg_info: MessageInfo,
     account_id: AccountId,
     account_base: AccountBase,
+    parent_account_id: Option<AccountId>,
 ) -> VCResult {
     // Only Factory can add new Account
     FACTORY.assert_admin(deps.as_ref(), &msg_info.sender)?;
     ACCOUNT_ADDRESSES.save(deps.storage, account_id, &account_base)?;
 
-    Ok(VcResponse::new(
+    let mut response = VcResponse::new(
         "add_account",
         vec![
             ("account_id", account_id.to_string().as_str()),
             ("manager", account_base.manager.as_ref()),
             ("proxy", account_base.proxy.as_ref()),
         ],
+    );
+
+    if let Some(parent_account_id) = parent_account_id {
+        if !ACCOUNT_ADDRESSES.has(deps.storage, parent_account_id) {
+            return Err(VCError::UnknownAccountId {
+                id: parent_account_id,
+            });
+        }
+        ACCOUNT_PARENTS.save(deps.storage, account_id, &parent_account_id)?;
+        response = response.add_attribute("parent_account_id", parent_account_id.to_string());
+    }
+
+    Ok(response)
+}
+
+/// Remove the parent of a sub-account, called by its manager when its governance changes.
+pub fn remove_account_parent(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    account_id: AccountId,
+) -> VCResult {
+    let account_base = ACCOUNT_ADDRESSES
+        .may_load(deps.storage, account_id)?
+        .ok_or(VCError::UnknownAccountId { id: account_id })?;
+    ensure!(
+        msg_info.sender == account_base.manager,
+        VCError::NotAccountManager {
+            sender: msg_info.sender,
+            account_id,
+        }
+    );
+
+    ACCOUNT_PARENTS.remove(deps.storage, account_id);
+
+    Ok(VcResponse::new(
+        "remove_account_parent",
+        vec![("account_id", account_id.to_string())],
     ))
 }
 
 /// Here we can add logic to allow subscribers to claim a namespace and upload contracts to that namespace
 pub fn propose_modules(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     modules: Vec<(ModuleInfo, ModuleReference)>,
 ) -> VCResult {
@@ -75,7 +118,7 @@ pub fn propose_modules(
             cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
         } else {
             // Only owner can add modules
-            validate_account_owner(deps.as_ref(), &module.namespace, &msg_info.sender)?;
+            validate_account_owner(deps.as_ref(), &env, &module.namespace, &msg_info.sender)?;
         }
 
         // verify contract admin is None if module is Adapter
@@ -113,6 +156,10 @@ pub fn approve_or_reject_modules(
 ) -> VCResult {
     // Only Admin can approve or rejects a module
     cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+    // The reviewers replace the Admin once they are set
+    if REVIEWERS.exists(deps.storage) {
+        return Err(VCError::ReviewersSet {});
+    }
 
     let mut attributes = vec![];
     if !approves.is_empty() {
@@ -138,6 +185,7 @@ fn approve_modules(storage: &mut dyn Storage, approves: Vec<ModuleInfo>) -> VCRe
         REGISTERED_MODULES.save(storage, module, &mod_ref)?;
         // Remove from pending
         PENDING_MODULES.remove(storage, module);
+        PENDING_REVIEWS.remove(storage, module);
     }
 
     let approves: Vec<_> = approves.into_iter().map(|m| m.to_string()).collect();
@@ -151,12 +199,119 @@ fn reject_modules(storage: &mut dyn Storage, rejects: Vec<ModuleInfo>) -> VCResu
             return Err(VCError::ModuleNotFound(module.clone()));
         }
         PENDING_MODULES.remove(storage, module);
+        PENDING_REVIEWS.remove(storage, module);
     }
 
     let rejects: Vec<_> = rejects.into_iter().map(|m| m.to_string()).collect();
     Ok(("rejects", rejects.join(",")).into())
 }
 
+/// Review a pending module as one of the reviewers
+/// The module is approved once enough reviewers approve it and rejected once it can't reach the threshold
+pub fn review_module(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    module: ModuleInfo,
+    approve: bool,
+    comment: Option<String>,
+) -> VCResult {
+    let reviewer_set = REVIEWERS
+        .may_load(deps.storage)?
+        .ok_or(VCError::NoReviewers {})?;
+    if !reviewer_set.reviewers.contains(&msg_info.sender) {
+        return Err(VCError::NotReviewer(msg_info.sender));
+    }
+    if !PENDING_MODULES.has(deps.storage, &module) {
+        return Err(VCError::ModuleNotFound(module));
+    }
+    validate_description(&comment)?;
+
+    let mut reviews = PENDING_REVIEWS
+        .may_load(deps.storage, &module)?
+        .unwrap_or_default();
+    // a reviewer can change its review
+    reviews.retain(|review| review.reviewer != msg_info.sender);
+    reviews.push(ModuleReview {
+        reviewer: msg_info.sender,
+        approve,
+        comment,
+    });
+
+    let (approvals, rejections) = reviewer_set.tally(&reviews);
+    let max_rejections = reviewer_set.reviewers.len() as u32 - reviewer_set.threshold;
+    let status = if approvals >= reviewer_set.threshold {
+        approve_modules(deps.storage, vec![module.clone()])?;
+        "approved"
+    } else if rejections > max_rejections {
+        reject_modules(deps.storage, vec![module.clone()])?;
+        "rejected"
+    } else {
+        PENDING_REVIEWS.save(deps.storage, &module, &reviews)?;
+        "pending"
+    };
+
+    Ok(VcResponse::new(
+        "review_module",
+        vec![
+            ("module", module.to_string()),
+            ("approve", approve.to_string()),
+            ("status", status.to_string()),
+        ],
+    ))
+}
+
+/// Set the reviewers of pending modules, removes them if none are provided
+pub fn update_reviewers(
+    deps: DepsMut,
+    msg_info: MessageInfo,
+    reviewers: Vec<String>,
+    threshold: u32,
+) -> VCResult {
+    cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
+
+    if reviewers.is_empty() {
+        REVIEWERS.remove(deps.storage);
+        return Ok(VcResponse::new(
+            "update_reviewers",
+            vec![("reviewers", "none")],
+        ));
+    }
+
+    let mut reviewers = reviewers
+        .iter()
+        .map(|reviewer| deps.api.addr_validate(reviewer))
+        .collect::<StdResult<Vec<_>>>()?;
+    reviewers.sort();
+    reviewers.dedup();
+    if threshold == 0 || threshold as usize > reviewers.len() {
+        return Err(VCError::InvalidReviewThreshold {
+            threshold,
+            reviewers: reviewers.len(),
+        });
+    }
+
+    let attributes = vec![
+        (
+            "reviewers",
+            reviewers
+                .iter()
+                .map(Addr::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        ("threshold", threshold.to_string()),
+    ];
+    REVIEWERS.save(
+        deps.storage,
+        &ReviewerSet {
+            reviewers,
+            threshold,
+        },
+    )?;
+
+    Ok(VcResponse::new("update_reviewers", attributes))
+}
+
 /// Remove a module from the Version Control registry.
 pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) -> VCResult {
     // Only the Version Control Admin can remove modules
@@ -173,6 +328,7 @@ pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) -
     REGISTERED_MODULES.remove(deps.storage, &module);
     YANKED_MODULES.remove(deps.storage, &module);
     MODULE_METADATA.remove(deps.storage, &module);
+    DEPRECATED_MODULES.remove(deps.storage, &module);
 
     // If this module has no more versions, we also remove the monetization
     if REGISTERED_MODULES
@@ -191,9 +347,9 @@ pub fn remove_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) -
 }
 
 /// Yank a module, preventing it from being used.
-pub fn yank_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) -> VCResult {
+pub fn yank_module(deps: DepsMut, env: Env, msg_info: MessageInfo, module: ModuleInfo) -> VCResult {
     // validate the caller is the owner of the namespace
-    validate_account_owner(deps.as_ref(), &module.namespace, &msg_info.sender)?;
+    validate_account_owner(deps.as_ref(), &env, &module.namespace, &msg_info.sender)?;
 
     // Only specific versions may be yanked
     module.assert_version_variant()?;
@@ -210,13 +366,56 @@ pub fn yank_module(deps: DepsMut, msg_info: MessageInfo, module: ModuleInfo) ->
     ))
 }
 
+/// Deprecate a module, it can still be installed but points to its successor.
+pub fn deprecate_module(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    module: ModuleInfo,
+    successor: Option<ModuleInfo>,
+    reason: String,
+) -> VCResult {
+    // validate the caller is the owner of the namespace
+    validate_account_owner(deps.as_ref(), &env, &module.namespace, &msg_info.sender)?;
+
+    // Only specific versions may be deprecated
+    module.assert_version_variant()?;
+    ensure!(
+        REGISTERED_MODULES.has(deps.storage, &module),
+        VCError::ModuleNotFound(module)
+    );
+
+    let mut attributes = vec![("module", module.to_string())];
+    if let Some(successor) = &successor {
+        successor.validate()?;
+        if let ModuleVersion::Version(_) = successor.version {
+            ensure!(
+                REGISTERED_MODULES.has(deps.storage, successor),
+                VCError::ModuleNotFound(successor.clone())
+            );
+        }
+        attributes.push(("successor", successor.to_string()));
+    }
+    validate_description(&Some(reason.clone()))?;
+
+    DEPRECATED_MODULES.save(
+        deps.storage,
+        &module,
+        &ModuleDeprecation { successor, reason },
+    )?;
+
+    Ok(VcResponse::new("deprecate_module", attributes))
+}
+
 /// Set a module monetization allowing the namespace owner to charge for module installation/usage or else.
 pub fn set_module_monetization(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     module_name: String,
     namespace: Namespace,
     monetization: Monetization,
+    revenue_split: Option<FeeSplit>,
 ) -> VCResult {
     // validate the caller is the owner of the namespace
 
@@ -225,7 +424,7 @@ pub fn set_module_monetization(
         cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
     } else {
         // Only owner can add modules
-        validate_account_owner(deps.as_ref(), &namespace, &msg_info.sender)?;
+        validate_account_owner(deps.as_ref(), &env, &namespace, &msg_info.sender)?;
     }
 
     // We verify the module exists before updating the monetization
@@ -241,7 +440,27 @@ pub fn set_module_monetization(
             })
         })??;
 
+    if let Monetization::Subscription {
+        per_period,
+        period_seconds,
+    } = &monetization
+    {
+        if *period_seconds == 0 {
+            return Err(VCError::InvalidSubscriptionPeriod {});
+        }
+        if per_period.amount.is_zero() {
+            return Err(VCError::InvalidSubscriptionPrice {});
+        }
+    }
+
     MODULE_MONETIZATION.save(deps.storage, (&namespace, &module_name), &monetization)?;
+    match revenue_split {
+        Some(split) => {
+            let split = split.check(deps.api)?;
+            MODULE_REVENUE_SPLIT.save(deps.storage, (&namespace, &module_name), &split)?;
+        }
+        None => MODULE_REVENUE_SPLIT.remove(deps.storage, (&namespace, &module_name)),
+    }
 
     Ok(VcResponse::new(
         "set_monetization",
@@ -255,6 +474,7 @@ pub fn set_module_monetization(
 /// Set a module metadata.
 pub fn set_module_metadata(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     module: ModuleInfo,
     metadata: ModuleMetadata,
@@ -266,7 +486,7 @@ pub fn set_module_metadata(
         cw_ownable::assert_owner(deps.storage, &msg_info.sender)?;
     } else {
         // Only owner can add modules
-        validate_account_owner(deps.as_ref(), &module.namespace, &msg_info.sender)?;
+        validate_account_owner(deps.as_ref(), &env, &module.namespace, &msg_info.sender)?;
     }
 
     // We verify the module exists before updating the monetization
@@ -274,78 +494,87 @@ pub fn set_module_metadata(
         return Err(VCError::ModuleNotFound(module));
     }
 
-    // We verify the metadata is a URL
-    validate_link(&Some(metadata.clone()))?;
+    metadata.validate()?;
 
     MODULE_METADATA.save(deps.storage, &module, &metadata)?;
 
     Ok(VcResponse::new(
         "set_metadata",
-        vec![("module", &module.to_string()), ("metadata", &metadata)],
+        vec![("module", module.to_string())],
     ))
 }
 
+/// Move the free-form metadata strings stored before [`ModuleMetadata`] was introduced into the structured type.
+/// Links become the module repository, any other text becomes its description.
+pub fn migrate_module_metadata(storage: &mut dyn Storage) -> StdResult<()> {
+    const LEGACY_MODULE_METADATA: Map<&ModuleInfo, String> = Map::new("mod_meta");
+
+    let legacy = LEGACY_MODULE_METADATA
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (module, text) in legacy {
+        LEGACY_MODULE_METADATA.remove(storage, &module);
+        if text.is_empty() || MODULE_METADATA.has(storage, &module) {
+            continue;
+        }
+        let metadata = if validate_link(&Some(text.clone())).is_ok() {
+            ModuleMetadata {
+                repository: Some(text),
+                ..Default::default()
+            }
+        } else {
+            ModuleMetadata {
+                description: Some(text),
+                ..Default::default()
+            }
+        };
+        MODULE_METADATA.save(storage, &module, &metadata)?;
+    }
+    Ok(())
+}
+
 /// Claim namespaces
 /// Only the Account Owner can do this
+/// An expired namespace can be claimed by another Account
 pub fn claim_namespace(
     deps: DepsMut,
+    env: Env,
     msg_info: MessageInfo,
     account_id: AccountId,
     namespace_to_claim: String,
 ) -> VCResult {
     // verify account owner
-    let account_base = ACCOUNT_ADDRESSES.load(deps.storage, account_id)?;
-    let account_owner = query_account_owner(&deps.querier, &account_base.manager, account_id)?;
-    if msg_info.sender != account_owner {
-        return Err(VCError::AccountOwnerMismatch {
-            sender: msg_info.sender,
-            owner: account_owner,
-        });
-    }
+    assert_account_owner(deps.as_ref(), account_id, &msg_info.sender)?;
 
-    // check if the account already has a namespace
-    let has_namespace = namespaces_info()
-        .idx
-        .account_id
-        .prefix(account_id)
-        .range(deps.storage, None, None, Order::Ascending)
-        .take(1)
-        .count()
-        == 1;
-    if has_namespace {
-        return Err(VCError::ExceedsNamespaceLimit {
-            limit: 1,
-            current: 1,
-        });
-    }
-
-    let Config {
-        namespace_registration_fee: fee,
-        ..
-    } = CONFIG.load(deps.storage)?;
-
-    let mut fee_messages = vec![];
+    let config = CONFIG.load(deps.storage)?;
 
-    if !fee.amount.is_zero() {
-        // assert it is paid
-        FixedFee::new(&fee).assert_payment(&msg_info)?;
+    // check if the account can claim another namespace
+    assert_namespace_limit(deps.storage, &env, &config, account_id)?;
 
-        // We transfer the namespace fee if necessary
-        let admin_account = ACCOUNT_ADDRESSES.load(deps.storage, 0)?;
-        fee_messages.push(CosmosMsg::Bank(BankMsg::Send {
-            to_address: admin_account.proxy.to_string(),
-            amount: msg_info.funds, //
-        }));
-    }
+    let fee_messages = namespace_fee_msgs(deps.as_ref(), &msg_info, &config)?;
 
     let namespace = Namespace::try_from(&namespace_to_claim)?;
     if let Some(id) = namespaces_info().may_load(deps.storage, &namespace)? {
-        return Err(VCError::NamespaceOccupied {
-            namespace: namespace.to_string(),
-            id,
-        });
+        // the owner renews an expired claim instead of claiming it again
+        if !is_namespace_expired(deps.storage, &env, &namespace)? || id == account_id {
+            return Err(VCError::NamespaceOccupied {
+                namespace: namespace.to_string(),
+                id,
+            });
+        }
+        release_namespace(deps.storage, &namespace)?;
     }
     namespaces_info().save(deps.storage, &namespace, &account_id)?;
+    if config.namespace_registration_period > 0 {
+        NAMESPACE_EXPIRATIONS.save(
+            deps.storage,
+            &namespace,
+            &env.block
+                .time
+                .plus_seconds(config.namespace_registration_period),
+        )?;
+    }
 
     Ok(VcResponse::new(
         "claim_namespace",
@@ -357,6 +586,315 @@ pub fn claim_namespace(
     .add_messages(fee_messages))
 }
 
+/// Renew the claim of a namespace for another registration period
+/// Only the Account Owner can do this, also after the claim expired as long as no other Account claimed it
+pub fn renew_namespace(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    namespace: String,
+) -> VCResult {
+    let namespace = Namespace::try_from(&namespace)?;
+    assert_namespace_claimant(deps.as_ref(), &namespace, &msg_info.sender)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    if config.namespace_registration_period == 0 {
+        return Err(VCError::NamespaceDoesNotExpire {});
+    }
+    // an expired claim no longer counts towards the limit, so renewing it claims it again
+    if is_namespace_expired(deps.storage, &env, &namespace)? {
+        let account_id = namespaces_info().load(deps.storage, &namespace)?;
+        assert_namespace_limit(deps.storage, &env, &config, account_id)?;
+    }
+
+    let fee_messages = namespace_fee_msgs(deps.as_ref(), &msg_info, &config)?;
+
+    // claims made before registration periods were enabled expire from now on
+    let expires_at = NAMESPACE_EXPIRATIONS
+        .may_load(deps.storage, &namespace)?
+        .map_or(env.block.time, |expires_at| expires_at.max(env.block.time))
+        .plus_seconds(config.namespace_registration_period);
+    NAMESPACE_EXPIRATIONS.save(deps.storage, &namespace, &expires_at)?;
+
+    Ok(VcResponse::new(
+        "renew_namespace",
+        vec![
+            ("namespace", namespace.to_string()),
+            ("expires_at", expires_at.to_string()),
+        ],
+    )
+    .add_messages(fee_messages))
+}
+
+/// Transfer a namespace and its registered modules to another Account
+/// Only the Account Owner can do this
+pub fn transfer_namespace(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    namespace: String,
+    to_account: AccountId,
+) -> VCResult {
+    let namespace = Namespace::try_from(&namespace)?;
+    validate_account_owner(deps.as_ref(), &env, &namespace, &msg_info.sender)?;
+
+    let from_account = move_namespace(deps.storage, &env, &namespace, to_account)?;
+
+    Ok(VcResponse::new(
+        "transfer_namespace",
+        vec![
+            ("namespace", namespace.to_string()),
+            ("from_account", from_account.to_string()),
+            ("to_account", to_account.to_string()),
+        ],
+    ))
+}
+
+/// Offer a namespace for sale, replacing the previous offer
+/// Only the Account Owner can do this
+pub fn offer_namespace(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    namespace: String,
+    price: Coin,
+    to_account: Option<AccountId>,
+) -> VCResult {
+    let namespace = Namespace::try_from(&namespace)?;
+    validate_account_owner(deps.as_ref(), &env, &namespace, &msg_info.sender)?;
+
+    let seller = namespaces_info().load(deps.storage, &namespace)?;
+    if let Some(buyer) = to_account {
+        if buyer == seller {
+            return Err(VCError::NamespaceOccupied {
+                namespace: namespace.to_string(),
+                id: seller,
+            });
+        }
+        if !ACCOUNT_ADDRESSES.has(deps.storage, buyer) {
+            return Err(VCError::UnknownAccountId { id: buyer });
+        }
+    }
+
+    NAMESPACE_OFFERS.save(
+        deps.storage,
+        &namespace,
+        &NamespaceOffer {
+            seller,
+            buyer: to_account,
+            price: price.clone(),
+        },
+    )?;
+
+    Ok(VcResponse::new(
+        "offer_namespace",
+        vec![
+            ("namespace", namespace.to_string()),
+            ("price", price.to_string()),
+        ],
+    ))
+}
+
+/// Withdraw the open offer for a namespace
+/// Only the Account Owner can do this
+pub fn cancel_namespace_offer(deps: DepsMut, msg_info: MessageInfo, namespace: String) -> VCResult {
+    let namespace = Namespace::try_from(&namespace)?;
+    assert_namespace_claimant(deps.as_ref(), &namespace, &msg_info.sender)?;
+    if !NAMESPACE_OFFERS.has(deps.storage, &namespace) {
+        return Err(VCError::NoNamespaceOffer { namespace });
+    }
+    NAMESPACE_OFFERS.remove(deps.storage, &namespace);
+
+    Ok(VcResponse::new(
+        "cancel_namespace_offer",
+        vec![("namespace", namespace.to_string())],
+    ))
+}
+
+/// Buy an offered namespace and pay the price to the selling Account
+/// Only the Owner of the buying Account can do this
+pub fn accept_namespace_offer(
+    deps: DepsMut,
+    env: Env,
+    msg_info: MessageInfo,
+    namespace: String,
+    account_id: AccountId,
+) -> VCResult {
+    let namespace = Namespace::try_from(&namespace)?;
+    assert_account_owner(deps.as_ref(), account_id, &msg_info.sender)?;
+
+    let offer = NAMESPACE_OFFERS
+        .may_load(deps.storage, &namespace)?
+        .ok_or_else(|| VCError::NoNamespaceOffer {
+            namespace: namespace.clone(),
+        })?;
+    if let Some(buyer) = offer.buyer {
+        if buyer != account_id {
+            return Err(VCError::NamespaceOfferedToOther { namespace, buyer });
+        }
+    }
+    // funds sent along with a free offer would be stuck in the contract
+    if offer.price.amount.is_zero() && !msg_info.funds.is_empty() {
+        return Err(VCError::InvalidFeePayment {
+            expected: offer.price,
+            sent: msg_info.funds,
+        });
+    }
+    let price = FixedFee::new(&offer.price).assert_payment(&msg_info)?;
+
+    let seller = move_namespace(deps.storage, &env, &namespace, account_id)?;
+
+    let mut response = VcResponse::new(
+        "accept_namespace_offer",
+        vec![
+            ("namespace", namespace.to_string()),
+            ("from_account", seller.to_string()),
+            ("to_account", account_id.to_string()),
+            ("price", price.to_string()),
+        ],
+    );
+    if !price.amount.is_zero() {
+        let seller_account = ACCOUNT_ADDRESSES.load(deps.storage, seller)?;
+        response = response.add_message(BankMsg::Send {
+            to_address: seller_account.proxy.to_string(),
+            amount: vec![price],
+        });
+    }
+    Ok(response)
+}
+
+/// Move a namespace to another Account, keeping its registered modules and expiration
+/// Returns the ID of the Account that claimed the namespace before
+fn move_namespace(
+    storage: &mut dyn Storage,
+    env: &Env,
+    namespace: &Namespace,
+    to_account: AccountId,
+) -> VCResult<AccountId> {
+    ensure!(
+        !is_namespace_expired(storage, env, namespace)?,
+        VCError::NamespaceExpired {
+            namespace: namespace.clone()
+        }
+    );
+    let from_account = namespaces_info().load(storage, namespace)?;
+    if from_account == to_account {
+        return Err(VCError::NamespaceOccupied {
+            namespace: namespace.to_string(),
+            id: from_account,
+        });
+    }
+    if !ACCOUNT_ADDRESSES.has(storage, to_account) {
+        return Err(VCError::UnknownAccountId { id: to_account });
+    }
+    let config = CONFIG.load(storage)?;
+    assert_namespace_limit(storage, env, &config, to_account)?;
+
+    namespaces_info().save(storage, namespace, &to_account)?;
+    NAMESPACE_OFFERS.remove(storage, namespace);
+    // revenue of the previous owner must not keep flowing from the modules of the new owner
+    clear_revenue_splits(storage, namespace)?;
+    Ok(from_account)
+}
+
+/// Remove the revenue splits of the modules in the namespace
+/// Their monetization stays, fees without a split go to the current namespace owner
+fn clear_revenue_splits(storage: &mut dyn Storage, namespace: &Namespace) -> StdResult<()> {
+    let split = MODULE_REVENUE_SPLIT
+        .prefix(namespace)
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for name in split {
+        MODULE_REVENUE_SPLIT.remove(storage, (namespace, &name));
+    }
+    Ok(())
+}
+
+/// Assert the Account can claim another namespace
+/// Expired claims don't count towards the limit
+fn assert_namespace_limit(
+    storage: &dyn Storage,
+    env: &Env,
+    config: &Config,
+    account_id: AccountId,
+) -> VCResult<()> {
+    let mut current = 0;
+    for namespace in namespaces_info().idx.account_id.prefix(account_id).keys(
+        storage,
+        None,
+        None,
+        Order::Ascending,
+    ) {
+        if !is_namespace_expired(storage, env, &namespace?)? {
+            current += 1;
+        }
+    }
+    if current >= config.namespace_limit as usize {
+        return Err(VCError::ExceedsNamespaceLimit {
+            limit: config.namespace_limit as usize,
+            current,
+        });
+    }
+    Ok(())
+}
+
+fn is_namespace_expired(
+    storage: &dyn Storage,
+    env: &Env,
+    namespace: &Namespace,
+) -> StdResult<bool> {
+    Ok(NAMESPACE_EXPIRATIONS
+        .may_load(storage, namespace)?
+        .map_or(false, |expires_at| env.block.time >= expires_at))
+}
+
+/// Assert the namespace registration fee is paid and transfer it to the admin Account
+fn namespace_fee_msgs(
+    deps: Deps,
+    msg_info: &MessageInfo,
+    config: &Config,
+) -> VCResult<Vec<CosmosMsg>> {
+    let fee = &config.namespace_registration_fee;
+    if fee.amount.is_zero() {
+        return Ok(vec![]);
+    }
+    // assert it is paid
+    FixedFee::new(fee).assert_payment(msg_info)?;
+
+    // We transfer the namespace fee if necessary
+    let admin_account = ACCOUNT_ADDRESSES.load(deps.storage, 0)?;
+    Ok(vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: admin_account.proxy.to_string(),
+        amount: msg_info.funds.clone(),
+    })])
+}
+
+/// Remove the claim of a namespace and yank its modules
+/// Returns the ID of the Account that claimed the namespace
+fn release_namespace(storage: &mut dyn Storage, namespace: &Namespace) -> VCResult<AccountId> {
+    for ((name, version), mod_ref) in REGISTERED_MODULES
+        .sub_prefix(namespace.clone())
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?
+        .into_iter()
+    {
+        let module = ModuleInfo {
+            namespace: namespace.clone(),
+            name,
+            version: ModuleVersion::Version(version),
+        };
+        REGISTERED_MODULES.remove(storage, &module);
+        YANKED_MODULES.save(storage, &module, &mod_ref)?;
+    }
+
+    let account_id = namespaces_info().load(storage, namespace)?;
+    namespaces_info().remove(storage, namespace)?;
+    NAMESPACE_EXPIRATIONS.remove(storage, namespace);
+    NAMESPACE_OFFERS.remove(storage, namespace);
+    clear_revenue_splits(storage, namespace)?;
+    Ok(account_id)
+}
+
 /// Remove namespaces
 /// Only admin or the account owner can do this
 pub fn remove_namespaces(
@@ -373,30 +911,11 @@ pub fn remove_namespaces(
             return Err(VCError::UnknownNamespace { namespace });
         }
         if !is_admin {
-            validate_account_owner(deps.as_ref(), &namespace, &msg_info.sender)?;
+            assert_namespace_claimant(deps.as_ref(), &namespace, &msg_info.sender)?;
         }
 
-        for ((name, version), mod_ref) in REGISTERED_MODULES
-            .sub_prefix(namespace.clone())
-            .range(deps.storage, None, None, Order::Ascending)
-            .collect::<StdResult<Vec<_>>>()?
-            .into_iter()
-        {
-            let module = ModuleInfo {
-                namespace: namespace.clone(),
-                name,
-                version: ModuleVersion::Version(version),
-            };
-            REGISTERED_MODULES.remove(deps.storage, &module);
-            YANKED_MODULES.save(deps.storage, &module, &mod_ref)?;
-        }
-
-        logs.push(format!(
-            "({}, {})",
-            namespace,
-            namespaces_info().load(deps.storage, &namespace)?
-        ));
-        namespaces_info().remove(deps.storage, &namespace)?;
+        let account_id = release_namespace(deps.storage, &namespace)?;
+        logs.push(format!("({namespace}, {account_id})"));
     }
 
     Ok(VcResponse::new(
@@ -410,6 +929,8 @@ pub fn update_config(
     info: MessageInfo,
     allow_direct_module_registration_and_updates: Option<bool>,
     namespace_registration_fee: Option<Coin>,
+    namespace_limit: Option<u32>,
+    namespace_registration_period: Option<u64>,
 ) -> VCResult {
     cw_ownable::assert_owner(deps.storage, &info.sender)?;
     let mut config = CONFIG.load(deps.storage)?;
@@ -446,6 +967,22 @@ pub fn update_config(
         ])
     }
 
+    if let Some(limit) = namespace_limit {
+        if limit < config.namespace_limit {
+            return Err(VCError::DecreaseNamespaceLimit {
+                limit,
+                current: config.namespace_limit,
+            });
+        }
+        config.namespace_limit = limit;
+        attributes.push(("namespace_limit", limit.to_string()));
+    }
+
+    if let Some(period) = namespace_registration_period {
+        config.namespace_registration_period = period;
+        attributes.push(("namespace_registration_period", period.to_string()));
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(VcResponse::new("update_config", attributes))
@@ -462,26 +999,59 @@ pub fn query_account_owner(
     owner.ok_or(VCError::NoAccountOwner { account_id })
 }
 
+/// Assert that the sender owns the Account that claimed the namespace and that the claim did not expire.
 pub fn validate_account_owner(
     deps: Deps,
+    env: &Env,
     namespace: &Namespace,
     sender: &Addr,
 ) -> Result<(), VCError> {
-    let sender = sender.clone();
+    assert_namespace_claimant(deps, namespace, sender)?;
+    ensure!(
+        !is_namespace_expired(deps.storage, env, namespace)?,
+        VCError::NamespaceExpired {
+            namespace: namespace.clone()
+        }
+    );
+    Ok(())
+}
+
+/// Assert that the sender owns the Account that claimed the namespace, also if the claim expired.
+fn assert_namespace_claimant(deps: Deps, namespace: &Namespace, sender: &Addr) -> VCResult<()> {
     let account_id = namespaces_info()
         .may_load(deps.storage, &namespace.clone())?
         .ok_or_else(|| VCError::UnknownNamespace {
             namespace: namespace.to_owned(),
         })?;
+    assert_account_owner(deps, account_id, sender)
+}
+
+/// Assert that the sender is the owner of the Account or of one of its parent Accounts.
+pub fn assert_account_owner(deps: Deps, account_id: AccountId, sender: &Addr) -> VCResult<()> {
     let account_base = ACCOUNT_ADDRESSES.load(deps.storage, account_id)?;
     let account_owner = query_account_owner(&deps.querier, &account_base.manager, account_id)?;
-    if sender != account_owner {
-        return Err(VCError::AccountOwnerMismatch {
-            sender,
-            owner: account_owner,
-        });
+    if *sender == account_owner {
+        return Ok(());
     }
-    Ok(())
+
+    // walk up the sub-account tree
+    let mut current_account_id = account_id;
+    while let Some(parent_account_id) =
+        ACCOUNT_PARENTS.may_load(deps.storage, current_account_id)?
+    {
+        let parent_base = ACCOUNT_ADDRESSES.load(deps.storage, parent_account_id)?;
+        let parent_owner =
+            query_account_owner(&deps.querier, &parent_base.manager, parent_account_id)?;
+        if *sender == parent_owner {
+            return Ok(());
+        }
+        current_account_id = parent_account_id;
+    }
+
+    Err(VCError::AccountOwnerMismatch {
+        sender: sender.clone(),
+        owner: account_owner,
+    })
 }
 
 pub fn set_factory(deps: DepsMut, info: MessageInfo, new_admin: String) -> VCResult {
@@ -495,13 +1065,16 @@ pub fn set_factory(deps: DepsMut, info: MessageInfo, new_admin: String) -> VCRes
 #[cfg(test)]
 mod test {
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-    use cosmwasm_std::{from_binary, to_binary, Addr, Coin, Uint64};
+    use cosmwasm_std::{from_binary, from_slice, to_binary, Addr, Coin, Decimal, Uint64};
     use cw_controllers::AdminError;
     use cw_ownable::OwnershipError;
     use speculoos::prelude::*;
 
     use abstract_core::manager::ConfigResponse as ManagerConfigResponse;
+    use abstract_core::objects::fee::UsageFee;
+    use abstract_core::objects::validation::ValidationError;
     use abstract_core::version_control::*;
+    use abstract_core::AbstractError;
     use abstract_testing::prelude::TEST_MODULE_ID;
     use abstract_testing::prelude::{
         TEST_ACCOUNT_FACTORY, TEST_ACCOUNT_ID, TEST_ADMIN, TEST_MODULE_FACTORY, TEST_NAMESPACE,
@@ -558,6 +1131,8 @@ mod test {
             InstantiateMsg {
                 allow_direct_module_registration_and_updates: Some(true),
                 namespace_registration_fee: None,
+                namespace_limit: None,
+                namespace_registration_period: None,
             },
         )?;
         execute_as_admin(
@@ -578,6 +1153,8 @@ mod test {
             InstantiateMsg {
                 allow_direct_module_registration_and_updates: Some(direct_registration_and_update),
                 namespace_registration_fee: None,
+                namespace_limit: None,
+                namespace_registration_period: None,
             },
         )?;
         execute_as_admin(
@@ -595,6 +1172,7 @@ mod test {
                     manager: Addr::unchecked(TEST_MANAGER),
                     proxy: Addr::unchecked(TEST_PROXY),
                 },
+                parent_account_id: None,
             },
         )
     }
@@ -610,6 +1188,7 @@ mod test {
                     manager: Addr::unchecked(TEST_MANAGER),
                     proxy: Addr::unchecked(TEST_PROXY),
                 },
+                parent_account_id: None,
             },
         )
         .unwrap();
@@ -716,6 +1295,7 @@ mod test {
 
     mod claim_namespace {
         use super::*;
+        use crate::queries;
         use abstract_core::{objects, AbstractError};
         use cosmwasm_std::{coins, BankMsg, CosmosMsg, SubMsg};
 
@@ -768,6 +1348,8 @@ mod test {
                 ExecuteMsg::UpdateConfig {
                     allow_direct_module_registration_and_updates: None,
                     namespace_registration_fee: Some(one_namespace_fee.clone()),
+                    namespace_limit: None,
+                    namespace_registration_period: None,
                 },
             )
             .unwrap();
@@ -783,6 +1365,7 @@ mod test {
                         manager: Addr::unchecked(TEST_MANAGER),
                         proxy: Addr::unchecked(TEST_ADMIN_PROXY),
                     },
+                    parent_account_id: None,
                 },
             )
             .unwrap();
@@ -853,6 +1436,69 @@ mod test {
             Ok(())
         }
 
+        #[test]
+        fn claim_namespaces_by_parent_owner() -> VersionControlTestResult {
+            const SUB_MANAGER: &str = "sub_manager";
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier()
+                .with_owner(SUB_MANAGER, Some(TEST_MANAGER))
+                .build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            // create sub-account
+            execute_as(
+                deps.as_mut(),
+                TEST_ACCOUNT_FACTORY,
+                ExecuteMsg::AddAccount {
+                    account_id: 2,
+                    account_base: AccountBase {
+                        manager: Addr::unchecked(SUB_MANAGER),
+                        proxy: Addr::unchecked("sub_proxy"),
+                    },
+                    parent_account_id: Some(TEST_ACCOUNT_ID),
+                },
+            )?;
+            assert_that!(ACCOUNT_PARENTS.load(&deps.storage, 2)?).is_equal_to(TEST_ACCOUNT_ID);
+
+            let new_namespace1 = Namespace::new("namespace1")?;
+            let msg = ExecuteMsg::ClaimNamespace {
+                account_id: 2,
+                namespace: new_namespace1.to_string(),
+            };
+            // not an owner in the account tree
+            let res = execute_as(deps.as_mut(), TEST_OTHER, msg.clone());
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::AccountOwnerMismatch {
+                    sender: Addr::unchecked(TEST_OTHER),
+                    owner: Addr::unchecked(TEST_MANAGER),
+                });
+
+            // owner of the parent account
+            execute_as(deps.as_mut(), TEST_OWNER, msg)?;
+            let account_id = namespaces_info().load(&deps.storage, &new_namespace1)?;
+            assert_that!(account_id).is_equal_to(2);
+            Ok(())
+        }
+
+        #[test]
+        fn add_sub_account_with_unknown_parent() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            mock_init_with_account(deps.as_mut(), true)?;
+            let res = execute_as(
+                deps.as_mut(),
+                TEST_ACCOUNT_FACTORY,
+                ExecuteMsg::AddAccount {
+                    account_id: 2,
+                    account_base: test_account_base(),
+                    parent_account_id: Some(5),
+                },
+            );
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::UnknownAccountId { id: 5 });
+            Ok(())
+        }
+
         #[test]
         fn claim_existing_namespaces() -> VersionControlTestResult {
             let mut deps = mock_dependencies();
@@ -868,6 +1514,7 @@ mod test {
                         manager: Addr::unchecked(TEST_MANAGER),
                         proxy: Addr::unchecked(TEST_PROXY),
                     },
+                    parent_account_id: None,
                 },
             )?;
             let new_namespace1 = Namespace::new("namespace1")?;
@@ -891,6 +1538,212 @@ mod test {
             Ok(())
         }
 
+        fn execute_after(deps: DepsMut, seconds: u64, sender: &str, msg: ExecuteMsg) -> VCResult {
+            let mut env = mock_env();
+            env.block.time = env.block.time.plus_seconds(seconds);
+            contract::execute(deps, env, mock_info(sender, &[]), msg)
+        }
+
+        fn update_namespace_config(
+            deps: DepsMut,
+            namespace_limit: Option<u32>,
+            namespace_registration_period: Option<u64>,
+        ) -> VCResult {
+            execute_as_admin(
+                deps,
+                ExecuteMsg::UpdateConfig {
+                    allow_direct_module_registration_and_updates: None,
+                    namespace_registration_fee: None,
+                    namespace_limit,
+                    namespace_registration_period,
+                },
+            )
+        }
+
+        #[test]
+        fn claim_namespaces_up_to_limit() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            let claim_msg = |namespace: &str| ExecuteMsg::ClaimNamespace {
+                account_id: TEST_ACCOUNT_ID,
+                namespace: namespace.to_string(),
+            };
+
+            execute_as(deps.as_mut(), TEST_OWNER, claim_msg("namespace1"))?;
+            let res = execute_as(deps.as_mut(), TEST_OWNER, claim_msg("namespace2"));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::ExceedsNamespaceLimit {
+                    limit: 1,
+                    current: 1,
+                });
+
+            update_namespace_config(deps.as_mut(), Some(2), None)?;
+            execute_as(deps.as_mut(), TEST_OWNER, claim_msg("namespace2"))?;
+            let namespaces =
+                queries::handle_namespaces_query(deps.as_ref(), vec![TEST_ACCOUNT_ID])?;
+            assert_that!(namespaces.namespaces).has_length(2);
+
+            let res = update_namespace_config(deps.as_mut(), Some(1), None);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::DecreaseNamespaceLimit {
+                    limit: 1,
+                    current: 2,
+                });
+            Ok(())
+        }
+
+        #[test]
+        fn claim_expired_namespace() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            create_second_account(deps.as_mut());
+            update_namespace_config(deps.as_mut(), None, Some(100))?;
+
+            let namespace = Namespace::new("namespace1")?;
+            let claim_msg = |account_id: AccountId| ExecuteMsg::ClaimNamespace {
+                account_id,
+                namespace: namespace.to_string(),
+            };
+            execute_as(deps.as_mut(), TEST_OWNER, claim_msg(TEST_ACCOUNT_ID))?;
+            let module =
+                ModuleInfo::from_id("namespace1:module", ModuleVersion::Version("0.1.0".into()))?;
+            REGISTERED_MODULES.save(deps.as_mut().storage, &module, &ModuleReference::App(1))?;
+
+            let res = execute_after(deps.as_mut(), 99, TEST_OWNER, claim_msg(2));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NamespaceOccupied {
+                    namespace: namespace.to_string(),
+                    id: TEST_ACCOUNT_ID,
+                });
+            // the owner has to renew its claim
+            let res = execute_after(deps.as_mut(), 100, TEST_OWNER, claim_msg(TEST_ACCOUNT_ID));
+            assert_that!(&res).is_err();
+
+            execute_after(deps.as_mut(), 100, TEST_OWNER, claim_msg(2))?;
+            let response = queries::handle_namespace_query(deps.as_ref(), namespace)?;
+            assert_that!(response.account_id).is_equal_to(2);
+            assert_that!(response.expires_at)
+                .is_equal_to(Some(mock_env().block.time.plus_seconds(200)));
+            // modules of the previous owner are yanked
+            assert_that!(REGISTERED_MODULES.has(&deps.storage, &module)).is_false();
+            assert_that!(YANKED_MODULES.has(&deps.storage, &module)).is_true();
+            Ok(())
+        }
+
+        #[test]
+        fn renew_namespace() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            let namespace = Namespace::new("namespace1")?;
+            execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::ClaimNamespace {
+                    account_id: TEST_ACCOUNT_ID,
+                    namespace: namespace.to_string(),
+                },
+            )?;
+            let renew_msg = ExecuteMsg::RenewNamespace {
+                namespace: namespace.to_string(),
+            };
+
+            let res = execute_as(deps.as_mut(), TEST_OWNER, renew_msg.clone());
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NamespaceDoesNotExpire {});
+
+            update_namespace_config(deps.as_mut(), None, Some(100))?;
+            let res = execute_as(deps.as_mut(), TEST_OTHER, renew_msg.clone());
+            assert_that!(&res).is_err();
+
+            // claims made without expiry expire a period after the renewal
+            execute_after(deps.as_mut(), 50, TEST_OWNER, renew_msg.clone())?;
+            execute_after(deps.as_mut(), 60, TEST_OWNER, renew_msg)?;
+            assert_that!(NAMESPACE_EXPIRATIONS.load(&deps.storage, &namespace)?)
+                .is_equal_to(mock_env().block.time.plus_seconds(250));
+            Ok(())
+        }
+
+        #[test]
+        fn expired_namespaces_do_not_count_towards_limit() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            update_namespace_config(deps.as_mut(), None, Some(100))?;
+            let claim_msg = |namespace: &str| ExecuteMsg::ClaimNamespace {
+                account_id: TEST_ACCOUNT_ID,
+                namespace: namespace.to_string(),
+            };
+            let exceeds_limit = VCError::ExceedsNamespaceLimit {
+                limit: 1,
+                current: 1,
+            };
+
+            execute_as(deps.as_mut(), TEST_OWNER, claim_msg("namespace1"))?;
+            let res = execute_after(deps.as_mut(), 99, TEST_OWNER, claim_msg("namespace2"));
+            assert_that!(&res).is_err().is_equal_to(&exceeds_limit);
+
+            execute_after(deps.as_mut(), 100, TEST_OWNER, claim_msg("namespace2"))?;
+            // renewing the expired claim would exceed the limit again
+            let res = execute_after(
+                deps.as_mut(),
+                100,
+                TEST_OWNER,
+                ExecuteMsg::RenewNamespace {
+                    namespace: "namespace1".to_string(),
+                },
+            );
+            assert_that!(&res).is_err().is_equal_to(&exceeds_limit);
+            Ok(())
+        }
+
+        #[test]
+        fn expired_claim_does_not_grant_ownership() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            update_namespace_config(deps.as_mut(), None, Some(100))?;
+            let namespace = Namespace::new("namespace1")?;
+            execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::ClaimNamespace {
+                    account_id: TEST_ACCOUNT_ID,
+                    namespace: namespace.to_string(),
+                },
+            )?;
+            let new_module =
+                ModuleInfo::from_id("namespace1:module", ModuleVersion::Version("0.1.0".into()))?;
+            let propose_msg = ExecuteMsg::ProposeModules {
+                modules: vec![(new_module, ModuleReference::App(0))],
+            };
+
+            let res = execute_after(deps.as_mut(), 100, TEST_OWNER, propose_msg.clone());
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NamespaceExpired {
+                    namespace: namespace.clone(),
+                });
+
+            // the previous owner can still renew the claim
+            execute_after(
+                deps.as_mut(),
+                100,
+                TEST_OWNER,
+                ExecuteMsg::RenewNamespace {
+                    namespace: namespace.to_string(),
+                },
+            )?;
+            execute_after(deps.as_mut(), 100, TEST_OWNER, propose_msg)?;
+            Ok(())
+        }
+
         #[test]
         fn cannot_claim_abstract() -> VCResult<()> {
             let mut deps = mock_dependencies();
@@ -921,31 +1774,274 @@ mod test {
                 .build();
             mock_init_with_account(deps.as_mut(), true)?;
 
-            // Add account 1
+            // Add account 1
+            execute_as(
+                deps.as_mut(),
+                TEST_ACCOUNT_FACTORY,
+                ExecuteMsg::AddAccount {
+                    account_id: 1,
+                    account_base: AccountBase {
+                        manager: Addr::unchecked(account_1_manager),
+                        proxy: Addr::unchecked("proxy2"),
+                    },
+                    parent_account_id: None,
+                },
+            )?;
+
+            // Attempt to claim the abstract namespace with account 1
+            let claim_abstract_msg = ExecuteMsg::ClaimNamespace {
+                account_id: 1,
+                namespace: ABSTRACT_NAMESPACE.to_string(),
+            };
+            let res = execute_as(deps.as_mut(), TEST_OWNER, claim_abstract_msg);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(VCError::NamespaceOccupied {
+                    namespace: Namespace::try_from("abstract")?.to_string(),
+                    id: ABSTRACT_ACCOUNT_ID,
+                });
+            Ok(())
+        }
+    }
+
+    mod transfer_namespace {
+        use super::*;
+        use crate::queries;
+        use cosmwasm_std::{coins, BankMsg, CosmosMsg, SubMsg};
+
+        const OTHER_MANAGER: &str = "other_manager";
+        const OTHER_PROXY: &str = "other_proxy";
+        const OTHER_ACCOUNT_ID: AccountId = 3;
+
+        /// Claims "namespace1" for the test account and adds an account owned by [`TEST_OTHER`]
+        fn mock_init_with_other_account(mut deps: DepsMut) -> VCResult {
+            mock_init_with_account(deps.branch(), true)?;
+            execute_as(
+                deps.branch(),
+                TEST_ACCOUNT_FACTORY,
+                ExecuteMsg::AddAccount {
+                    account_id: OTHER_ACCOUNT_ID,
+                    account_base: AccountBase {
+                        manager: Addr::unchecked(OTHER_MANAGER),
+                        proxy: Addr::unchecked(OTHER_PROXY),
+                    },
+                    parent_account_id: None,
+                },
+            )?;
+            execute_as(
+                deps,
+                TEST_OWNER,
+                ExecuteMsg::ClaimNamespace {
+                    account_id: TEST_ACCOUNT_ID,
+                    namespace: "namespace1".to_string(),
+                },
+            )
+        }
+
+        fn other_account_querier() -> MockQuerierBuilder {
+            mock_manager_querier().with_owner(OTHER_MANAGER, Some(TEST_OTHER))
+        }
+
+        #[test]
+        fn transfer_keeps_modules() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = other_account_querier().build();
+            mock_init_with_other_account(deps.as_mut())?;
+            let namespace = Namespace::new("namespace1")?;
+            let module =
+                ModuleInfo::from_id("namespace1:module", ModuleVersion::Version("0.1.0".into()))?;
+            REGISTERED_MODULES.save(deps.as_mut().storage, &module, &ModuleReference::App(1))?;
+            let split = FeeSplit::new(vec![UsageFee::new(
+                &deps.api,
+                Decimal::percent(100),
+                "revenue_recipient",
+            )?])?;
+            execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::SetModuleMonetization {
+                    module_name: "module".to_string(),
+                    namespace: namespace.clone(),
+                    monetization: Monetization::InstallFee(FixedFee::new(&Coin::new(10, "ujunox"))),
+                    revenue_split: Some(split),
+                },
+            )?;
+
+            let transfer_msg = |to_account: AccountId| ExecuteMsg::TransferNamespace {
+                namespace: namespace.to_string(),
+                to_account,
+            };
+
+            let res = execute_as(deps.as_mut(), TEST_OTHER, transfer_msg(OTHER_ACCOUNT_ID));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::AccountOwnerMismatch {
+                    sender: Addr::unchecked(TEST_OTHER),
+                    owner: Addr::unchecked(TEST_OWNER),
+                });
+            let res = execute_as(deps.as_mut(), TEST_OWNER, transfer_msg(4));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::UnknownAccountId { id: 4 });
+
+            execute_as(deps.as_mut(), TEST_OWNER, transfer_msg(OTHER_ACCOUNT_ID))?;
+            let response = queries::handle_namespace_query(deps.as_ref(), namespace.clone())?;
+            assert_that!(response.account_id).is_equal_to(OTHER_ACCOUNT_ID);
+            assert_that!(REGISTERED_MODULES.has(&deps.storage, &module)).is_true();
+            // the monetization is kept, the revenue split of the previous owner is cleared
+            assert_that!(MODULE_MONETIZATION.has(&deps.storage, (&namespace, "module"))).is_true();
+            assert_that!(MODULE_REVENUE_SPLIT.has(&deps.storage, (&namespace, "module")))
+                .is_false();
+
+            // the receiving account has reached its namespace limit
+            execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::ClaimNamespace {
+                    account_id: TEST_ACCOUNT_ID,
+                    namespace: "namespace2".to_string(),
+                },
+            )?;
+            let res = execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::TransferNamespace {
+                    namespace: "namespace2".to_string(),
+                    to_account: OTHER_ACCOUNT_ID,
+                },
+            );
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::ExceedsNamespaceLimit {
+                    limit: 1,
+                    current: 1,
+                });
+            Ok(())
+        }
+
+        #[test]
+        fn sell_namespace() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = other_account_querier().build();
+            mock_init_with_other_account(deps.as_mut())?;
+            let namespace = Namespace::new("namespace1")?;
+            let price = Coin::new(10, "ujunox");
+
+            let res = execute_as(
+                deps.as_mut(),
+                TEST_OTHER,
+                ExecuteMsg::OfferNamespace {
+                    namespace: namespace.to_string(),
+                    price: price.clone(),
+                    to_account: None,
+                },
+            );
+            assert_that!(&res).is_err();
+            execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::OfferNamespace {
+                    namespace: namespace.to_string(),
+                    price: price.clone(),
+                    to_account: None,
+                },
+            )?;
+            let response = queries::handle_namespace_offer_query(deps.as_ref(), namespace.clone())?;
+            assert_that!(response.offer).is_equal_to(Some(NamespaceOffer {
+                seller: TEST_ACCOUNT_ID,
+                buyer: None,
+                price: price.clone(),
+            }));
+
+            let accept_msg = ExecuteMsg::AcceptNamespaceOffer {
+                namespace: namespace.to_string(),
+                account_id: OTHER_ACCOUNT_ID,
+            };
+            let res = execute_as_with_funds(
+                deps.as_mut(),
+                TEST_OTHER,
+                accept_msg.clone(),
+                &coins(5, "ujunox"),
+            );
+            assert_that!(&res)
+                .is_err()
+                .matches(|e| matches!(e, VCError::Abstract(AbstractError::Fee(_))));
+
+            let res =
+                execute_as_with_funds(deps.as_mut(), TEST_OTHER, accept_msg, &[price.clone()])?;
+            assert_that!(&res.messages).is_equal_to(vec![SubMsg::new(CosmosMsg::Bank(
+                BankMsg::Send {
+                    to_address: TEST_PROXY.to_string(),
+                    amount: vec![price],
+                },
+            ))]);
+            let response = queries::handle_namespace_query(deps.as_ref(), namespace.clone())?;
+            assert_that!(response.account_id).is_equal_to(OTHER_ACCOUNT_ID);
+            let response = queries::handle_namespace_offer_query(deps.as_ref(), namespace)?;
+            assert_that!(response.offer).is_none();
+            Ok(())
+        }
+
+        #[test]
+        fn reserved_and_cancelled_offers() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = other_account_querier().build();
+            mock_init_with_other_account(deps.as_mut())?;
+            create_second_account(deps.as_mut());
+            let namespace = Namespace::new("namespace1")?;
+
             execute_as(
                 deps.as_mut(),
-                TEST_ACCOUNT_FACTORY,
-                ExecuteMsg::AddAccount {
-                    account_id: 1,
-                    account_base: AccountBase {
-                        manager: Addr::unchecked(account_1_manager),
-                        proxy: Addr::unchecked("proxy2"),
-                    },
+                TEST_OWNER,
+                ExecuteMsg::OfferNamespace {
+                    namespace: namespace.to_string(),
+                    price: Coin::new(0, "ujunox"),
+                    to_account: Some(2),
                 },
             )?;
+            let accept_msg = ExecuteMsg::AcceptNamespaceOffer {
+                namespace: namespace.to_string(),
+                account_id: OTHER_ACCOUNT_ID,
+            };
+            let res = execute_as(deps.as_mut(), TEST_OTHER, accept_msg.clone());
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NamespaceOfferedToOther {
+                    namespace: namespace.clone(),
+                    buyer: 2,
+                });
 
-            // Attempt to claim the abstract namespace with account 1
-            let claim_abstract_msg = ExecuteMsg::ClaimNamespace {
-                account_id: 1,
-                namespace: ABSTRACT_NAMESPACE.to_string(),
+            // funds can't be sent along with a free offer
+            let res = execute_as_with_funds(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::AcceptNamespaceOffer {
+                    namespace: namespace.to_string(),
+                    account_id: 2,
+                },
+                &coins(5, "ujunox"),
+            );
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::InvalidFeePayment {
+                    expected: Coin::new(0, "ujunox"),
+                    sent: coins(5, "ujunox"),
+                });
+
+            let cancel_msg = ExecuteMsg::CancelNamespaceOffer {
+                namespace: namespace.to_string(),
             };
-            let res = execute_as(deps.as_mut(), TEST_OWNER, claim_abstract_msg);
+            execute_as(deps.as_mut(), TEST_OWNER, cancel_msg.clone())?;
+            let res = execute_as(deps.as_mut(), TEST_OWNER, cancel_msg);
             assert_that!(&res)
                 .is_err()
-                .is_equal_to(VCError::NamespaceOccupied {
-                    namespace: Namespace::try_from("abstract")?.to_string(),
-                    id: ABSTRACT_ACCOUNT_ID,
+                .is_equal_to(&VCError::NoNamespaceOffer {
+                    namespace: namespace.clone(),
                 });
+            let res = execute_as(deps.as_mut(), TEST_OTHER, accept_msg);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NoNamespaceOffer { namespace });
             Ok(())
         }
     }
@@ -961,6 +2057,8 @@ mod test {
             let msg = ExecuteMsg::UpdateConfig {
                 allow_direct_module_registration_and_updates: Some(false),
                 namespace_registration_fee: None,
+                namespace_limit: None,
+                namespace_registration_period: None,
             };
 
             let res = execute_as(deps.as_mut(), TEST_OTHER, msg);
@@ -979,6 +2077,8 @@ mod test {
             let msg = ExecuteMsg::UpdateConfig {
                 allow_direct_module_registration_and_updates: Some(false),
                 namespace_registration_fee: None,
+                namespace_limit: None,
+                namespace_registration_period: None,
             };
 
             let res = execute_as_admin(deps.as_mut(), msg);
@@ -1018,6 +2118,8 @@ mod test {
                     denom: "ujunox".to_string(),
                     amount: Uint128::one(),
                 }),
+                namespace_limit: None,
+                namespace_registration_period: None,
             };
 
             let res = execute_as(deps.as_mut(), TEST_OTHER, msg);
@@ -1041,6 +2143,8 @@ mod test {
             let msg = ExecuteMsg::UpdateConfig {
                 allow_direct_module_registration_and_updates: None,
                 namespace_registration_fee: Some(new_fee.clone()),
+                namespace_limit: None,
+                namespace_registration_period: None,
             };
 
             let res = execute_as_admin(deps.as_mut(), msg);
@@ -1787,11 +2891,12 @@ mod test {
             let _module = REGISTERED_MODULES.load(&deps.storage, &new_module)?;
 
             let monetization = Monetization::InstallFee(FixedFee::new(&coin(45, "ujuno")));
-            let metadata = "".to_string();
+            let metadata = ModuleMetadata::default();
             let monetization_module_msg = ExecuteMsg::SetModuleMonetization {
                 module_name: new_module.name.clone(),
                 namespace: new_module.namespace.clone(),
                 monetization: monetization.clone(),
+                revenue_split: None,
             };
             execute_as(deps.as_mut(), TEST_ADMIN, monetization_module_msg)?;
 
@@ -1816,6 +2921,92 @@ mod test {
             Ok(())
         }
 
+        #[test]
+        fn add_module_revenue_split() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            let mut new_module = test_module();
+            new_module.namespace = Namespace::new(ABSTRACT_NAMESPACE)?;
+            let msg = ExecuteMsg::ProposeModules {
+                modules: vec![(new_module.clone(), ModuleReference::App(0))],
+            };
+            execute_as(deps.as_mut(), TEST_ADMIN, msg)?;
+
+            let set_split_msg = |revenue_split: FeeSplit| ExecuteMsg::SetModuleMonetization {
+                module_name: new_module.name.clone(),
+                namespace: new_module.namespace.clone(),
+                monetization: Monetization::InstallFee(FixedFee::new(&coin(45, "ujuno"))),
+                revenue_split: Some(revenue_split),
+            };
+
+            // shares must sum to 100%
+            let unchecked_split: FeeSplit =
+                from_slice(br#"{"recipients":[{"fee":{"share":"0.8"},"recipient":"publisher"}]}"#)?;
+            let res = execute_as(deps.as_mut(), TEST_ADMIN, set_split_msg(unchecked_split));
+            assert_that!(&res)
+                .is_err()
+                .matches(|e| matches!(e, VCError::Abstract(AbstractError::Fee(_))));
+
+            let split = FeeSplit::new(vec![
+                UsageFee::new(&deps.api, Decimal::percent(80), "publisher")?,
+                UsageFee::new(&deps.api, Decimal::percent(20), "co_author")?,
+            ])?;
+            execute_as(deps.as_mut(), TEST_ADMIN, set_split_msg(split.clone()))?;
+
+            let query_msg = QueryMsg::Modules {
+                infos: vec![new_module.clone()],
+            };
+            let res = query(deps.as_ref(), mock_env(), query_msg)?;
+            let ser_res = from_binary::<ModulesResponse>(&res)?;
+            assert_that!(ser_res.modules[0].config.revenue_split).is_equal_to(Some(split));
+
+            Ok(())
+        }
+
+        #[test]
+        fn subscription_needs_period() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            let mut new_module = test_module();
+            new_module.namespace = Namespace::new(ABSTRACT_NAMESPACE)?;
+            let msg = ExecuteMsg::ProposeModules {
+                modules: vec![(new_module.clone(), ModuleReference::App(0))],
+            };
+            execute_as(deps.as_mut(), TEST_ADMIN, msg)?;
+
+            let monetization_module_msg = ExecuteMsg::SetModuleMonetization {
+                module_name: new_module.name.clone(),
+                namespace: new_module.namespace.clone(),
+                monetization: Monetization::Subscription {
+                    per_period: coin(10, "ujuno"),
+                    period_seconds: 0,
+                },
+                revenue_split: None,
+            };
+            let res = execute_as(deps.as_mut(), TEST_ADMIN, monetization_module_msg);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::InvalidSubscriptionPeriod {});
+
+            let monetization_module_msg = ExecuteMsg::SetModuleMonetization {
+                module_name: new_module.name.clone(),
+                namespace: new_module.namespace.clone(),
+                monetization: Monetization::Subscription {
+                    per_period: coin(0, "ujuno"),
+                    period_seconds: 3600,
+                },
+                revenue_split: None,
+            };
+            let res = execute_as(deps.as_mut(), TEST_ADMIN, monetization_module_msg);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::InvalidSubscriptionPrice {});
+
+            Ok(())
+        }
+
         #[test]
         fn add_module_metadata() -> VersionControlTestResult {
             let mut deps = mock_dependencies();
@@ -1831,7 +3022,12 @@ mod test {
             let _module = REGISTERED_MODULES.load(&deps.storage, &new_module)?;
 
             let monetization = Monetization::None;
-            let metadata = "ipfs://YRUI243876FJHKHV3IY".to_string();
+            let metadata = ModuleMetadata {
+                description: Some("Test module".to_string()),
+                repository: Some("ipfs://YRUI243876FJHKHV3IY".to_string()),
+                tags: vec!["test".to_string()],
+                ..Default::default()
+            };
             let metadata_module_msg = ExecuteMsg::SetModuleMetadata {
                 module: new_module.clone(),
                 metadata: metadata.clone(),
@@ -1858,6 +3054,33 @@ mod test {
 
             Ok(())
         }
+
+        #[test]
+        fn rejects_invalid_metadata() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            let mut new_module = test_module();
+            new_module.namespace = Namespace::new(ABSTRACT_NAMESPACE)?;
+            let msg = ExecuteMsg::ProposeModules {
+                modules: vec![(new_module.clone(), ModuleReference::App(0))],
+            };
+            execute_as(deps.as_mut(), TEST_ADMIN, msg)?;
+
+            let metadata_module_msg = ExecuteMsg::SetModuleMetadata {
+                module: new_module,
+                metadata: ModuleMetadata {
+                    icon: Some("icon.png".to_string()),
+                    ..Default::default()
+                },
+            };
+            let res = execute_as(deps.as_mut(), TEST_ADMIN, metadata_module_msg);
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::Validation(ValidationError::LinkInvalidShort(11)));
+
+            Ok(())
+        }
     }
 
     fn claim_test_namespace_as_owner(deps: DepsMut) -> VersionControlTestResult {
@@ -1977,6 +3200,265 @@ mod test {
         }
     }
 
+    mod review_modules {
+        use super::*;
+        use crate::queries;
+
+        const TEST_REVIEWERS: [&str; 3] = ["reviewer1", "reviewer2", "reviewer3"];
+
+        /// Proposes a module that has to be reviewed by 2 of 3 reviewers
+        fn mock_init_with_pending_module(mut deps: DepsMut) -> VCResult<ModuleInfo> {
+            mock_init_with_account(deps.branch(), false)?;
+            claim_test_namespace_as_owner(deps.branch())?;
+            let module = ModuleInfo::from_id(TEST_MODULE_ID, TEST_VERSION.into())?;
+            execute_as(
+                deps.branch(),
+                TEST_OWNER,
+                ExecuteMsg::ProposeModules {
+                    modules: vec![(module.clone(), ModuleReference::App(0))],
+                },
+            )?;
+            execute_as_admin(
+                deps,
+                ExecuteMsg::UpdateReviewers {
+                    reviewers: TEST_REVIEWERS.map(String::from).to_vec(),
+                    threshold: 2,
+                },
+            )?;
+            Ok(module)
+        }
+
+        fn review_msg(module: &ModuleInfo, approve: bool) -> ExecuteMsg {
+            ExecuteMsg::ReviewModule {
+                module: module.clone(),
+                approve,
+                comment: Some("reviewed the source".to_string()),
+            }
+        }
+
+        #[test]
+        fn approve_with_threshold() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            let module = mock_init_with_pending_module(deps.as_mut())?;
+
+            let res = execute_as(deps.as_mut(), TEST_OTHER, review_msg(&module, true));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NotReviewer(Addr::unchecked(TEST_OTHER)));
+
+            execute_as(deps.as_mut(), TEST_REVIEWERS[0], review_msg(&module, false))?;
+            // reviewers can change their review
+            execute_as(deps.as_mut(), TEST_REVIEWERS[0], review_msg(&module, true))?;
+            let pending = queries::handle_pending_modules_query(deps.as_ref(), None, None)?;
+            assert_that!(pending.threshold).is_equal_to(Some(2));
+            assert_that!(pending.modules[0].reviews).has_length(1);
+            assert_that!(pending.modules[0].approvals).is_equal_to(1);
+            assert_that!(pending.modules[0].rejections).is_equal_to(0);
+            assert_that!(REGISTERED_MODULES.has(&deps.storage, &module)).is_false();
+
+            execute_as(deps.as_mut(), TEST_REVIEWERS[1], review_msg(&module, true))?;
+            assert_that!(REGISTERED_MODULES.has(&deps.storage, &module)).is_true();
+            assert_that!(PENDING_MODULES.has(&deps.storage, &module)).is_false();
+            assert_that!(PENDING_REVIEWS.has(&deps.storage, &module)).is_false();
+            Ok(())
+        }
+
+        #[test]
+        fn reject_when_threshold_unreachable() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            let module = mock_init_with_pending_module(deps.as_mut())?;
+
+            execute_as(deps.as_mut(), TEST_REVIEWERS[0], review_msg(&module, false))?;
+            assert_that!(PENDING_MODULES.has(&deps.storage, &module)).is_true();
+
+            execute_as(deps.as_mut(), TEST_REVIEWERS[1], review_msg(&module, false))?;
+            assert_that!(PENDING_MODULES.has(&deps.storage, &module)).is_false();
+            assert_that!(REGISTERED_MODULES.has(&deps.storage, &module)).is_false();
+
+            let res = execute_as(deps.as_mut(), TEST_REVIEWERS[2], review_msg(&module, true));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::ModuleNotFound(module));
+            Ok(())
+        }
+
+        #[test]
+        fn admin_can_not_bypass_reviewers() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            let module = mock_init_with_pending_module(deps.as_mut())?;
+
+            for (approves, rejects) in [
+                (vec![module.clone()], vec![]),
+                (vec![], vec![module.clone()]),
+            ] {
+                let res = execute_as_admin(
+                    deps.as_mut(),
+                    ExecuteMsg::ApproveOrRejectModules { approves, rejects },
+                );
+                assert_that!(&res)
+                    .is_err()
+                    .is_equal_to(&VCError::ReviewersSet {});
+            }
+            assert_that!(PENDING_MODULES.has(&deps.storage, &module)).is_true();
+
+            // the admin approves modules again once the reviewers are removed
+            execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::UpdateReviewers {
+                    reviewers: vec![],
+                    threshold: 0,
+                },
+            )?;
+            execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::ApproveOrRejectModules {
+                    approves: vec![module.clone()],
+                    rejects: vec![],
+                },
+            )?;
+            assert_that!(REGISTERED_MODULES.has(&deps.storage, &module)).is_true();
+            Ok(())
+        }
+
+        #[test]
+        fn update_reviewers() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            let module = mock_init_with_pending_module(deps.as_mut())?;
+            let update_msg = |threshold: u32| ExecuteMsg::UpdateReviewers {
+                reviewers: TEST_REVIEWERS.map(String::from).to_vec(),
+                threshold,
+            };
+
+            let res = execute_as(deps.as_mut(), TEST_OTHER, update_msg(1));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::Ownership(OwnershipError::NotOwner {}));
+            for threshold in [0, 4] {
+                let res = execute_as_admin(deps.as_mut(), update_msg(threshold));
+                assert_that!(&res)
+                    .is_err()
+                    .is_equal_to(&VCError::InvalidReviewThreshold {
+                        threshold,
+                        reviewers: 3,
+                    });
+            }
+
+            execute_as_admin(
+                deps.as_mut(),
+                ExecuteMsg::UpdateReviewers {
+                    reviewers: vec![],
+                    threshold: 0,
+                },
+            )?;
+            let res = execute_as(deps.as_mut(), TEST_REVIEWERS[0], review_msg(&module, true));
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NoReviewers {});
+            Ok(())
+        }
+    }
+
+    mod deprecate_module {
+        use super::*;
+        use crate::queries;
+
+        fn propose_versions(deps: DepsMut, versions: &[&str]) -> VCResult<Vec<ModuleInfo>> {
+            let modules = versions
+                .iter()
+                .map(|version| {
+                    ModuleInfo::from_id(TEST_MODULE_ID, ModuleVersion::Version(version.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let msg = ExecuteMsg::ProposeModules {
+                modules: modules
+                    .iter()
+                    .map(|module| (module.clone(), ModuleReference::App(0)))
+                    .collect(),
+            };
+            execute_as(deps, TEST_OWNER, msg)?;
+            Ok(modules)
+        }
+
+        #[test]
+        fn deprecate_with_successor() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            claim_test_namespace_as_owner(deps.as_mut())?;
+            let modules = propose_versions(deps.as_mut(), &["0.1.0", "0.2.0"])?;
+            let (old_module, new_module) = (modules[0].clone(), modules[1].clone());
+
+            let msg = ExecuteMsg::DeprecateModule {
+                module: old_module.clone(),
+                successor: Some(new_module.clone()),
+                reason: "uses the old pool interface".to_string(),
+            };
+            let res = execute_as(deps.as_mut(), TEST_OTHER, msg.clone());
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::AccountOwnerMismatch {
+                    sender: Addr::unchecked(TEST_OTHER),
+                    owner: Addr::unchecked(TEST_OWNER),
+                });
+            execute_as(deps.as_mut(), TEST_OWNER, msg)?;
+
+            let response = queries::handle_modules_query(deps.as_ref(), vec![old_module])?;
+            assert_that!(response.modules[0].config.deprecation).is_equal_to(Some(
+                ModuleDeprecation {
+                    successor: Some(new_module.clone()),
+                    reason: "uses the old pool interface".to_string(),
+                },
+            ));
+            // deprecated modules remain installable
+            assert_that!(response.modules[0].module.reference).is_equal_to(ModuleReference::App(0));
+            let response = queries::handle_modules_query(deps.as_ref(), vec![new_module])?;
+            assert_that!(response.modules[0].config.deprecation).is_none();
+            Ok(())
+        }
+
+        #[test]
+        fn requires_registered_modules() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut(), true)?;
+            claim_test_namespace_as_owner(deps.as_mut())?;
+            let modules = propose_versions(deps.as_mut(), &["0.1.0"])?;
+            let unknown_module =
+                ModuleInfo::from_id(TEST_MODULE_ID, ModuleVersion::Version("0.2.0".into()))?;
+
+            let res = execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::DeprecateModule {
+                    module: unknown_module.clone(),
+                    successor: None,
+                    reason: "unused".to_string(),
+                },
+            );
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::ModuleNotFound(unknown_module.clone()));
+
+            let res = execute_as(
+                deps.as_mut(),
+                TEST_OWNER,
+                ExecuteMsg::DeprecateModule {
+                    module: modules[0].clone(),
+                    successor: Some(unknown_module.clone()),
+                    reason: "unused".to_string(),
+                },
+            );
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::ModuleNotFound(unknown_module));
+            Ok(())
+        }
+    }
+
     mod register_os {
         use super::*;
 
@@ -1992,6 +3474,7 @@ mod test {
             let msg = ExecuteMsg::AddAccount {
                 account_id: 0,
                 account_base: test_core.clone(),
+                parent_account_id: None,
             };
 
             // as other
@@ -2013,6 +3496,45 @@ mod test {
             assert_that!(&account).is_equal_to(&test_core);
             Ok(())
         }
+
+        #[test]
+        fn remove_account_parent() -> VersionControlTestResult {
+            const SUB_MANAGER: &str = "sub_manager";
+            let mut deps = mock_dependencies();
+            mock_init_with_factory(deps.as_mut())?;
+
+            for (account_id, manager, parent_account_id) in
+                [(0, TEST_MANAGER, None), (1, SUB_MANAGER, Some(0))]
+            {
+                execute_as(
+                    deps.as_mut(),
+                    TEST_ACCOUNT_FACTORY,
+                    ExecuteMsg::AddAccount {
+                        account_id,
+                        account_base: AccountBase {
+                            manager: Addr::unchecked(manager),
+                            proxy: Addr::unchecked(TEST_PROXY),
+                        },
+                        parent_account_id,
+                    },
+                )?;
+            }
+
+            let msg = ExecuteMsg::RemoveAccountParent { account_id: 1 };
+
+            // only the manager of the sub-account
+            let res = execute_as(deps.as_mut(), TEST_MANAGER, msg.clone());
+            assert_that!(&res)
+                .is_err()
+                .is_equal_to(&VCError::NotAccountManager {
+                    sender: Addr::unchecked(TEST_MANAGER),
+                    account_id: 1,
+                });
+
+            execute_as(deps.as_mut(), SUB_MANAGER, msg)?;
+            assert_that!(ACCOUNT_PARENTS.has(&deps.storage, 1)).is_false();
+            Ok(())
+        }
     }
 
     mod configure {
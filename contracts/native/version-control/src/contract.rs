@@ -9,8 +9,8 @@ use abstract_sdk::core::{
     objects::{module_version::assert_cw_contract_upgrade, ABSTRACT_ACCOUNT_ID},
     version_control::namespaces_info,
     version_control::{
-        state::{CONFIG, FACTORY},
-        ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+        state::{CONFIG, FACTORY, REVIEWERS},
+        ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, ReviewersResponse,
     },
     VERSION_CONTROL,
 };
@@ -35,6 +35,7 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> VCResult {
 
     assert_cw_contract_upgrade(deps.storage, VERSION_CONTROL, to_version)?;
     cw2::set_contract_version(deps.storage, VERSION_CONTROL, CONTRACT_VERSION)?;
+    migrate_module_metadata(deps.storage)?;
     Ok(VcResponse::action("migrate"))
 }
 
@@ -45,6 +46,8 @@ pub fn instantiate(deps: DepsMut, _env: Env, info: MessageInfo, msg: Instantiate
     let InstantiateMsg {
         allow_direct_module_registration_and_updates,
         namespace_registration_fee,
+        namespace_limit,
+        namespace_registration_period,
     } = msg;
 
     CONFIG.save(
@@ -56,6 +59,8 @@ pub fn instantiate(deps: DepsMut, _env: Env, info: MessageInfo, msg: Instantiate
                 denom: "none".to_string(),
                 amount: Uint128::zero(),
             }),
+            namespace_limit: namespace_limit.unwrap_or(1),
+            namespace_registration_period: namespace_registration_period.unwrap_or_default(),
         },
     )?;
 
@@ -77,37 +82,85 @@ pub fn instantiate(deps: DepsMut, _env: Env, info: MessageInfo, msg: Instantiate
 #[cfg_attr(feature = "export", cosmwasm_std::entry_point)]
 pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> VCResult {
     match msg {
-        ExecuteMsg::ProposeModules { modules } => propose_modules(deps, info, modules),
+        ExecuteMsg::ProposeModules { modules } => propose_modules(deps, env, info, modules),
         ExecuteMsg::ApproveOrRejectModules { approves, rejects } => {
             approve_or_reject_modules(deps, info, approves, rejects)
         }
+        ExecuteMsg::ReviewModule {
+            module,
+            approve,
+            comment,
+        } => review_module(deps, info, module, approve, comment),
+        ExecuteMsg::UpdateReviewers {
+            reviewers,
+            threshold,
+        } => update_reviewers(deps, info, reviewers, threshold),
         ExecuteMsg::RemoveModule { module } => remove_module(deps, info, module),
-        ExecuteMsg::YankModule { module } => yank_module(deps, info, module),
+        ExecuteMsg::YankModule { module } => yank_module(deps, env, info, module),
+        ExecuteMsg::DeprecateModule {
+            module,
+            successor,
+            reason,
+        } => deprecate_module(deps, env, info, module, successor, reason),
         ExecuteMsg::SetModuleMonetization {
             module_name,
             namespace,
             monetization,
-        } => set_module_monetization(deps, info, module_name, namespace, monetization),
+            revenue_split,
+        } => set_module_monetization(
+            deps,
+            env,
+            info,
+            module_name,
+            namespace,
+            monetization,
+            revenue_split,
+        ),
         ExecuteMsg::SetModuleMetadata { module, metadata } => {
-            set_module_metadata(deps, info, module, metadata)
+            set_module_metadata(deps, env, info, module, metadata)
         }
         ExecuteMsg::ClaimNamespace {
             namespace,
             account_id,
-        } => claim_namespace(deps, info, account_id, namespace),
+        } => claim_namespace(deps, env, info, account_id, namespace),
+        ExecuteMsg::RenewNamespace { namespace } => renew_namespace(deps, env, info, namespace),
+        ExecuteMsg::TransferNamespace {
+            namespace,
+            to_account,
+        } => transfer_namespace(deps, env, info, namespace, to_account),
+        ExecuteMsg::OfferNamespace {
+            namespace,
+            price,
+            to_account,
+        } => offer_namespace(deps, env, info, namespace, price, to_account),
+        ExecuteMsg::CancelNamespaceOffer { namespace } => {
+            cancel_namespace_offer(deps, info, namespace)
+        }
+        ExecuteMsg::AcceptNamespaceOffer {
+            namespace,
+            account_id,
+        } => accept_namespace_offer(deps, env, info, namespace, account_id),
         ExecuteMsg::RemoveNamespaces { namespaces } => remove_namespaces(deps, info, namespaces),
         ExecuteMsg::AddAccount {
             account_id,
             account_base: base,
-        } => add_account(deps, info, account_id, base),
+            parent_account_id,
+        } => add_account(deps, info, account_id, base, parent_account_id),
+        ExecuteMsg::RemoveAccountParent { account_id } => {
+            remove_account_parent(deps, info, account_id)
+        }
         ExecuteMsg::UpdateConfig {
             allow_direct_module_registration_and_updates,
             namespace_registration_fee,
+            namespace_limit,
+            namespace_registration_period,
         } => update_config(
             deps,
             info,
             allow_direct_module_registration_and_updates,
             namespace_registration_fee,
+            namespace_limit,
+            namespace_registration_period,
         ),
         ExecuteMsg::SetFactory { new_factory } => set_factory(deps, info, new_factory),
         ExecuteMsg::UpdateOwnership(action) => {
@@ -129,6 +182,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> VCResult<Binary> {
         QueryMsg::Namespace { namespace } => {
             to_binary(&queries::handle_namespace_query(deps, namespace)?)
         }
+        QueryMsg::NamespaceOffer { namespace } => {
+            to_binary(&queries::handle_namespace_offer_query(deps, namespace)?)
+        }
         QueryMsg::Config {} => {
             let factory = FACTORY.get(deps)?.unwrap();
             to_binary(&ConfigResponse { factory })
@@ -151,6 +207,12 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> VCResult<Binary> {
                 limit,
             )?)
         }
+        QueryMsg::PendingModules { start_after, limit } => to_binary(
+            &queries::handle_pending_modules_query(deps, start_after, limit)?,
+        ),
+        QueryMsg::Reviewers {} => to_binary(&ReviewersResponse {
+            reviewers: REVIEWERS.may_load(deps.storage)?,
+        }),
         QueryMsg::Ownership {} => to_binary(&query_ownership!(deps)?),
     }
     .map_err(Into::into)
@@ -268,5 +330,81 @@ mod tests {
                 .is_equal_to(version.to_string());
             Ok(())
         }
+
+        #[test]
+        fn migrates_legacy_module_metadata() -> VCResult<()> {
+            use abstract_core::objects::module::{ModuleInfo, ModuleMetadata, ModuleVersion};
+            use abstract_core::version_control::state::MODULE_METADATA;
+            use cw_storage_plus::Map;
+
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            cw2::set_contract_version(deps.as_mut().storage, VERSION_CONTROL, "0.0.0")?;
+
+            let legacy: Map<&ModuleInfo, String> = Map::new("mod_meta");
+            let module = |name: &str| ModuleInfo {
+                namespace: Namespace::new("test").unwrap(),
+                name: name.to_string(),
+                version: ModuleVersion::Version("1.0.0".into()),
+            };
+            legacy.save(
+                deps.as_mut().storage,
+                &module("described"),
+                &"A module that does things".to_string(),
+            )?;
+            legacy.save(
+                deps.as_mut().storage,
+                &module("linked"),
+                &"https://github.com/abstract/linked".to_string(),
+            )?;
+            legacy.save(deps.as_mut().storage, &module("empty"), &String::new())?;
+
+            migrate(deps.as_mut(), mock_env(), MigrateMsg {})?;
+
+            assert_that!(MODULE_METADATA.load(&deps.storage, &module("described"))?).is_equal_to(
+                ModuleMetadata {
+                    description: Some("A module that does things".to_string()),
+                    ..Default::default()
+                },
+            );
+            assert_that!(MODULE_METADATA.load(&deps.storage, &module("linked"))?).is_equal_to(
+                ModuleMetadata {
+                    repository: Some("https://github.com/abstract/linked".to_string()),
+                    ..Default::default()
+                },
+            );
+            assert_that!(MODULE_METADATA.has(&deps.storage, &module("empty"))).is_false();
+            assert_that!(legacy
+                .keys(&deps.storage, None, None, cosmwasm_std::Order::Ascending)
+                .next())
+            .is_none();
+            Ok(())
+        }
+
+        #[test]
+        fn loads_config_without_namespace_settings() -> VCResult<()> {
+            use abstract_core::version_control::{state::CONFIG, Config};
+            use cosmwasm_std::Storage;
+
+            let mut deps = mock_dependencies();
+            mock_init(deps.as_mut())?;
+            cw2::set_contract_version(deps.as_mut().storage, VERSION_CONTROL, "0.0.0")?;
+
+            // config as stored before the namespace limit and registration period were added
+            deps.storage.set(
+                CONFIG.as_slice(),
+                br#"{"allow_direct_module_registration_and_updates":true,"namespace_registration_fee":{"denom":"ujunox","amount":"0"}}"#,
+            );
+
+            migrate(deps.as_mut(), mock_env(), MigrateMsg {})?;
+
+            assert_that!(CONFIG.load(&deps.storage)?).is_equal_to(Config {
+                allow_direct_module_registration_and_updates: true,
+                namespace_registration_fee: cosmwasm_std::Coin::new(0, "ujunox"),
+                namespace_limit: 1,
+                namespace_registration_period: 0,
+            });
+            Ok(())
+        }
     }
 }
@@ -44,6 +44,9 @@ pub enum VCError {
     #[error("Account ID {} is not in version control register", id)]
     UnknownAccountId { id: AccountId },
 
+    #[error("{} is not the manager of Account {}", sender, account_id)]
+    NotAccountManager { sender: Addr, account_id: AccountId },
+
     #[error("Namespace {} is not in version control register", namespace)]
     UnknownNamespace { namespace: Namespace },
 
@@ -77,6 +80,43 @@ pub enum VCError {
 
     #[error("Invalid fee payment sent. Expected {}, sent {:?}", expected, sent)]
     InvalidFeePayment { expected: Coin, sent: Vec<Coin> },
+
+    #[error("Namespace {} has expired and has to be renewed first", namespace)]
+    NamespaceExpired { namespace: Namespace },
+
+    #[error("Namespace {} is not offered for sale", namespace)]
+    NoNamespaceOffer { namespace: Namespace },
+
+    #[error("Namespace {} is offered to account {}", namespace, buyer)]
+    NamespaceOfferedToOther {
+        namespace: Namespace,
+        buyer: AccountId,
+    },
+
+    #[error("No module reviewers are set")]
+    NoReviewers {},
+
+    #[error("{0} is not a module reviewer")]
+    NotReviewer(Addr),
+
+    #[error("Pending modules are approved or rejected by the module reviewers")]
+    ReviewersSet {},
+
+    #[error(
+        "Review threshold must be between 1 and the number of reviewers: {}, reviewers: {}",
+        threshold,
+        reviewers
+    )]
+    InvalidReviewThreshold { threshold: u32, reviewers: usize },
+
+    #[error("Namespace claims do not expire")]
+    NamespaceDoesNotExpire {},
+
+    #[error("Subscription period must be greater than zero")]
+    InvalidSubscriptionPeriod {},
+
+    #[error("Subscription price must be greater than zero")]
+    InvalidSubscriptionPrice {},
 }
 
 impl From<cw_semver::Error> for VCError {
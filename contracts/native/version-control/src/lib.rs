@@ -22,6 +22,8 @@ mod testing {
             version_control::InstantiateMsg {
                 allow_direct_module_registration_and_updates: Some(true),
                 namespace_registration_fee: None,
+                namespace_limit: None,
+                namespace_registration_period: None,
             },
         )
     }
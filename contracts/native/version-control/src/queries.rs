@@ -2,7 +2,11 @@ use crate::contract::VCResult;
 use crate::error::VCError;
 use abstract_core::{
     objects::module::ModuleStatus,
-    version_control::{state::PENDING_MODULES, ModuleConfiguration, NamespaceResponse},
+    version_control::{
+        state::{NAMESPACE_OFFERS, PENDING_MODULES, PENDING_REVIEWS, REVIEWERS},
+        ModuleConfiguration, NamespaceOfferResponse, NamespaceResponse, PendingModuleResponse,
+        PendingModulesResponse,
+    },
 };
 use abstract_sdk::core::{
     objects::{
@@ -13,7 +17,7 @@ use abstract_sdk::core::{
     },
     version_control::{
         namespaces_info,
-        state::{ACCOUNT_ADDRESSES, REGISTERED_MODULES, YANKED_MODULES},
+        state::{ACCOUNT_ADDRESSES, NAMESPACE_EXPIRATIONS, REGISTERED_MODULES, YANKED_MODULES},
         AccountBaseResponse, ModuleFilter, ModuleResponse, ModulesListResponse, ModulesResponse,
         NamespaceListResponse,
     },
@@ -40,23 +44,40 @@ pub fn handle_account_address_query(
 pub fn handle_modules_query(deps: Deps, modules: Vec<ModuleInfo>) -> StdResult<ModulesResponse> {
     let mut modules_response = ModulesResponse { modules: vec![] };
     for mut module in modules {
-        let maybe_module_ref = if let ModuleVersion::Version(_) = module.version {
-            REGISTERED_MODULES.load(deps.storage, &module)
-        } else {
-            // get latest
-            let versions: StdResult<Vec<(String, ModuleReference)>> = REGISTERED_MODULES
-                .prefix((module.namespace.clone(), module.name.clone()))
-                .range(deps.storage, None, None, Order::Descending)
-                .take(1)
-                .collect();
-            let (latest_version, id) = versions?
-                .first()
-                .ok_or_else(|| StdError::GenericErr {
-                    msg: VCError::ModuleNotFound(module.clone()).to_string(),
-                })?
-                .clone();
-            module.version = ModuleVersion::Version(latest_version);
-            Ok(id)
+        let maybe_module_ref = match &module.version {
+            ModuleVersion::Version(_) => REGISTERED_MODULES.load(deps.storage, &module),
+            ModuleVersion::Latest => {
+                // get latest
+                let versions: StdResult<Vec<(String, ModuleReference)>> = REGISTERED_MODULES
+                    .prefix((module.namespace.clone(), module.name.clone()))
+                    .range(deps.storage, None, None, Order::Descending)
+                    .take(1)
+                    .collect();
+                let (latest_version, id) = versions?
+                    .first()
+                    .ok_or_else(|| StdError::GenericErr {
+                        msg: VCError::ModuleNotFound(module.clone()).to_string(),
+                    })?
+                    .clone();
+                module.version = ModuleVersion::Version(latest_version);
+                Ok(id)
+            }
+            ModuleVersion::Range(_) => {
+                // get the highest registered version in range, yanked versions are not registered
+                let versions = REGISTERED_MODULES
+                    .prefix((module.namespace.clone(), module.name.clone()))
+                    .keys(deps.storage, None, None, Order::Ascending)
+                    .collect::<StdResult<Vec<String>>>()?;
+                let version = module
+                    .version
+                    .highest_in_range(versions)
+                    .map_err(|e| StdError::generic_err(e.to_string()))?
+                    .ok_or_else(|| StdError::GenericErr {
+                        msg: VCError::ModuleNotFound(module.clone()).to_string(),
+                    })?;
+                module.version = ModuleVersion::Version(version);
+                REGISTERED_MODULES.load(deps.storage, &module)
+            }
         };
 
         match maybe_module_ref {
@@ -92,6 +113,7 @@ pub fn handle_module_list_query(
         name: ref name_filter,
         version: version_filter,
         status,
+        tag: tag_filter,
     } = filter.unwrap_or_default();
 
     let mod_lib = match status {
@@ -100,6 +122,14 @@ pub fn handle_module_list_query(
         Some(ModuleStatus::YANKED) => &YANKED_MODULES,
         None => &REGISTERED_MODULES,
     };
+    // the tag is matched before the limit is applied, its metadata is loaded per module
+    let has_tag = |info: &ModuleInfo| {
+        tag_filter.as_ref().map_or(true, |tag| {
+            ModuleConfiguration::from_storage(deps.storage, info)
+                .metadata
+                .has_tag(tag)
+        })
+    };
     let mut modules: Vec<(ModuleInfo, ModuleReference)> = vec![];
 
     if let Some(namespace_filter) = namespace_filter {
@@ -111,6 +141,7 @@ pub fn handle_module_list_query(
             namespace_filter,
             name_filter,
             mod_lib,
+            has_tag,
         )?);
     } else {
         let start_bound: Option<Bound<&ModuleInfo>> = start_after.as_ref().map(Bound::exclusive);
@@ -119,6 +150,7 @@ pub fn handle_module_list_query(
         modules.extend(
             mod_lib
                 .range(deps.storage, start_bound, None, Order::Ascending)
+                .filter(|r| r.as_ref().map_or(true, |(info, _)| has_tag(info)))
                 .take(limit)
                 .collect::<StdResult<Vec<_>>>()?
                 .into_iter(),
@@ -174,9 +206,56 @@ pub fn handle_namespace_query(deps: Deps, namespace: Namespace) -> StdResult<Nam
     let account_id = namespaces_info().load(deps.storage, &namespace)?;
     let account_base = ACCOUNT_ADDRESSES.load(deps.storage, account_id)?;
 
+    let expires_at = NAMESPACE_EXPIRATIONS.may_load(deps.storage, &namespace)?;
+
     Ok(NamespaceResponse {
         account_id,
         account_base,
+        expires_at,
+    })
+}
+
+pub fn handle_namespace_offer_query(
+    deps: Deps,
+    namespace: Namespace,
+) -> StdResult<NamespaceOfferResponse> {
+    let offer = NAMESPACE_OFFERS.may_load(deps.storage, &namespace)?;
+    Ok(NamespaceOfferResponse { offer })
+}
+
+pub fn handle_pending_modules_query(
+    deps: Deps,
+    start_after: Option<ModuleInfo>,
+    limit: Option<u8>,
+) -> StdResult<PendingModulesResponse> {
+    let start_bound: Option<Bound<&ModuleInfo>> = start_after.as_ref().map(Bound::exclusive);
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let reviewer_set = REVIEWERS.may_load(deps.storage)?;
+
+    let modules = PENDING_MODULES
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .map(|entry| {
+            let (info, reference) = entry?;
+            let reviews = PENDING_REVIEWS
+                .may_load(deps.storage, &info)?
+                .unwrap_or_default();
+            let (approvals, rejections) = reviewer_set
+                .as_ref()
+                .map(|set| set.tally(&reviews))
+                .unwrap_or_default();
+            Ok(PendingModuleResponse {
+                module: Module { info, reference },
+                reviews,
+                approvals,
+                rejections,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(PendingModulesResponse {
+        modules,
+        threshold: reviewer_set.map(|set| set.threshold),
     })
 }
 
@@ -204,6 +283,7 @@ fn filter_modules_by_namespace(
     namespace: Namespace,
     name: &Option<String>,
     mod_lib: &Map<&ModuleInfo, ModuleReference>,
+    has_tag: impl Fn(&ModuleInfo) -> bool,
 ) -> StdResult<Vec<(ModuleInfo, ModuleReference)>> {
     let mut modules: Vec<(ModuleInfo, ModuleReference)> = vec![];
 
@@ -216,19 +296,21 @@ fn filter_modules_by_namespace(
             mod_lib
                 .prefix((namespace.clone(), name.clone()))
                 .range(deps.storage, start_bound, None, Order::Ascending)
+                .map(|r| {
+                    r.map(|(version, reference)| {
+                        (
+                            ModuleInfo {
+                                namespace: namespace.clone(),
+                                name: name.clone(),
+                                version: ModuleVersion::Version(version),
+                            },
+                            reference,
+                        )
+                    })
+                })
+                .filter(|r| r.as_ref().map_or(true, |(info, _)| has_tag(info)))
                 .take(limit)
-                .collect::<StdResult<Vec<_>>>()?
-                .into_iter()
-                .map(|(version, reference)| {
-                    (
-                        ModuleInfo {
-                            namespace: namespace.clone(),
-                            name: name.clone(),
-                            version: ModuleVersion::Version(version),
-                        },
-                        reference,
-                    )
-                }),
+                .collect::<StdResult<Vec<_>>>()?,
         )
     } else {
         // Filter by just namespace using sub prefix
@@ -239,19 +321,21 @@ fn filter_modules_by_namespace(
             mod_lib
                 .sub_prefix(namespace.clone())
                 .range(deps.storage, start_bound, None, Order::Ascending)
+                .map(|r| {
+                    r.map(|((name, version), reference)| {
+                        (
+                            ModuleInfo {
+                                namespace: namespace.clone(),
+                                name,
+                                version: ModuleVersion::Version(version),
+                            },
+                            reference,
+                        )
+                    })
+                })
+                .filter(|r| r.as_ref().map_or(true, |(info, _)| has_tag(info)))
                 .take(limit)
-                .collect::<StdResult<Vec<_>>>()?
-                .into_iter()
-                .map(|((name, version), reference)| {
-                    (
-                        ModuleInfo {
-                            namespace: namespace.clone(),
-                            name,
-                            version: ModuleVersion::Version(version),
-                        },
-                        reference,
-                    )
-                }),
+                .collect::<StdResult<Vec<_>>>()?,
         );
     }
     Ok(modules)
@@ -267,7 +351,11 @@ mod test {
     use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
     use cosmwasm_std::{to_binary, Addr, Binary, DepsMut, StdError, Uint64};
 
-    use abstract_core::{manager, version_control::*};
+    use abstract_core::{
+        manager,
+        objects::module::ModuleMetadata,
+        version_control::{state::MODULE_METADATA, *},
+    };
 
     use crate::contract;
     use crate::contract::VCResult;
@@ -327,6 +415,8 @@ mod test {
             InstantiateMsg {
                 allow_direct_module_registration_and_updates: Some(true),
                 namespace_registration_fee: None,
+                namespace_limit: None,
+                namespace_registration_period: None,
             },
         )?;
         execute_as_admin(
@@ -348,6 +438,7 @@ mod test {
             ExecuteMsg::AddAccount {
                 account_id: TEST_ACCOUNT_ID,
                 account_base: test_account_base(),
+                parent_account_id: None,
             },
         )?;
         execute_as(
@@ -359,6 +450,7 @@ mod test {
                     manager: Addr::unchecked(TEST_OTHER_MANAGER_ADDR),
                     proxy: Addr::unchecked(TEST_OTHER_PROXY_ADDR),
                 },
+                parent_account_id: None,
             },
         )
     }
@@ -493,6 +585,40 @@ mod test {
             assert_that!(modules.swap_remove(0).module.info).is_equal_to(&newest_version);
             Ok(())
         }
+
+        #[test]
+        fn get_highest_registered_in_range() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            mock_init_with_account(deps.as_mut())?;
+
+            add_namespace(deps.as_mut(), "test");
+
+            let module_id = "test:module";
+            let versions = ["1.2.0", "1.10.0", "2.0.0"]
+                .map(|version| ModuleInfo::from_id(module_id, version.into()).unwrap());
+            for version in versions.iter() {
+                add_module(deps.as_mut(), version.clone());
+            }
+            // yanked versions are not resolved
+            REGISTERED_MODULES.remove(deps.as_mut().storage, &versions[1]);
+
+            let range_query = |range: &str| QueryMsg::Modules {
+                infos: vec![
+                    ModuleInfo::from_id(module_id, ModuleVersion::Range(range.into())).unwrap(),
+                ],
+            };
+
+            let ModulesResponse { mut modules } =
+                from_binary(&query_helper(deps.as_ref(), range_query("^1.2"))?)?;
+            assert_that!(modules.swap_remove(0).module.info).is_equal_to(&versions[0]);
+
+            let res = query_helper(deps.as_ref(), range_query("^3"));
+            assert_that!(res)
+                .is_err()
+                .matches(|e| matches!(e, VCError::Std(StdError::GenericErr { .. })));
+            Ok(())
+        }
     }
 
     use cosmwasm_std::from_binary;
@@ -760,6 +886,52 @@ mod test {
             });
         }
 
+        #[test]
+        fn filter_by_tag() -> VersionControlTestResult {
+            let mut deps = mock_dependencies();
+            deps.querier = mock_manager_querier().build();
+            init_with_mods(deps.as_mut());
+
+            let tagged_module =
+                ModuleInfo::from_id("cw-plus:module3", ModuleVersion::Version("0.1.2".into()))?;
+            MODULE_METADATA.save(
+                deps.as_mut().storage,
+                &tagged_module,
+                &ModuleMetadata {
+                    tags: vec!["dex".to_string()],
+                    ..Default::default()
+                },
+            )?;
+
+            let filter = ModuleFilter {
+                tag: Some("dex".to_string()),
+                ..Default::default()
+            };
+            let ModulesListResponse { modules } =
+                from_binary(&query_helper(deps.as_ref(), filtered_list_msg(filter))?)?;
+            assert_that!(modules).has_length(1);
+            assert_that!(modules[0].module.info).is_equal_to(&tagged_module);
+
+            // untagged modules don't take up the limit
+            for namespace in [None, Some("cw-plus".to_string())] {
+                let ModulesListResponse { modules } = from_binary(&query_helper(
+                    deps.as_ref(),
+                    QueryMsg::ModuleList {
+                        filter: Some(ModuleFilter {
+                            namespace,
+                            tag: Some("dex".to_string()),
+                            ..Default::default()
+                        }),
+                        start_after: None,
+                        limit: Some(1),
+                    },
+                )?)?;
+                assert_that!(modules).has_length(1);
+                assert_that!(modules[0].module.info).is_equal_to(&tagged_module);
+            }
+            Ok(())
+        }
+
         #[test]
         fn filter_by_namespace_non_existing() {
             let mut deps = mock_dependencies();
@@ -19,8 +19,13 @@ pub mod state {
 
     pub use crate::objects::account_id::ACCOUNT_ID;
     use crate::objects::common_namespace::OWNERSHIP_STORAGE_KEY;
-    use crate::objects::{gov_type::GovernanceDetails, module::ModuleId};
-    use cosmwasm_std::{Addr, Api};
+    use crate::objects::{
+        account_id::AccountId,
+        gov_type::GovernanceDetails,
+        module::{ModuleId, ModuleInfo, ModuleVersion},
+        namespace::Namespace,
+    };
+    use cosmwasm_std::{Addr, Api, Coin, Timestamp};
     use cw_address_like::AddressLike;
     use cw_controllers::Admin;
     use cw_ownable::Ownership;
@@ -71,6 +76,109 @@ pub mod state {
         }
     }
 
+    /// Proposal to execute a set of manager actions, used by [`GovernanceDetails::Multisig`] Accounts.
+    #[cosmwasm_schema::cw_serde]
+    pub struct Proposal {
+        pub proposer: Addr,
+        pub description: String,
+        pub actions: Vec<ProposalAction>,
+        /// Members that approved the proposal
+        pub approvals: Vec<Addr>,
+        pub executed: bool,
+    }
+
+    /// Manager action of a [`Proposal`] and the funds it is executed with
+    #[cosmwasm_schema::cw_serde]
+    pub struct ProposalAction {
+        pub msg: super::ExecuteMsg,
+        /// Funds sent along with the action, provided when the proposal is executed
+        pub funds: Vec<Coin>,
+    }
+
+    impl From<super::ExecuteMsg> for ProposalAction {
+        fn from(msg: super::ExecuteMsg) -> Self {
+            ProposalAction { msg, funds: vec![] }
+        }
+    }
+
+    /// Ownership transfer that can be executed after a delay
+    #[cosmwasm_schema::cw_serde]
+    pub struct ScheduledOwnerTransfer {
+        pub governance_details: GovernanceDetails<Addr>,
+        /// Time after which the transfer can be executed
+        pub executable_at: Timestamp,
+        /// Time after which the transfer can no longer be executed
+        pub expires_at: Option<Timestamp>,
+    }
+
+    /// Guardians that can recover the Account when its owner key is lost
+    #[cosmwasm_schema::cw_serde]
+    pub struct Guardians {
+        pub members: Vec<Addr>,
+        /// Number of guardian approvals required to recover the Account
+        pub quorum: u64,
+        /// Seconds between reaching the quorum and the recovery becoming executable
+        pub delay: u64,
+    }
+
+    /// Ownership recovery proposed by the guardians
+    #[cosmwasm_schema::cw_serde]
+    pub struct Recovery {
+        pub governance_details: GovernanceDetails<Addr>,
+        /// Guardians that approved the recovery
+        pub approvals: Vec<Addr>,
+        /// Time after which the recovery can be executed, set once the quorum is reached
+        pub executable_at: Option<Timestamp>,
+    }
+
+    /// Subscription of the Account to a module with [`crate::objects::module::Monetization::Subscription`] monetization
+    #[cosmwasm_schema::cw_serde]
+    pub struct ModuleSubscription {
+        pub per_period: Coin,
+        pub period_seconds: u64,
+        /// Time until which the subscription is paid
+        pub paid_until: Timestamp,
+    }
+
+    impl ModuleSubscription {
+        /// Whether the subscription is paid at the provided time
+        pub fn is_active(&self, time: Timestamp) -> bool {
+            time < self.paid_until
+        }
+    }
+
+    /// Restricts the modules that can be installed or upgraded on the Account
+    #[cosmwasm_schema::cw_serde]
+    #[derive(Default)]
+    pub struct InstallPolicy {
+        /// Only modules from these namespaces are allowed, all namespaces are allowed if `None`
+        pub allowed_namespaces: Option<Vec<Namespace>>,
+        /// Denied modules, [`ModuleVersion::Latest`] denies every version of the module
+        pub denied_modules: Vec<ModuleInfo>,
+    }
+
+    impl InstallPolicy {
+        /// Whether the module version can be installed
+        pub fn allows(&self, module: &ModuleInfo) -> bool {
+            if let Some(namespaces) = &self.allowed_namespaces {
+                if !namespaces.contains(&module.namespace) {
+                    return false;
+                }
+            }
+            !self.denied_modules.iter().any(|denied| {
+                denied.id() == module.id()
+                    && match &denied.version {
+                        ModuleVersion::Latest => true,
+                        ModuleVersion::Version(_) => denied.version == module.version,
+                        ModuleVersion::Range(_) => denied
+                            .version
+                            .highest_in_range(vec![module.version.to_string()])
+                            .map_or(false, |matched| matched.is_some()),
+                    }
+            })
+        }
+    }
+
     /// Suspension status
     pub const SUSPENSION_STATUS: Item<SuspensionStatus> = Item::new("\u{0}{12}is_suspended");
     /// Configuration
@@ -83,20 +191,44 @@ pub mod state {
     pub const OWNER: Item<Ownership<Addr>> = Item::new(OWNERSHIP_STORAGE_KEY);
     /// Enabled Abstract modules
     pub const ACCOUNT_MODULES: Map<ModuleId, Addr> = Map::new("modules");
+    /// Reverse mapping of [`ACCOUNT_MODULES`], module address -> module id
+    pub const MODULE_IDS: Map<&Addr, String> = Map::new("module_ids");
     /// Stores the dependency relationship between modules
     /// map module -> modules that depend on module.
     pub const DEPENDENTS: Map<ModuleId, HashSet<String>> = Map::new("dependents");
+    /// Sub-accounts owned by this Account, mapped to their manager address
+    pub const SUB_ACCOUNTS: Map<AccountId, Addr> = Map::new("sub_accs");
+    /// Scheduled ownership transfer
+    pub const PENDING_OWNER_TRANSFER: Item<ScheduledOwnerTransfer> = Item::new("pending_transfer");
+    /// Multisig proposals
+    pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+    /// Id of the next multisig proposal
+    pub const NEXT_PROPOSAL_ID: Item<u64> = Item::new("next_proposal_id");
+    /// Proposals with a lower id were made under a previous governance and can't be voted on or executed
+    pub const FIRST_VALID_PROPOSAL_ID: Item<u64> = Item::new("first_valid_proposal");
+    /// Guardian set of the Account
+    pub const GUARDIANS: Item<Guardians> = Item::new("guardians");
+    /// Ownership recovery proposed by the guardians
+    pub const PENDING_RECOVERY: Item<Recovery> = Item::new("pending_recovery");
+    /// Subscriptions of the installed modules
+    pub const SUBSCRIPTIONS: Map<ModuleId, ModuleSubscription> = Map::new("subscriptions");
+    /// Modules that can be installed on the Account, all modules can be installed if not set
+    pub const INSTALL_POLICY: Item<InstallPolicy> = Item::new("install_policy");
 }
 
-use self::state::AccountInfo;
+use self::state::{
+    AccountInfo, Guardians, InstallPolicy, ModuleSubscription, Proposal, ProposalAction, Recovery,
+    ScheduledOwnerTransfer,
+};
 use crate::manager::state::SuspensionStatus;
 use crate::objects::{
     account_id::AccountId,
     gov_type::GovernanceDetails,
     module::{Module, ModuleInfo},
 };
+use crate::proxy::ModulePermissionsUnchecked;
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Binary, Uint64};
+use cosmwasm_std::{Addr, Binary, Coin, Uint64};
 use cw2::ContractVersion;
 
 /// Manager Migrate Msg
@@ -119,6 +251,20 @@ pub struct InstantiateMsg {
 #[cosmwasm_schema::cw_serde]
 pub struct CallbackMsg {}
 
+/// Action of an [`ExecuteMsg::Batch`] and the funds it is executed with
+#[cosmwasm_schema::cw_serde]
+pub struct BatchAction {
+    pub msg: ExecuteMsg,
+    /// Part of the funds sent with the batch that is provided to the action
+    pub funds: Vec<Coin>,
+}
+
+impl From<ExecuteMsg> for BatchAction {
+    fn from(msg: ExecuteMsg) -> Self {
+        BatchAction { msg, funds: vec![] }
+    }
+}
+
 /// Internal configuration actions accessible from the [`ExecuteMsg::UpdateInternalConfig`] message.
 #[cosmwasm_schema::cw_serde]
 #[non_exhaustive]
@@ -129,6 +275,15 @@ pub enum InternalConfigAction {
         to_add: Option<Vec<(String, String)>>,
         to_remove: Option<Vec<String>>,
     },
+    /// Registers a sub-account created by this Account in [`state::SUB_ACCOUNTS`]
+    /// Only callable by account factory.
+    RegisterSubAccount {
+        account_id: AccountId,
+        manager: String,
+    },
+    /// Removes a sub-account from [`state::SUB_ACCOUNTS`] once it is no longer governed by this Account
+    /// Only callable by the manager of the sub-account.
+    UnregisterSubAccount { account_id: AccountId },
 }
 
 /// Manager execute messages
@@ -138,6 +293,12 @@ pub enum InternalConfigAction {
 pub enum ExecuteMsg {
     /// Forward execution message to module
     ExecOnModule { module_id: String, exec_msg: Binary },
+    /// Set or clear the permission scopes of an installed module on the proxy.
+    /// `None` gives the module unrestricted access to the proxy.
+    UpdateModulePermissions {
+        module_id: String,
+        permissions: Option<ModulePermissionsUnchecked>,
+    },
     /// Update Abstract-specific configuration of the module.
     /// Only callable by the account factory or owner.
     UpdateInternalConfig(Binary),
@@ -148,29 +309,110 @@ pub enum ExecuteMsg {
         module: ModuleInfo,
         // Instantiate message used to instantiate the contract.
         init_msg: Option<Binary>,
+        /// Permission scopes of the module on the proxy, the module is unrestricted if `None`.
+        permissions: Option<ModulePermissionsUnchecked>,
     },
     /// Registers a module after creation.
     /// Used as a callback *only* by the Module Factory to register the module on the Account.
     RegisterModule { module_addr: String, module: Module },
+    /// Pay the next period of the subscription to a module.
+    /// The sent funds must match the subscription fee, which is transferred to the module's namespace owner.
+    /// The fee is re-priced with the monetization of the installed module version, a module that became free ends its subscription.
+    #[cfg_attr(feature = "interface", payable)]
+    PaySubscription { module_id: String },
     /// Uninstall a module given its ID.
     UninstallModule { module_id: String },
+    /// Uninstall a module and all the modules that depend on it.
+    /// Dependents are uninstalled before their dependencies, see [`QueryMsg::UninstallPlan`].
+    UninstallModuleCascade { module_id: String },
     /// Upgrade the module to a new version
     /// If module is `abstract::manager` then the contract will do a self-migration.
     Upgrade {
         modules: Vec<(ModuleInfo, Option<Binary>)>,
     },
+    /// Execute multiple actions atomically, callable by Owner.
+    /// Install requirements of the modules installed in the batch are asserted once all actions are executed.
+    /// The sent funds must match the total funds of the actions.
+    #[cfg_attr(feature = "interface", payable)]
+    Batch { actions: Vec<BatchAction> },
     /// Update info
     UpdateInfo {
         name: Option<String>,
         description: Option<String>,
         link: Option<String>,
     },
+    /// Creates a sub-account that is owned by this Account.
+    /// Callable by Owner
+    CreateSubAccount {
+        name: String,
+        description: Option<String>,
+        link: Option<String>,
+    },
+    /// Forward execution message to the manager of a sub-account.
+    /// Callable by Owner
+    ExecOnSubAccount {
+        account_id: AccountId,
+        exec_msg: Binary,
+    },
     /// Sets a new Owner
     SetOwner { owner: GovernanceDetails<String> },
-    /// Update account statuses
+    /// Schedule an ownership transfer that can be executed once `delay_seconds` have passed.
+    /// The transfer expires `expiry_seconds` after it became executable.
+    /// Callable by Owner
+    ScheduleOwnerTransfer {
+        owner: GovernanceDetails<String>,
+        delay_seconds: u64,
+        expiry_seconds: Option<u64>,
+    },
+    /// Cancel the scheduled ownership transfer, callable by Owner
+    CancelOwnerTransfer {},
+    /// Execute the scheduled ownership transfer after its delay.
+    /// Callable by Owner or the new owner
+    ExecuteOwnerTransfer {},
+    /// Update account statuses.
+    /// Callable by Owner, guardians can only suspend the Account once a recovery reached their quorum.
+    /// The Owner can not lift the suspension while that recovery is pending
     UpdateStatus { is_suspended: Option<bool> },
+    /// Set the guardians that can recover the Account, an empty list removes the guardians.
+    /// Cancels any pending recovery, unless it was approved by the guardians' quorum.
+    /// Callable by Owner, also while the Account is suspended
+    UpdateGuardians {
+        guardians: Vec<String>,
+        quorum: u64,
+        delay_seconds: u64,
+    },
+    /// Propose to recover the Account by transferring it to a new owner.
+    /// Callable by a guardian
+    ProposeRecovery { owner: GovernanceDetails<String> },
+    /// Approve the pending recovery.
+    /// Callable by a guardian
+    ApproveRecovery {},
+    /// Execute the pending recovery once it reached the quorum and its delay passed, the new owner does not have to accept the ownership.
+    /// Callable by a guardian
+    ExecuteRecovery {},
+    /// Cancel the pending recovery, unless it was approved by the guardians' quorum.
+    /// Callable by Owner, also while the Account is suspended
+    CancelRecovery {},
     /// Update settings for the Account, including IBC enabled, etc.
-    UpdateSettings { ibc_enabled: Option<bool> },
+    /// `install_policy` restricts the modules that can be installed and upgraded
+    UpdateSettings {
+        ibc_enabled: Option<bool>,
+        install_policy: Option<InstallPolicy>,
+    },
+    /// Propose a set of manager actions to the Account's multisig.
+    /// Only callable by a member of a [`GovernanceDetails::Multisig`] governance.
+    Propose {
+        description: String,
+        actions: Vec<ProposalAction>,
+    },
+    /// Approve a pending multisig proposal.
+    /// Only callable by a member of a [`GovernanceDetails::Multisig`] governance.
+    Vote { proposal_id: u64 },
+    /// Execute a multisig proposal that reached the approval threshold.
+    /// The actions are executed by the manager on itself, which is the owner of a multisig Account.
+    /// The sent funds must match the total funds of the proposal's actions.
+    #[cfg_attr(feature = "interface", payable)]
+    ExecuteProposal { proposal_id: u64 },
     /// Callback endpoint
     Callback(CallbackMsg),
 }
@@ -204,6 +446,44 @@ pub enum QueryMsg {
     /// Returns [`InfoResponse`]
     #[returns(InfoResponse)]
     Info {},
+    /// Query the scheduled ownership transfer.
+    /// Returns [`PendingOwnerTransferResponse`]
+    #[returns(PendingOwnerTransferResponse)]
+    PendingOwnerTransfer {},
+    /// Query the guardians of the Account and the pending recovery
+    /// Returns [`GuardiansResponse`]
+    #[returns(GuardiansResponse)]
+    Guardians {},
+    /// Query the subscription of an installed module.
+    /// Returns [`SubscriptionStatusResponse`]
+    #[returns(SubscriptionStatusResponse)]
+    SubscriptionStatus { module_id: String },
+    /// Query the modules that can be installed on the Account.
+    /// Returns [`InstallPolicyResponse`]
+    #[returns(InstallPolicyResponse)]
+    InstallPolicy {},
+    /// Query the modules that would be uninstalled by [`ExecuteMsg::UninstallModuleCascade`], in uninstall order.
+    /// Returns [`UninstallPlanResponse`]
+    #[returns(UninstallPlanResponse)]
+    UninstallPlan { module_id: String },
+    /// Query the ids of the sub-accounts owned by this Account.
+    /// Returns [`SubAccountIdsResponse`]
+    #[returns(SubAccountIdsResponse)]
+    SubAccountIds {
+        start_after: Option<AccountId>,
+        limit: Option<u8>,
+    },
+    /// Query a multisig proposal.
+    /// Returns [`ProposalResponse`]
+    #[returns(ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    /// Query the multisig proposals of the Account.
+    /// Returns [`ProposalsResponse`]
+    #[returns(ProposalsResponse)]
+    Proposals {
+        start_after: Option<u64>,
+        limit: Option<u8>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -240,3 +520,54 @@ pub struct ManagerModuleInfo {
 pub struct ModuleInfosResponse {
     pub module_infos: Vec<ManagerModuleInfo>,
 }
+
+#[cosmwasm_schema::cw_serde]
+pub struct PendingOwnerTransferResponse {
+    pub transfer: Option<ScheduledOwnerTransfer>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct GuardiansResponse {
+    pub guardians: Option<Guardians>,
+    pub recovery: Option<Recovery>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct SubscriptionStatusResponse {
+    /// `None` if the module is not monetized with a subscription
+    pub subscription: Option<ModuleSubscription>,
+    /// Whether the module can be executed, false once its subscription lapsed
+    pub is_active: bool,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct InstallPolicyResponse {
+    pub install_policy: InstallPolicy,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct UninstallPlanResponse {
+    pub modules: Vec<String>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct SubAccountIdsResponse {
+    pub sub_accounts: Vec<AccountId>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ProposalResponse {
+    pub id: u64,
+    pub proposal: Proposal,
+    /// Total weight of the current members that approved the proposal
+    pub approved_weight: u64,
+    /// Weight required for the proposal to pass
+    pub threshold: u64,
+    /// Whether the proposal was made under the current governance and can still be voted on and executed
+    pub valid: bool,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ProposalsResponse {
+    pub proposals: Vec<ProposalResponse>,
+}
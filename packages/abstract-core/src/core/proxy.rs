@@ -10,6 +10,7 @@
 //! [price sources](crate::objects::price_source) are what allow the proxy contract to provide value queries for its assets. It needs to be configured using the [`ExecuteMsg::UpdateAssets`] endpoint.
 //! After configuring the price sources [`QueryMsg::TotalValue`] can be called to get the total holding value.
 
+use crate::AbstractResult;
 #[allow(unused_imports)]
 use crate::{
     ibc_client::ExecuteMsg as IbcClientMsg,
@@ -21,17 +22,21 @@ use crate::{
     },
 };
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{CosmosMsg, Empty, Uint128};
-use cw_asset::{Asset, AssetInfo};
+use cosmwasm_std::{Addr, Api, CosmosMsg, Empty, Timestamp, Uint128};
+use cw_address_like::AddressLike;
+use cw_asset::{Asset, AssetInfo, AssetInfoBase};
 
 pub mod state {
     pub use crate::objects::account_id::ACCOUNT_ID;
+    use cw_asset::AssetInfo;
     use cw_controllers::Admin;
 
     use cosmwasm_std::Addr;
-    use cw_storage_plus::Item;
+    use cw_storage_plus::{Item, Map};
 
-    use crate::objects::{ans_host::AnsHost, common_namespace::ADMIN_NAMESPACE};
+    use super::{ModulePermissions, OutflowLimit, SpendingPeriod};
+    use crate::objects::{ans_host::AnsHost, common_namespace::ADMIN_NAMESPACE, AssetEntry};
+    use cosmwasm_std::{Timestamp, Uint128};
     #[cosmwasm_schema::cw_serde]
     pub struct State {
         pub modules: Vec<Addr>,
@@ -39,6 +44,131 @@ pub mod state {
     pub const ANS_HOST: Item<AnsHost> = Item::new("\u{0}{6}ans_host");
     pub const STATE: Item<State> = Item::new("\u{0}{5}state");
     pub const ADMIN: Admin = Admin::new(ADMIN_NAMESPACE);
+    /// Permission scopes of whitelisted modules. Modules without an entry are unrestricted.
+    pub const MODULE_PERMISSIONS: Map<&Addr, ModulePermissions> = Map::new("module_perms");
+    /// Amount spent by a module for an asset in the current spend-limit period.
+    pub const MODULE_SPENDING: Map<(&Addr, &AssetInfo), SpendingPeriod> =
+        Map::new("module_spending");
+    /// Account-wide outflow limits on assets sent through module actions, keyed by the resolved asset.
+    pub const OUTFLOW_LIMITS: Map<&AssetInfo, (AssetEntry, OutflowLimit)> =
+        Map::new("outflow_limits");
+    /// Amounts sent per asset that are still within the window of its outflow limit, grouped in buckets by time.
+    pub const OUTFLOW_BUCKETS: Map<&AssetInfo, Vec<(Timestamp, Uint128)>> =
+        Map::new("outflow_buckets");
+    /// Whether the balances of the Account on remote chains are included in its total value.
+    pub const INCLUDE_REMOTE_BALANCES: Item<bool> = Item::new("remote_balances");
+}
+
+/// Kind of [`CosmosMsg`] a module can be allowed to execute through the proxy.
+#[cosmwasm_schema::cw_serde]
+pub enum CosmosMsgKind {
+    Bank,
+    Wasm,
+    Staking,
+    Distribution,
+    Stargate,
+    Ibc,
+    Gov,
+}
+
+/// Permission scopes of a module that is whitelisted on the proxy.
+#[cosmwasm_schema::cw_serde]
+pub struct ModulePermissionsBase<T: AddressLike> {
+    /// Kinds of messages the module is allowed to execute. All kinds are allowed when `None`.
+    pub allowed_msgs: Option<Vec<CosmosMsgKind>>,
+    /// Native denoms and cw20 tokens the module is allowed to move out of the proxy. All assets are allowed when `None`.
+    /// Modules with allowed assets or spend limits can't execute messages that move unknown assets, such as Stargate messages.
+    pub allowed_assets: Option<Vec<AssetInfoBase<T>>>,
+    /// Maximum amounts the module can move out of the proxy per period.
+    pub spend_limits: Vec<SpendLimitBase<T>>,
+}
+
+pub type ModulePermissionsUnchecked = ModulePermissionsBase<String>;
+pub type ModulePermissions = ModulePermissionsBase<Addr>;
+
+impl ModulePermissionsUnchecked {
+    pub fn check(self, api: &dyn Api) -> AbstractResult<ModulePermissions> {
+        let allowed_assets = self
+            .allowed_assets
+            .map(|assets| {
+                assets
+                    .into_iter()
+                    .map(|info| info.check(api, None))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let spend_limits = self
+            .spend_limits
+            .into_iter()
+            .map(|limit| limit.check(api))
+            .collect::<AbstractResult<Vec<_>>>()?;
+        Ok(ModulePermissions {
+            allowed_msgs: self.allowed_msgs,
+            allowed_assets,
+            spend_limits,
+        })
+    }
+}
+
+impl From<ModulePermissions> for ModulePermissionsUnchecked {
+    fn from(permissions: ModulePermissions) -> Self {
+        ModulePermissionsUnchecked {
+            allowed_msgs: permissions.allowed_msgs,
+            allowed_assets: permissions
+                .allowed_assets
+                .map(|assets| assets.into_iter().map(Into::into).collect()),
+            spend_limits: permissions
+                .spend_limits
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// Cap on the amount of an asset a module can move out of the proxy within `period` seconds.
+#[cosmwasm_schema::cw_serde]
+pub struct SpendLimitBase<T: AddressLike> {
+    pub asset: AssetInfoBase<T>,
+    pub amount: Uint128,
+    pub period: u64,
+}
+
+pub type SpendLimitUnchecked = SpendLimitBase<String>;
+pub type SpendLimit = SpendLimitBase<Addr>;
+
+impl SpendLimitUnchecked {
+    pub fn check(self, api: &dyn Api) -> AbstractResult<SpendLimit> {
+        Ok(SpendLimit {
+            asset: self.asset.check(api, None)?,
+            amount: self.amount,
+            period: self.period,
+        })
+    }
+}
+
+impl From<SpendLimit> for SpendLimitUnchecked {
+    fn from(limit: SpendLimit) -> Self {
+        SpendLimitUnchecked {
+            asset: limit.asset.into(),
+            amount: limit.amount,
+            period: limit.period,
+        }
+    }
+}
+
+/// Maximum amount of an asset that can be sent through module actions within any `window` of seconds.
+#[cosmwasm_schema::cw_serde]
+pub struct OutflowLimit {
+    pub limit: Uint128,
+    pub window: u64,
+}
+
+/// Amount spent within the period that started at `start`.
+#[cosmwasm_schema::cw_serde]
+pub struct SpendingPeriod {
+    pub start: Timestamp,
+    pub spent: Uint128,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -58,8 +188,16 @@ pub enum ExecuteMsg {
     ModuleActionWithData { msg: CosmosMsg<Empty> },
     /// Execute IBC action on Client
     IbcAction { msgs: Vec<IbcClientMsg> },
-    /// Adds the provided address to whitelisted dapps
-    AddModule { module: String },
+    /// Adds the provided address to whitelisted dapps, optionally restricted to the provided permission scopes
+    AddModule {
+        module: String,
+        permissions: Option<ModulePermissionsUnchecked>,
+    },
+    /// Sets or clears (`None`) the permission scopes of a whitelisted module
+    UpdateModulePermissions {
+        module: String,
+        permissions: Option<ModulePermissionsUnchecked>,
+    },
     /// Removes the provided address from the whitelisted dapps
     RemoveModule { module: String },
     /// Updates the VAULT_ASSETS map
@@ -67,6 +205,16 @@ pub enum ExecuteMsg {
         to_add: Vec<(AssetEntry, UncheckedPriceSource)>,
         to_remove: Vec<AssetEntry>,
     },
+    /// Updates the outflow limits applied to assets sent through module actions
+    UpdateOutflowLimits {
+        to_add: Vec<(AssetEntry, OutflowLimit)>,
+        to_remove: Vec<AssetEntry>,
+    },
+    /// Sets whether the balances on remote chains, as last synced by the IBC client, are included in the total value
+    UpdateRemoteBalances { include: bool },
+    /// Accumulates the pool ratios of the assets priced by a time-weighted average.
+    /// Permissionless so the averages can be kept up to date by anyone.
+    UpdateTwaps {},
 }
 #[cosmwasm_schema::cw_serde]
 pub struct MigrateMsg {}
@@ -79,7 +227,8 @@ pub enum QueryMsg {
     /// Returns [`ConfigResponse`]
     #[returns(ConfigResponse)]
     Config {},
-    /// Returns the total value of the assets held by this account
+    /// Returns the total value of the assets held by this account,
+    /// including its remote balances if enabled through [`ExecuteMsg::UpdateRemoteBalances`]
     /// [`AccountValue`]
     #[returns(AccountValue)]
     TotalValue {},
@@ -113,6 +262,14 @@ pub enum QueryMsg {
     /// Returns [`BaseAssetResponse`]
     #[returns(BaseAssetResponse)]
     BaseAsset {},
+    /// Returns the permission scopes of a whitelisted module
+    /// [`ModulePermissionsResponse`]
+    #[returns(ModulePermissionsResponse)]
+    ModulePermissions { module: String },
+    /// Returns the outflow limit of an asset and the amount spent within its current window
+    /// [`OutflowUsageResponse`]
+    #[returns(OutflowUsageResponse)]
+    OutflowUsage { asset: AssetEntry },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -120,6 +277,22 @@ pub struct ConfigResponse {
     pub modules: Vec<String>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct ModulePermissionsResponse {
+    /// `None` when the module is unrestricted
+    pub permissions: Option<ModulePermissionsUnchecked>,
+    /// Amounts spent per asset in the current period of each spend limit
+    pub spending: Vec<(AssetInfo, SpendingPeriod)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct OutflowUsageResponse {
+    /// `None` when the asset has no outflow limit
+    pub limit: Option<OutflowLimit>,
+    /// Amount spent within the current window
+    pub used: Uint128,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct TokenValueResponse {
     pub value: Uint128,
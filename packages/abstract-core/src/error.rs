@@ -1,4 +1,4 @@
-use cosmwasm_std::{OverflowError, StdError};
+use cosmwasm_std::{OverflowError, StdError, Timestamp};
 use cw_asset::AssetError;
 use cw_semver::Error as CwSemverError;
 use semver::{Error as SemverError, Version};
@@ -62,6 +62,24 @@ pub enum AbstractError {
     #[error("deposit error: {0}")]
     Deposit(String),
 
+    #[error("Price of {asset} last updated at {updated_at} is older than the maximum age of {max_age} seconds")]
+    StalePrice {
+        asset: String,
+        updated_at: Timestamp,
+        max_age: u64,
+    },
+
+    #[error(
+        "Price of {asset} was updated at {updated_at}, which is later than the current block time"
+    )]
+    FuturePrice {
+        asset: String,
+        updated_at: Timestamp,
+    },
+
+    #[error("No time-weighted average price available yet for {0}")]
+    TwapNotReady(String),
+
     #[error("The version or name of this module was not consistent between its stores (cw2: {cw2} and abstract module data: {module}).")]
     UnequalModuleData { cw2: String, module: String },
 }
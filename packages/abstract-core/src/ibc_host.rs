@@ -13,7 +13,9 @@ use crate::{
         MigrateMsg as MiddlewareMigrateMsg, QueryMsg as MiddlewareQueryMsg,
     },
     ibc_client::CallbackInfo,
+    manager,
     objects::account_id::AccountId,
+    version_control::AccountBase,
 };
 use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::{Addr, Binary, CosmosMsg, Empty, QueryRequest};
@@ -31,6 +33,8 @@ pub struct BaseInstantiateMsg {
     pub ans_host_address: String,
     /// Code-id for cw1 proxy contract
     pub cw1_code_id: u64,
+    /// Account factory used to create Abstract Accounts for remote Accounts
+    pub account_factory_address: Option<String>,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -38,8 +42,12 @@ pub struct BaseMigrateMsg {}
 
 #[cosmwasm_schema::cw_serde]
 pub enum InternalAction {
-    Register { account_proxy_address: String },
+    Register {
+        account_proxy_address: String,
+    },
     WhoAmI,
+    /// Create an Abstract Account (manager + proxy) for the remote Account through the account factory
+    CreateAccount,
 }
 
 /// Callable actions on a remote host
@@ -53,9 +61,13 @@ pub enum HostAction {
     },
     Query {
         msgs: Vec<QueryRequest<Empty>>,
+        /// Id under which the client stores the query result
+        query_id: String,
     },
     SendAllBack {},
     Balances {},
+    /// Execute a message on the manager of the Abstract Account created for the remote Account
+    Manager(manager::ExecuteMsg),
     /// Can't be called through the packet endpoint directly
     Internal(InternalAction),
 }
@@ -65,18 +77,26 @@ impl HostAction {
         self,
         account_id: AccountId,
         retries: u8,
+        timeout: u64,
         client_chain: String,
         callback_info: Option<CallbackInfo>,
     ) -> PacketMsg {
         PacketMsg {
             client_chain,
             retries,
+            retried: 0,
+            timeout,
             callback_info,
             account_id,
             action: self,
         }
     }
 }
+/// Lifetime of packets sent before the timeout was part of the packet
+fn default_packet_timeout() -> u64 {
+    60 * 60
+}
+
 /// This is the message we send over the IBC channel
 #[cosmwasm_schema::cw_serde]
 pub struct PacketMsg {
@@ -84,6 +104,12 @@ pub struct PacketMsg {
     pub client_chain: String,
     /// Amount of retries to attempt if packet returns with StdAck::Error
     pub retries: u8,
+    /// Amount of retries already performed for this packet
+    #[serde(default)]
+    pub retried: u8,
+    /// Seconds the packet, and each of its retries, can take to be received by the host
+    #[serde(default = "default_packet_timeout")]
+    pub timeout: u64,
     pub account_id: AccountId,
     /// Callback performed after receiving an StdAck::Result
     pub callback_info: Option<CallbackInfo>,
@@ -99,6 +125,7 @@ pub enum BaseExecuteMsg {
     UpdateConfig {
         ans_host_address: Option<String>,
         cw1_code_id: Option<u64>,
+        account_factory_address: Option<String>,
     },
     /// Allow for fund recovery through the Admin
     RecoverAccount {
@@ -126,11 +153,19 @@ pub enum BaseQueryMsg {
     /// No pagination - this is a test contract
     #[returns(ListAccountsResponse)]
     ListAccounts {},
+    /// Returns the Abstract Account created for the remote Account,
+    /// or none.
+    #[returns(AbstractAccountResponse)]
+    AbstractAccount {
+        client_chain: String,
+        account_id: AccountId,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
 pub struct HostConfigResponse {
     pub ans_host_address: Addr,
+    pub account_factory_address: Option<Addr>,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -138,6 +173,11 @@ pub struct AccountResponse {
     pub account: Option<String>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct AbstractAccountResponse {
+    pub account: Option<AccountBase>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ListAccountsResponse {
     pub accounts: Vec<AccountInfo>,
@@ -163,6 +203,7 @@ mod tests {
 
         // Create required parameters
         let retries = 5u8;
+        let timeout = 600u64;
         let client_chain = String::from("test_client_chain");
         let callback_info = Some(CallbackInfo {
             id: "15".to_string(),
@@ -173,6 +214,7 @@ mod tests {
         let packet_msg = host_action.clone().into_packet(
             TEST_ACCOUNT_ID,
             retries,
+            timeout,
             client_chain.clone(),
             callback_info.clone(),
         );
@@ -180,6 +222,8 @@ mod tests {
         // Check if the returned PacketMsg has the expected values
         assert_that!(packet_msg.client_chain).is_equal_to(client_chain);
         assert_that!(packet_msg.retries).is_equal_to(retries);
+        assert_that!(packet_msg.retried).is_equal_to(0);
+        assert_that!(packet_msg.timeout).is_equal_to(timeout);
         assert_that!(packet_msg.callback_info).is_equal_to(callback_info);
         assert_that!(packet_msg.account_id).is_equal_to(TEST_ACCOUNT_ID);
         assert_that!(packet_msg.action).is_equal_to(host_action);
@@ -29,6 +29,8 @@ pub mod state {
         pub account_manager_address: Option<Addr>,
         pub manager_module: Option<Module>,
         pub proxy_module: Option<Module>,
+        /// Account ID and manager address of the parent Account when creating a sub-account
+        pub parent_account: Option<(AccountId, Addr)>,
     }
 
     pub const CONFIG: Item<Config> = Item::new("\u{0}{5}config");
@@ -77,6 +79,16 @@ pub enum ExecuteMsg {
         // Account link
         link: Option<String>,
     },
+    /// Creates a sub-account that is owned by the calling Account.
+    /// Only callable by the manager of a registered Account.
+    CreateSubAccount {
+        // Account name
+        name: String,
+        // Account description
+        description: Option<String>,
+        // Account link
+        link: Option<String>,
+    },
 }
 
 /// Account Factory query messages
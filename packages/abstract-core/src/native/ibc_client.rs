@@ -1,13 +1,14 @@
 use self::state::AccountData;
 use crate::{abstract_ica::StdAck, ibc_host::HostAction, objects::account_id::AccountId};
-use abstract_ica::IbcResponseMsg;
+use abstract_ica::{IbcCallbackOutcome, IbcResponseMsg};
 use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::{from_slice, Binary, Coin, CosmosMsg, StdResult, Timestamp};
 
 pub mod state {
 
-    use super::LatestQueryResponse;
+    use super::{FailedPacket, PacketRetry, QueryResult, RemoteAccount};
     use crate::{
+        ibc_host::PacketMsg,
         objects::{account_id::AccountId, ans_host::AnsHost, common_namespace::ADMIN_NAMESPACE},
         ANS_HOST as ANS_HOST_KEY,
     };
@@ -33,6 +34,8 @@ pub mod state {
         /// must not be of type `Addr`.
         pub remote_addr: Option<String>,
         pub remote_balance: Vec<Coin>,
+        /// Abstract Account created for this Account on the remote chain, if any.
+        pub remote_account: Option<RemoteAccount>,
     }
 
     pub const ADMIN: Admin = Admin::new(ADMIN_NAMESPACE);
@@ -41,8 +44,19 @@ pub mod state {
     pub const CONFIG: Item<Config> = Item::new("config");
     /// (channel-id,account_id) -> remote_addr
     pub const ACCOUNTS: Map<(&str, AccountId), AccountData> = Map::new("accounts");
-    /// Todo: see if we can remove this
-    pub const LATEST_QUERIES: Map<(&str, AccountId), LatestQueryResponse> = Map::new("queries");
+    /// (account_id, channel-id, query_id) -> result of the query
+    pub const QUERY_RESULTS: Map<(AccountId, &str, &str), QueryResult> = Map::new("query_results");
+    /// account_id -> (channel-id, query_id) of the stored query results, oldest first
+    pub const QUERY_HISTORY: Map<AccountId, Vec<(String, String)>> = Map::new("query_history");
+    /// (account_id, channel-id, packet sequence) -> packet that timed out
+    pub const FAILED_PACKETS: Map<(AccountId, &str, u64), FailedPacket> =
+        Map::new("failed_packets");
+    /// host_chain -> packet lifetime in seconds
+    pub const HOST_TIMEOUTS: Map<&str, u64> = Map::new("host_timeouts");
+    /// (account_id, channel-id, packet sequence) -> retry of the errored packet
+    pub const PACKET_RETRIES: Map<(AccountId, &str, u64), PacketRetry> = Map::new("packet_retries");
+    /// (resend time in seconds, channel-id, errored packet sequence) -> packet waiting to be resent
+    pub const RETRY_QUEUE: Map<(u64, &str, u64), PacketMsg> = Map::new("retry_queue");
     pub const ANS_HOST: Item<AnsHost> = Item::new(ANS_HOST_KEY);
 }
 
@@ -57,6 +71,13 @@ pub struct InstantiateMsg {
 #[cosmwasm_schema::cw_serde]
 pub struct MigrateMsg {}
 
+/// Addresses of an Abstract Account on a remote chain
+#[cosmwasm_schema::cw_serde]
+pub struct RemoteAccount {
+    pub manager: String,
+    pub proxy: String,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct CallbackInfo {
     pub id: String,
@@ -65,8 +86,20 @@ pub struct CallbackInfo {
 
 impl CallbackInfo {
     pub fn to_callback_msg(self, ack_data: &Binary) -> StdResult<CosmosMsg> {
-        let msg: StdAck = from_slice(ack_data)?;
-        IbcResponseMsg { id: self.id, msg }.into_cosmos_account_msg(self.receiver)
+        let ack: StdAck = from_slice(ack_data)?;
+        IbcResponseMsg {
+            id: self.id,
+            msg: IbcCallbackOutcome::Ack(ack),
+        }
+        .into_cosmos_account_msg(self.receiver)
+    }
+
+    pub fn to_timeout_msg(self) -> StdResult<CosmosMsg> {
+        IbcResponseMsg {
+            id: self.id,
+            msg: IbcCallbackOutcome::Timeout {},
+        }
+        .into_cosmos_account_msg(self.receiver)
     }
 }
 
@@ -85,15 +118,24 @@ pub enum ExecuteMsg {
     /// Only callable by Account proxy
     /// Will attempt to forward the specified funds to the corresponding
     /// address on the remote chain.
+    /// Transfers that time out are refunded to the proxy by the ICS-20 transfer module.
     SendFunds {
         host_chain: String,
         funds: Vec<Coin>,
+        // Seconds before the transfers time out, defaults to the host's timeout
+        timeout: Option<u64>,
     },
     /// Register an Account on a remote chain over IBC
     /// This action creates a proxy for them on the remote chain.
     Register {
         host_chain: String,
     },
+    /// Only callable by a registered Account proxy
+    /// Creates a full Abstract Account (manager + proxy) for them on the remote chain,
+    /// which can then be controlled through [`HostAction::Manager`].
+    CreateRemoteAccount {
+        host_chain: String,
+    },
     SendPacket {
         // host chain to be executed on
         // Example: "osmosis"
@@ -104,10 +146,31 @@ pub enum ExecuteMsg {
         callback_info: Option<CallbackInfo>,
         // Number of retries if packet errors
         retries: u8,
+        // Seconds before the packet and its retries time out, defaults to the host's timeout
+        timeout: Option<u64>,
     },
     RemoveHost {
         host_chain: String,
     },
+    /// Set the lifetime of packets sent to a host, in seconds.
+    /// Removing it falls back to the default lifetime.
+    UpdateHostTimeout {
+        host_chain: String,
+        timeout: Option<u64>,
+    },
+    /// Only callable by the Admin
+    /// Requests the balances of the registered remote accounts from their hosts,
+    /// paginated over the (channel-id, account_id) of the accounts.
+    /// Accounts sync their own remote balances by sending a [`HostAction::Balances`] packet from their proxy.
+    SyncBalances {
+        start_after: Option<(String, AccountId)>,
+        limit: Option<u8>,
+    },
+    /// Resends the errored packets whose retry backoff has elapsed.
+    /// Callable by anyone.
+    ResendRetries {
+        limit: Option<u8>,
+    },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -126,15 +189,49 @@ pub enum QueryMsg {
         chain: String,
         account_id: AccountId,
     },
-    // Get remote account info for a chain + Account
-    #[returns(LatestQueryResponse)]
-    LatestQueryResult {
+    // Get the result of a query sent to a chain by an Account
+    #[returns(QueryResult)]
+    QueryResult {
         chain: String,
         account_id: AccountId,
+        query_id: String,
+    },
+    // Get the query results stored for an Account
+    #[returns(QueryResultsResponse)]
+    QueryResults {
+        account_id: AccountId,
+        start_after: Option<(String, String)>,
+        limit: Option<u8>,
     },
     // get the channels
     #[returns(ListChannelsResponse)]
     ListChannels {},
+    // Get the lifetime of packets sent to a host
+    #[returns(HostTimeoutResponse)]
+    HostTimeout { host_chain: String },
+    // Get the retries performed for the packets of an Account
+    #[returns(PacketRetriesResponse)]
+    PacketRetries {
+        account_id: AccountId,
+        start_after: Option<(String, u64)>,
+        limit: Option<u8>,
+    },
+    // Get the packets of an Account that timed out
+    #[returns(FailedPacketsResponse)]
+    FailedPackets {
+        account_id: AccountId,
+        start_after: Option<(String, u64)>,
+        limit: Option<u8>,
+    },
+    // Get the time since the balances of the remote accounts were last updated
+    #[returns(BalanceStalenessResponse)]
+    BalanceStaleness {
+        start_after: Option<(String, AccountId)>,
+        limit: Option<u8>,
+    },
+    // Get the last synced balances of an Account on each host chain
+    #[returns(RemoteBalancesResponse)]
+    RemoteBalances { account_id: AccountId },
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -153,11 +250,86 @@ pub struct ListChannelsResponse {
     pub channels: Vec<(String, String)>,
 }
 
+/// Result of a [`HostAction::Query`] returned by the host
+#[cosmwasm_schema::cw_serde]
+pub struct QueryResult {
+    pub channel_id: String,
+    pub query_id: String,
+    /// time at which the result was received
+    pub received_at: Timestamp,
+    pub response: StdAck,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct QueryResultsResponse {
+    pub results: Vec<QueryResult>,
+}
+
+/// Packet that timed out before it was received by the host,
+/// or errored packet whose retry could not be resent as its channel was removed
+#[cosmwasm_schema::cw_serde]
+pub struct FailedPacket {
+    pub channel_id: String,
+    pub sequence: u64,
+    /// time at which the timeout was processed
+    pub timed_out_at: Timestamp,
+    pub action: HostAction,
+    pub callback_info: Option<CallbackInfo>,
+}
+
 #[cosmwasm_schema::cw_serde]
-pub struct LatestQueryResponse {
+pub struct FailedPacketsResponse {
+    pub packets: Vec<FailedPacket>,
+}
+
+/// Retry of a packet the host answered with an error
+#[cosmwasm_schema::cw_serde]
+pub struct PacketRetry {
+    pub channel_id: String,
+    /// sequence of the errored packet
+    pub sequence: u64,
+    /// number of this retry, starting at 1
+    pub retry: u8,
+    pub retries_left: u8,
+    /// error returned by the host
+    pub error: String,
+    pub retried_at: Timestamp,
+    /// time after which the packet can be resent, see [`ExecuteMsg::ResendRetries`]
+    pub resend_at: Timestamp,
+    pub action: HostAction,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PacketRetriesResponse {
+    pub retries: Vec<PacketRetry>,
+}
+
+/// Staleness of the balances of a remote account
+#[cosmwasm_schema::cw_serde]
+pub struct BalanceStaleness {
+    pub channel_id: String,
+    pub account_id: AccountId,
     /// last block balance was updated (0 is never)
     pub last_update_time: Timestamp,
-    pub response: StdAck,
+    /// seconds since the last update, none if the balances were never synced
+    pub seconds_since_update: Option<u64>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct BalanceStalenessResponse {
+    pub accounts: Vec<BalanceStaleness>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct RemoteBalancesResponse {
+    /// (host_chain, balances) of the Account's remote accounts
+    pub balances: Vec<(String, Vec<Coin>)>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct HostTimeoutResponse {
+    /// packet lifetime in seconds
+    pub timeout: u64,
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -178,6 +350,7 @@ pub struct AccountInfo {
     /// the channel and making a query and in that time it is empty
     pub remote_addr: Option<String>,
     pub remote_balance: Vec<Coin>,
+    pub remote_account: Option<RemoteAccount>,
 }
 
 impl AccountInfo {
@@ -189,6 +362,7 @@ impl AccountInfo {
             last_update_time: input.last_update_time,
             remote_addr: input.remote_addr,
             remote_balance: input.remote_balance,
+            remote_account: input.remote_account,
         }
     }
 }
@@ -201,6 +375,7 @@ pub struct AccountResponse {
     /// the channel and making a query and in that time it is empty
     pub remote_addr: Option<String>,
     pub remote_balance: Vec<Coin>,
+    pub remote_account: Option<RemoteAccount>,
 }
 
 impl From<AccountData> for AccountResponse {
@@ -209,6 +384,7 @@ impl From<AccountData> for AccountResponse {
             last_update_time: input.last_update_time,
             remote_addr: input.remote_addr,
             remote_balance: input.remote_balance,
+            remote_account: input.remote_account,
         }
     }
 }
@@ -229,6 +405,10 @@ mod tests {
             last_update_time: Default::default(),
             remote_addr: None,
             remote_balance: vec![],
+            remote_account: Some(RemoteAccount {
+                manager: "remote_manager".to_string(),
+                proxy: "remote_proxy".to_string(),
+            }),
         };
 
         let expected = AccountInfo {
@@ -237,6 +417,7 @@ mod tests {
             last_update_time: input.last_update_time,
             remote_addr: input.clone().remote_addr,
             remote_balance: input.clone().remote_balance,
+            remote_account: input.clone().remote_account,
         };
 
         let actual = AccountInfo::convert(channel_id, TEST_ACCOUNT_ID, input);
@@ -252,6 +433,7 @@ mod tests {
             last_update_time: input.last_update_time,
             remote_addr: input.clone().remote_addr,
             remote_balance: input.clone().remote_balance,
+            remote_account: input.clone().remote_account,
         };
 
         let actual = AccountResponse::from(input);
@@ -285,4 +467,22 @@ mod tests {
             )
         });
     }
+
+    #[test]
+    fn test_callback_info_to_timeout_msg() {
+        let callback_info = CallbackInfo {
+            id: "15".to_string(),
+            receiver: "receiver".to_string(),
+        };
+
+        let actual = callback_info.to_timeout_msg().unwrap();
+
+        let expected: CosmosMsg = IbcResponseMsg {
+            id: "15".to_string(),
+            msg: IbcCallbackOutcome::Timeout {},
+        }
+        .into_cosmos_account_msg("receiver")
+        .unwrap();
+        assert_that!(actual).is_equal_to(expected);
+    }
 }
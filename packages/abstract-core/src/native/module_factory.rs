@@ -6,7 +6,10 @@
 //! This contract is instantiated by Abstract and only used internally. Adding or upgrading modules is done using the [`crate::manager::ExecuteMsg`] endpoint.  
 pub mod state {
     use crate::{
-        objects::module::{Module, ModuleInfo},
+        objects::{
+            module::{Module, ModuleInfo},
+            AccountId,
+        },
         version_control::AccountBase,
     };
     use cosmwasm_std::{Addr, Binary};
@@ -29,10 +32,15 @@ pub mod state {
     pub const CONFIG: Item<Config> = Item::new("\u{0}{5}config");
     pub const CONTEXT: Item<Context> = Item::new("\u{0}{7}context");
     pub const MODULE_INIT_BINARIES: Map<&ModuleInfo, Binary> = Map::new("module_init_binaries");
+    /// Number of times a module was instantiated on an Account, part of the instantiate2 salt
+    pub const INSTALL_NONCES: Map<(AccountId, &str), u32> = Map::new("install_nonces");
 }
 
 use crate::{
-    objects::module::{Module, ModuleInfo},
+    objects::{
+        module::{Module, ModuleInfo},
+        AccountId,
+    },
     version_control::AccountBase,
 };
 use cosmwasm_schema::QueryResponses;
@@ -82,6 +90,13 @@ pub enum QueryMsg {
     /// Returns [`ContextResponse`]
     #[returns(ContextResponse)]
     Context {},
+    /// Get the address a module will have once installed on the Account.
+    /// Returns [`ModuleAddressResponse`]
+    #[returns(ModuleAddressResponse)]
+    PredictModuleAddress {
+        account_id: AccountId,
+        module: ModuleInfo,
+    },
 }
 
 /// Module factory config response
@@ -97,6 +112,13 @@ pub struct ContextResponse {
     pub module: Option<Module>,
 }
 
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleAddressResponse {
+    pub address: Addr,
+    /// Number of previous installs of the module on the Account, part of the salt of the address
+    pub install_nonce: u32,
+}
+
 /// We currently take no arguments for migrations
 #[cosmwasm_schema::cw_serde]
 pub struct MigrateMsg {}
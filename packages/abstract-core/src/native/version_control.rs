@@ -15,22 +15,38 @@ pub type ModuleMapEntry = (ModuleInfo, ModuleReference);
 pub struct Config {
     pub allow_direct_module_registration_and_updates: bool,
     pub namespace_registration_fee: cosmwasm_std::Coin,
+    /// Maximum number of namespaces an Account can claim
+    #[serde(default = "default_namespace_limit")]
+    pub namespace_limit: u32,
+    /// Seconds a namespace claim lasts before it has to be renewed by paying the registration fee again.
+    /// Claims don't expire if zero.
+    #[serde(default)]
+    pub namespace_registration_period: u64,
+}
+
+/// Configs stored before the namespace limit was configurable allowed a single namespace per Account.
+fn default_namespace_limit() -> u32 {
+    1
 }
 
 pub mod state {
 
+    use cosmwasm_std::Timestamp;
     use cw_controllers::Admin;
     use cw_storage_plus::{Item, Map};
 
     use crate::objects::{
         account_id::AccountId,
         common_namespace::ADMIN_NAMESPACE,
+        fee::FeeSplit,
         module::{ModuleInfo, ModuleMetadata, Monetization},
         module_reference::ModuleReference,
         namespace::Namespace,
     };
 
-    use super::{AccountBase, Config};
+    use super::{
+        AccountBase, Config, ModuleDeprecation, ModuleReview, NamespaceOffer, ReviewerSet,
+    };
 
     pub const ADMIN: Admin = Admin::new(ADMIN_NAMESPACE);
     pub const FACTORY: Admin = Admin::new("fac");
@@ -39,17 +55,34 @@ pub mod state {
 
     // Modules waiting for approvals
     pub const PENDING_MODULES: Map<&ModuleInfo, ModuleReference> = Map::new("pendm");
+    // Reviews of pending modules, removed once the module is approved or rejected
+    pub const PENDING_REVIEWS: Map<&ModuleInfo, Vec<ModuleReview>> = Map::new("pend_reviews");
+    /// Committee that approves or rejects pending modules instead of the admin
+    pub const REVIEWERS: Item<ReviewerSet> = Item::new("reviewers");
     // We can iterate over the map giving just the prefix to get all the versions
     pub const REGISTERED_MODULES: Map<&ModuleInfo, ModuleReference> = Map::new("lib");
     // Yanked Modules
     pub const YANKED_MODULES: Map<&ModuleInfo, ModuleReference> = Map::new("yknd");
     // Modules Fee
     pub const MODULE_MONETIZATION: Map<(&Namespace, &str), Monetization> = Map::new("mod_m");
+    // Recipients of the module fees, the namespace owner receives the fees if none are set
+    pub const MODULE_REVENUE_SPLIT: Map<(&Namespace, &str), FeeSplit> = Map::new("mod_split");
     // Modules Metadata
-    pub const MODULE_METADATA: Map<&ModuleInfo, ModuleMetadata> = Map::new("mod_meta");
+    pub const MODULE_METADATA: Map<&ModuleInfo, ModuleMetadata> = Map::new("mod_metadata");
+    // Deprecated Modules, these can still be installed
+    pub const DEPRECATED_MODULES: Map<&ModuleInfo, ModuleDeprecation> = Map::new("deprecated");
 
     /// Maps Account ID to the address of its core contracts
     pub const ACCOUNT_ADDRESSES: Map<AccountId, AccountBase> = Map::new("accs");
+
+    /// Maps the ID of a sub-account to the ID of its parent Account
+    pub const ACCOUNT_PARENTS: Map<AccountId, AccountId> = Map::new("acc_parents");
+
+    /// Time after which a namespace claim expires, set if claims expire
+    pub const NAMESPACE_EXPIRATIONS: Map<&Namespace, Timestamp> = Map::new("ns_expiry");
+
+    /// Open offers to sell a namespace, removed when the namespace changes owner
+    pub const NAMESPACE_OFFERS: Map<&Namespace, NamespaceOffer> = Map::new("ns_offers");
 }
 
 /// Sub indexes for namespaces.
@@ -74,13 +107,14 @@ pub fn namespaces_info<'a>() -> IndexedMap<'a, &'a Namespace, AccountId, Namespa
 
 use crate::objects::{
     account_id::AccountId,
+    fee::FeeSplit,
     module::{Module, ModuleInfo, ModuleMetadata, ModuleStatus, ModuleVersion, Monetization},
     module_reference::ModuleReference,
     namespace::Namespace,
 };
 use cosmwasm_schema::QueryResponses;
-use cosmwasm_std::{Addr, Coin, Order, Storage};
-use state::MODULE_MONETIZATION;
+use cosmwasm_std::{Addr, Coin, Order, Storage, Timestamp};
+use state::{DEPRECATED_MODULES, MODULE_MONETIZATION, MODULE_REVENUE_SPLIT};
 
 use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
 
@@ -101,6 +135,10 @@ pub struct InstantiateMsg {
     /// SHOULD ONLY BE `true` FOR TESTING
     pub allow_direct_module_registration_and_updates: Option<bool>,
     pub namespace_registration_fee: Option<Coin>,
+    /// Maximum number of namespaces an Account can claim, defaults to 1
+    pub namespace_limit: Option<u32>,
+    /// Seconds a namespace claim lasts before it has to be renewed, claims don't expire by default
+    pub namespace_registration_period: Option<u64>,
 }
 
 /// Version Control Execute Msg
@@ -113,17 +151,28 @@ pub enum ExecuteMsg {
     /// Yank a version of a module so that it may not be installed
     /// Only callable by Admin
     YankModule { module: ModuleInfo },
+    /// Deprecate a version of a module, it can still be installed
+    /// Only callable by namespace admin
+    DeprecateModule {
+        module: ModuleInfo,
+        /// Module to migrate to instead
+        successor: Option<ModuleInfo>,
+        reason: String,
+    },
     /// Propose new modules to the version registry
     /// Namespaces need to be claimed by the Account before proposing modules
     /// Once proposed, the modules need to be approved by the Admin via [`ExecuteMsg::ApproveOrRejectModules`]
+    /// or by the reviewers via [`ExecuteMsg::ReviewModule`] when they are set
     ProposeModules { modules: Vec<ModuleMapEntry> },
     /// Sets the monetization configuration for a module.
     /// The version doesn't matter here, but we keep it for compatibility purposes
     /// Only callable by namespace admin
+    /// `revenue_split` sets the recipients of the collected fees, the namespace owner receives them if it is `None`
     SetModuleMonetization {
         module_name: String,
         namespace: Namespace,
         monetization: Monetization,
+        revenue_split: Option<FeeSplit>,
     },
     /// Sets the metadata configuration for a module.
     /// Only callable by namespace admin
@@ -135,15 +184,60 @@ pub enum ExecuteMsg {
     /// Approve or reject modules
     /// This takes the modules in the pending_modules map and
     /// moves them to the registered_modules map or yanked_modules map
+    /// Only callable by Admin when no reviewers are set
     ApproveOrRejectModules {
         approves: Vec<ModuleInfo>,
         rejects: Vec<ModuleInfo>,
     },
+    /// Approve or reject a pending module as a reviewer
+    /// The module is approved once `threshold` reviewers approve it
+    /// and rejected once too many reviewers reject it to reach the threshold
+    ReviewModule {
+        module: ModuleInfo,
+        approve: bool,
+        comment: Option<String>,
+    },
+    /// Set the reviewers of pending modules and the number of approvals a module needs
+    /// Removes the reviewers if `reviewers` is empty
+    /// Only callable by Admin
+    UpdateReviewers {
+        reviewers: Vec<String>,
+        threshold: u32,
+    },
     /// Claim namespaces
+    /// An expired namespace can be claimed by another Account, its modules are yanked
     ClaimNamespace {
         account_id: AccountId,
         namespace: String,
     },
+    /// Extend the claim of a namespace by paying the namespace registration fee
+    /// Only callable by the owner of the namespace's Account
+    #[cfg_attr(feature = "interface", payable)]
+    RenewNamespace { namespace: String },
+    /// Transfer a namespace and its registered modules to another Account
+    /// Only callable by the owner of the namespace's Account
+    TransferNamespace {
+        namespace: String,
+        to_account: AccountId,
+    },
+    /// Offer a namespace for sale at the given price
+    /// Any Account can buy it if `to_account` is not set
+    /// Only callable by the owner of the namespace's Account
+    OfferNamespace {
+        namespace: String,
+        price: Coin,
+        to_account: Option<AccountId>,
+    },
+    /// Withdraw the open offer for a namespace
+    /// Only callable by the owner of the namespace's Account
+    CancelNamespaceOffer { namespace: String },
+    /// Buy an offered namespace for an Account by paying the offer price
+    /// Only callable by the owner of the buying Account
+    #[cfg_attr(feature = "interface", payable)]
+    AcceptNamespaceOffer {
+        namespace: String,
+        account_id: AccountId,
+    },
     /// Remove namespace claims
     /// Only admin or root user can call this
     RemoveNamespaces { namespaces: Vec<String> },
@@ -152,13 +246,21 @@ pub enum ExecuteMsg {
     AddAccount {
         account_id: AccountId,
         account_base: AccountBase,
+        /// The parent Account if the new Account is a sub-account
+        parent_account_id: Option<AccountId>,
     },
+    /// Removes the parent of a sub-account once it is no longer governed by it.
+    /// Only callable by the manager of the sub-account.
+    RemoveAccountParent { account_id: AccountId },
     /// Updates configuration of the VC contract. Available Config :
     /// 1. Whether the contract allows direct module registration
     /// 2. the number of namespaces an Account can claim
     UpdateConfig {
         allow_direct_module_registration_and_updates: Option<bool>,
         namespace_registration_fee: Option<Coin>,
+        /// Can not be decreased
+        namespace_limit: Option<u32>,
+        namespace_registration_period: Option<u64>,
     },
     /// Sets a new Factory
     SetFactory { new_factory: String },
@@ -172,6 +274,8 @@ pub struct ModuleFilter {
     pub name: Option<String>,
     pub version: Option<String>,
     pub status: Option<ModuleStatus>,
+    /// Only return modules tagged with this tag in their [`ModuleMetadata`]
+    pub tag: Option<String>,
 }
 
 /// Version Control Query Msg
@@ -197,6 +301,10 @@ pub enum QueryMsg {
     /// Returns [`NamespaceResponse`]
     #[returns(NamespaceResponse)]
     Namespace { namespace: Namespace },
+    /// Queries the open offer for a namespace
+    /// Returns [`NamespaceOfferResponse`]
+    #[returns(NamespaceOfferResponse)]
+    NamespaceOffer { namespace: Namespace },
     /// Returns [`ConfigResponse`]
     #[returns(ConfigResponse)]
     Config {},
@@ -213,6 +321,15 @@ pub enum QueryMsg {
         start_after: Option<String>,
         limit: Option<u8>,
     },
+    /// Returns [`PendingModulesResponse`]
+    #[returns(PendingModulesResponse)]
+    PendingModules {
+        start_after: Option<ModuleInfo>,
+        limit: Option<u8>,
+    },
+    /// Returns [`ReviewersResponse`]
+    #[returns(ReviewersResponse)]
+    Reviewers {},
 }
 
 #[cosmwasm_schema::cw_serde]
@@ -236,6 +353,17 @@ pub struct ModuleResponse {
 pub struct ModuleConfiguration {
     pub monetization: Monetization,
     pub metadata: ModuleMetadata,
+    /// Recipients of the module fees, `None` if the namespace owner receives them
+    pub revenue_split: Option<FeeSplit>,
+    /// Set if the module version is deprecated
+    pub deprecation: Option<ModuleDeprecation>,
+}
+
+/// Deprecation of a module version with an optional successor to migrate to
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleDeprecation {
+    pub successor: Option<ModuleInfo>,
+    pub reason: String,
 }
 
 impl ModuleConfiguration {
@@ -243,6 +371,8 @@ impl ModuleConfiguration {
         Self {
             monetization,
             metadata,
+            revenue_split: None,
+            deprecation: None,
         }
     }
 
@@ -280,7 +410,7 @@ impl ModuleConfiguration {
         }
 
         // Else, no metadata
-        "".to_string()
+        ModuleMetadata::default()
     }
 
     pub fn from_storage(storage: &dyn Storage, module: &ModuleInfo) -> Self {
@@ -290,13 +420,77 @@ impl ModuleConfiguration {
 
         let metadata = ModuleConfiguration::metadata_from_storage(storage, module);
 
+        let revenue_split = MODULE_REVENUE_SPLIT
+            .may_load(storage, (&module.namespace, &module.name))
+            .unwrap_or_default();
+
+        let deprecation = DEPRECATED_MODULES
+            .may_load(storage, module)
+            .unwrap_or_default();
+
         Self {
             monetization,
             metadata,
+            revenue_split,
+            deprecation,
         }
     }
 }
 
+/// Reviewers of pending modules
+#[cosmwasm_schema::cw_serde]
+pub struct ReviewerSet {
+    pub reviewers: Vec<Addr>,
+    /// Number of approvals a module needs to be registered
+    pub threshold: u32,
+}
+
+impl ReviewerSet {
+    /// Counts the approvals and rejections of the current reviewers
+    pub fn tally(&self, reviews: &[ModuleReview]) -> (u32, u32) {
+        reviews
+            .iter()
+            .filter(|review| self.reviewers.contains(&review.reviewer))
+            .fold((0, 0), |(approvals, rejections), review| {
+                if review.approve {
+                    (approvals + 1, rejections)
+                } else {
+                    (approvals, rejections + 1)
+                }
+            })
+    }
+}
+
+/// Review of a pending module
+#[cosmwasm_schema::cw_serde]
+pub struct ModuleReview {
+    pub reviewer: Addr,
+    pub approve: bool,
+    pub comment: Option<String>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PendingModuleResponse {
+    pub module: Module,
+    pub reviews: Vec<ModuleReview>,
+    /// Approvals of the current reviewers
+    pub approvals: u32,
+    /// Rejections of the current reviewers
+    pub rejections: u32,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PendingModulesResponse {
+    pub modules: Vec<PendingModuleResponse>,
+    /// Number of approvals a module needs, `None` if only the admin approves modules
+    pub threshold: Option<u32>,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct ReviewersResponse {
+    pub reviewers: Option<ReviewerSet>,
+}
+
 #[cosmwasm_schema::cw_serde]
 pub struct ModulesListResponse {
     pub modules: Vec<ModuleResponse>,
@@ -306,6 +500,23 @@ pub struct ModulesListResponse {
 pub struct NamespaceResponse {
     pub account_id: AccountId,
     pub account_base: AccountBase,
+    /// Time after which the claim expires, `None` if it doesn't expire
+    pub expires_at: Option<Timestamp>,
+}
+
+/// An offer to sell a namespace to another Account
+#[cosmwasm_schema::cw_serde]
+pub struct NamespaceOffer {
+    /// Account that owned the namespace when the offer was made
+    pub seller: AccountId,
+    /// Account that can accept the offer, any Account can if `None`
+    pub buyer: Option<AccountId>,
+    pub price: Coin,
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct NamespaceOfferResponse {
+    pub offer: Option<NamespaceOffer>,
 }
 
 #[cosmwasm_schema::cw_serde]
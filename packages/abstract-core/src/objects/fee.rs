@@ -1,5 +1,5 @@
 use crate::{error::AbstractError, AbstractResult};
-use cosmwasm_std::{Addr, Api, Coin, CosmosMsg, Decimal, MessageInfo, Uint128};
+use cosmwasm_std::{Addr, Api, BankMsg, Coin, CosmosMsg, Decimal, MessageInfo, Uint128};
 use cw_asset::Asset;
 
 /// A wrapper around Fee to help handle fee logic.
@@ -43,6 +43,64 @@ impl UsageFee {
     }
 }
 
+/// Split of a fee across multiple recipients.
+/// The shares of the recipients sum to 100%.
+#[cosmwasm_schema::cw_serde]
+pub struct FeeSplit {
+    recipients: Vec<UsageFee>,
+}
+
+impl FeeSplit {
+    pub fn new(recipients: Vec<UsageFee>) -> AbstractResult<Self> {
+        let total = recipients
+            .iter()
+            .try_fold(Decimal::zero(), |total, fee| total.checked_add(fee.share()))?;
+        if total != Decimal::percent(100) {
+            return Err(AbstractError::Fee(format!(
+                "fee split shares must sum to 1, got {total}"
+            )));
+        }
+        Ok(FeeSplit { recipients })
+    }
+    /// Validates the recipients and shares of an unchecked split
+    pub fn check(self, api: &dyn Api) -> AbstractResult<Self> {
+        let recipients = self
+            .recipients
+            .into_iter()
+            .map(|fee| UsageFee::new(api, fee.share(), fee.recipient()))
+            .collect::<AbstractResult<Vec<_>>>()?;
+        FeeSplit::new(recipients)
+    }
+    pub fn recipients(&self) -> &[UsageFee] {
+        &self.recipients
+    }
+    /// Messages that send each recipient its share of the fee.
+    /// Rounding leftovers are sent to the first recipient.
+    pub fn msgs(&self, fee: Coin) -> Vec<CosmosMsg> {
+        let mut amounts: Vec<Uint128> = self
+            .recipients
+            .iter()
+            .map(|recipient| recipient.compute(fee.amount))
+            .collect();
+        let distributed: Uint128 = amounts.iter().sum();
+        if let Some(first) = amounts.first_mut() {
+            *first += fee.amount - distributed;
+        }
+
+        self.recipients
+            .iter()
+            .zip(amounts)
+            .filter(|(_, amount)| !amount.is_zero())
+            .map(|(recipient, amount)| {
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: recipient.recipient().into_string(),
+                    amount: vec![Coin::new(amount.u128(), &fee.denom)],
+                })
+            })
+            .collect()
+    }
+}
+
 /// A wrapper around Decimal to help handle fractional fees.
 #[cosmwasm_schema::cw_serde]
 pub struct Fee {
@@ -193,6 +251,55 @@ mod tests {
             assert_eq!(msg, asset.transfer_msg(recipient).unwrap(),);
         }
     }
+    mod fee_split {
+        use cosmwasm_std::{coin, testing::MockApi};
+
+        use super::*;
+
+        fn usage_fee(percent: u64, recipient: &str) -> UsageFee {
+            UsageFee::new(&MockApi::default(), Decimal::percent(percent), recipient).unwrap()
+        }
+
+        #[test]
+        fn test_fee_split_must_sum_to_100() {
+            let split = FeeSplit::new(vec![usage_fee(80, "publisher"), usage_fee(15, "author")]);
+            assert!(split.is_err());
+            let split = FeeSplit::new(vec![]);
+            assert!(split.is_err());
+            let split = FeeSplit::new(vec![
+                usage_fee(80, "publisher"),
+                usage_fee(15, "author"),
+                usage_fee(5, "platform"),
+            ]);
+            assert!(split.is_ok());
+        }
+
+        #[test]
+        fn test_fee_split_msgs() {
+            let split = FeeSplit::new(vec![
+                usage_fee(80, "publisher"),
+                usage_fee(15, "author"),
+                usage_fee(5, "platform"),
+            ])
+            .unwrap();
+            let msgs = split.msgs(coin(1001, "ujunox"));
+            let send = |to: &str, amount: u128| {
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: to.to_string(),
+                    amount: vec![coin(amount, "ujunox")],
+                })
+            };
+            // rounding leftovers go to the first recipient
+            assert_eq!(
+                msgs,
+                vec![
+                    send("publisher", 801),
+                    send("author", 150),
+                    send("platform", 50)
+                ]
+            );
+        }
+    }
     mod transfer_fee {
         use cosmwasm_std::{
             coin, coins,
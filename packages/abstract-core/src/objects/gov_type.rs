@@ -26,6 +26,20 @@ pub enum GovernanceDetails<T: AddressLike> {
         /// Governance type used for doing extra off-chain queries depending on the type.
         governance_type: String,
     },
+    /// A set of weighted members that approve actions through proposals on the manager
+    Multisig {
+        /// The members and their voting weight
+        members: Vec<(T, u64)>,
+        /// The total weight required for a proposal to pass
+        threshold: u64,
+    },
+    /// Account owned by another Abstract Account
+    SubAccount {
+        /// The manager of the parent Account
+        manager: T,
+        /// The proxy of the parent Account
+        proxy: T,
+    },
 }
 
 impl GovernanceDetails<String> {
@@ -77,18 +91,81 @@ impl GovernanceDetails<String> {
                     governance_type,
                 })
             }
+            GovernanceDetails::Multisig { members, threshold } => {
+                if members.is_empty() {
+                    return Err(AbstractError::FormattingError {
+                        object: "multisig members".into(),
+                        expected: "at least one member".into(),
+                        actual: "0".into(),
+                    });
+                }
+                let mut verified_members: Vec<(Addr, u64)> = Vec::with_capacity(members.len());
+                for (member, weight) in members {
+                    let addr = api.addr_validate(&member)?;
+                    if weight == 0 {
+                        return Err(AbstractError::FormattingError {
+                            object: "multisig member weight".into(),
+                            expected: "a non-zero weight".into(),
+                            actual: format!("{addr}: {weight}"),
+                        });
+                    }
+                    if verified_members.iter().any(|(m, _)| *m == addr) {
+                        return Err(AbstractError::Assert(format!(
+                            "multisig member {addr} is listed more than once"
+                        )));
+                    }
+                    verified_members.push((addr, weight));
+                }
+                let total_weight = verified_members
+                    .iter()
+                    .try_fold(0u64, |total, (_, weight)| total.checked_add(*weight))
+                    .ok_or_else(|| {
+                        AbstractError::Assert("multisig total weight overflows".to_string())
+                    })?;
+                if threshold == 0 || threshold > total_weight {
+                    return Err(AbstractError::FormattingError {
+                        object: "multisig threshold".into(),
+                        expected: format!("between 1 and {total_weight}"),
+                        actual: threshold.to_string(),
+                    });
+                }
+                Ok(GovernanceDetails::Multisig {
+                    members: verified_members,
+                    threshold,
+                })
+            }
+            GovernanceDetails::SubAccount { manager, proxy } => {
+                let manager = api.addr_validate(&manager)?;
+                let proxy = api.addr_validate(&proxy)?;
+                Ok(GovernanceDetails::SubAccount { manager, proxy })
+            }
         }
     }
 }
 
 impl GovernanceDetails<Addr> {
-    /// Get the owner address from the governance details
-    pub fn owner_address(&self) -> Addr {
+    /// Get the owner address from the governance details.
+    /// Returns `None` for governance types that are enforced by the manager itself (i.e. multisig).
+    pub fn owner_address(&self) -> Option<Addr> {
         match self {
-            GovernanceDetails::Monarchy { monarch } => monarch.clone(),
+            GovernanceDetails::Monarchy { monarch } => Some(monarch.clone()),
             GovernanceDetails::External {
                 governance_address, ..
-            } => governance_address.clone(),
+            } => Some(governance_address.clone()),
+            GovernanceDetails::Multisig { .. } => None,
+            GovernanceDetails::SubAccount { manager, .. } => Some(manager.clone()),
+        }
+    }
+
+    /// Get the voting weight of a multisig member. Returns `None` if the address is not a member
+    /// or the governance is not a multisig.
+    pub fn member_weight(&self, address: &Addr) -> Option<u64> {
+        match self {
+            GovernanceDetails::Multisig { members, .. } => members
+                .iter()
+                .find(|(member, _)| member == address)
+                .map(|(_, weight)| *weight),
+            _ => None,
         }
     }
 }
@@ -106,6 +183,17 @@ impl From<GovernanceDetails<Addr>> for GovernanceDetails<String> {
                 governance_address: governance_address.to_string(),
                 governance_type,
             },
+            GovernanceDetails::Multisig { members, threshold } => GovernanceDetails::Multisig {
+                members: members
+                    .into_iter()
+                    .map(|(member, weight)| (member.to_string(), weight))
+                    .collect(),
+                threshold,
+            },
+            GovernanceDetails::SubAccount { manager, proxy } => GovernanceDetails::SubAccount {
+                manager: manager.to_string(),
+                proxy: proxy.to_string(),
+            },
         }
     }
 }
@@ -117,6 +205,8 @@ impl<T: AddressLike> ToString for GovernanceDetails<T> {
             GovernanceDetails::External {
                 governance_type, ..
             } => governance_type.to_owned(),
+            GovernanceDetails::Multisig { .. } => "multisig".to_string(),
+            GovernanceDetails::SubAccount { .. } => "sub-account".to_string(),
         }
     }
 }
@@ -173,5 +263,76 @@ mod test {
             governance_type: "gov_type".to_string(),
         };
         assert_that!(gov.verify(&deps.api)).is_err();
+
+        let gov = GovernanceDetails::SubAccount {
+            manager: "manager".to_string(),
+            proxy: "proxy".to_string(),
+        };
+        assert_that!(gov.verify(&deps.api)).is_ok();
+
+        let gov = GovernanceDetails::SubAccount {
+            manager: "NOT_OK".to_string(),
+            proxy: "proxy".to_string(),
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
+    }
+
+    #[test]
+    fn test_verify_multisig() {
+        let deps = mock_dependencies();
+        let gov = GovernanceDetails::Multisig {
+            members: vec![("alice".to_string(), 1), ("bob".to_string(), 2)],
+            threshold: 2,
+        };
+        assert_that!(gov.verify(&deps.api)).is_ok();
+
+        // no members
+        let gov = GovernanceDetails::<String>::Multisig {
+            members: vec![],
+            threshold: 1,
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
+
+        // duplicate member
+        let gov = GovernanceDetails::Multisig {
+            members: vec![("alice".to_string(), 1), ("alice".to_string(), 1)],
+            threshold: 1,
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
+
+        // zero weight
+        let gov = GovernanceDetails::Multisig {
+            members: vec![("alice".to_string(), 0)],
+            threshold: 1,
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
+
+        // threshold too high
+        let gov = GovernanceDetails::Multisig {
+            members: vec![("alice".to_string(), 1), ("bob".to_string(), 2)],
+            threshold: 4,
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
+
+        // zero threshold
+        let gov = GovernanceDetails::Multisig {
+            members: vec![("alice".to_string(), 1)],
+            threshold: 0,
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
+
+        // invalid addr
+        let gov = GovernanceDetails::Multisig {
+            members: vec![("NOT_OK".to_string(), 1)],
+            threshold: 1,
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
+
+        // total weight overflows
+        let gov = GovernanceDetails::Multisig {
+            members: vec![("alice".to_string(), u64::MAX), ("bob".to_string(), 1)],
+            threshold: 1,
+        };
+        assert_that!(gov.verify(&deps.api)).is_err();
     }
 }
@@ -2,10 +2,13 @@ use super::module_reference::ModuleReference;
 use crate::objects::fee::FixedFee;
 use crate::objects::module_version::MODULE;
 use crate::objects::namespace::Namespace;
+use crate::objects::validation::{
+    validate_description, validate_link, validate_name, ValidationError,
+};
 use crate::{error::AbstractError, AbstractResult};
-use cosmwasm_std::{ensure_eq, to_binary, Addr, Binary, QuerierWrapper, StdError, StdResult};
+use cosmwasm_std::{ensure_eq, to_binary, Addr, Binary, Coin, QuerierWrapper, StdError, StdResult};
 use cw2::ContractVersion;
-use cw_semver::Version;
+use cw_semver::{Version, VersionReq};
 use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
 use std::fmt::{self, Display};
 
@@ -111,7 +114,7 @@ impl ModuleInfo {
 
     pub fn assert_version_variant(&self) -> AbstractResult<()> {
         match &self.version {
-            ModuleVersion::Latest => Err(AbstractError::Assert(
+            ModuleVersion::Latest | ModuleVersion::Range(_) => Err(AbstractError::Assert(
                 "Module version must be set to a specific version".into(),
             )),
             ModuleVersion::Version(ver) => {
@@ -140,11 +143,7 @@ impl<'a> PrimaryKey<'a> for &ModuleInfo {
     fn key(&self) -> Vec<cw_storage_plus::Key> {
         let mut keys = self.namespace.key();
         keys.extend(self.name.key());
-        let temp = match &self.version {
-            ModuleVersion::Latest => "latest".key(),
-            ModuleVersion::Version(ver) => ver.key(),
-        };
-        keys.extend(temp);
+        keys.extend(self.version.prefix());
         keys
     }
 }
@@ -160,11 +159,14 @@ impl<'a> Prefixer<'a> for &ModuleInfo {
 
 impl<'a> Prefixer<'a> for ModuleVersion {
     fn prefix(&self) -> Vec<Key> {
-        let self_as_bytes = match &self {
-            ModuleVersion::Latest => "latest".as_bytes(),
-            ModuleVersion::Version(ver) => ver.as_bytes(),
-        };
-        vec![Key::Ref(self_as_bytes)]
+        match &self {
+            ModuleVersion::Latest => vec![Key::Ref("latest".as_bytes())],
+            ModuleVersion::Version(ver) => vec![Key::Ref(ver.as_bytes())],
+            // ranges are marked so they never match a stored version
+            ModuleVersion::Range(req) => {
+                vec![Key::Ref(RANGE_KEY.as_bytes()), Key::Ref(req.as_bytes())]
+            }
+        }
     }
 }
 
@@ -196,6 +198,15 @@ impl KeyDeserialize for ModuleVersion {
 
     #[inline(always)]
     fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        let range_prefix = [
+            (RANGE_KEY.len() as u16).to_be_bytes().as_slice(),
+            RANGE_KEY.as_bytes(),
+        ]
+        .concat();
+        if let Some(req) = value.strip_prefix(range_prefix.as_slice()) {
+            let req = String::from_utf8(req.to_vec()).map_err(StdError::invalid_utf8)?;
+            return Ok(Self::Range(req));
+        }
         let val = String::from_utf8(value).map_err(StdError::invalid_utf8)?;
         if &val == "latest" {
             Ok(Self::Latest)
@@ -215,10 +226,15 @@ fn parse_length(value: &[u8]) -> StdResult<usize> {
     .into())
 }
 
+/// Key segment that precedes the requirement of a [`ModuleVersion::Range`] in storage keys
+const RANGE_KEY: &str = "range";
+
 #[cosmwasm_schema::cw_serde]
 pub enum ModuleVersion {
     Latest,
     Version(String),
+    /// Semver requirement (e.g. `^1.2`) resolved to the highest matching registered version
+    Range(String),
 }
 
 impl ModuleVersion {
@@ -230,8 +246,32 @@ impl ModuleVersion {
                 Version::parse(ver)?;
                 Ok(())
             }
+            ModuleVersion::Range(req) => {
+                VersionReq::parse(req)?;
+                Ok(())
+            }
         }
     }
+
+    /// Returns the highest of the provided versions that matches the requirement of a [`ModuleVersion::Range`].
+    /// Versions that are not valid semver are skipped.
+    pub fn highest_in_range(
+        &self,
+        versions: impl IntoIterator<Item = String>,
+    ) -> AbstractResult<Option<String>> {
+        let ModuleVersion::Range(req) = self else {
+            return Err(AbstractError::Assert(
+                "Module version must be a version range".into(),
+            ));
+        };
+        let req = VersionReq::parse(req)?;
+        Ok(versions
+            .into_iter()
+            .filter_map(|ver| Version::parse(&ver).ok().map(|parsed| (parsed, ver)))
+            .filter(|(parsed, _)| req.matches(parsed))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, ver)| ver))
+    }
 }
 
 // Do not change!!
@@ -240,6 +280,7 @@ impl Display for ModuleVersion {
         let print_str = match self {
             ModuleVersion::Latest => "latest".to_string(),
             ModuleVersion::Version(ver) => ver.to_owned(),
+            ModuleVersion::Range(req) => format!("range({req})"),
         };
         f.write_str(&print_str)
     }
@@ -269,7 +310,9 @@ impl TryInto<Version> for ModuleVersion {
 
     fn try_into(self) -> AbstractResult<Version> {
         match self {
-            ModuleVersion::Latest => Err(AbstractError::MissingVersion("module".to_string())),
+            ModuleVersion::Latest | ModuleVersion::Range(_) => {
+                Err(AbstractError::MissingVersion("module".to_string()))
+            }
             ModuleVersion::Version(ver) => {
                 let version = Version::parse(&ver)?;
                 Ok(version)
@@ -433,9 +476,55 @@ pub fn assert_module_data_validity(
 pub enum Monetization {
     None,
     InstallFee(FixedFee),
+    /// Recurring fee paid by the Account every `period_seconds` to keep using the module.
+    /// The first period is paid on install.
+    Subscription {
+        per_period: Coin,
+        period_seconds: u64,
+    },
+}
+/// Module Metadata
+#[cosmwasm_schema::cw_serde]
+#[derive(Default)]
+pub struct ModuleMetadata {
+    pub description: Option<String>,
+    /// Link to the source code of the module
+    pub repository: Option<String>,
+    /// Links to the audit reports of the module
+    pub audits: Vec<String>,
+    /// Tags used to categorize the module, e.g. `dex` or `staking`
+    pub tags: Vec<String>,
+    /// Link to the icon of the module
+    pub icon: Option<String>,
+    /// SPDX identifier of the license of the module
+    pub license: Option<String>,
+    /// Ids of the chains the module supports
+    pub chains: Vec<String>,
+}
+
+impl ModuleMetadata {
+    /// Validates the description, links and identifiers of the metadata
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate_description(&self.description)?;
+        validate_link(&self.repository)?;
+        validate_link(&self.icon)?;
+        for audit in &self.audits {
+            validate_link(&Some(audit.clone()))?;
+        }
+        if let Some(license) = &self.license {
+            validate_name(license)?;
+        }
+        self.tags
+            .iter()
+            .chain(self.chains.iter())
+            .try_for_each(|name| validate_name(name))
+    }
+
+    /// Whether the module is tagged with the provided tag
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
 }
-/// Module Metadata String
-pub type ModuleMetadata = String;
 
 //--------------------------------------------------------------------------------------------------
 // Tests
@@ -637,6 +726,26 @@ mod test {
 
             assert_eq!(items[1], ("2.0.0".to_string(), 999));
         }
+
+        #[test]
+        fn range_key_differs_from_version_key() {
+            let mut deps = mock_dependencies();
+            let version = mock_key();
+            let range = ModuleInfo {
+                version: ModuleVersion::Range("1.9.9".into()),
+                ..version.clone()
+            };
+            assert_that!((&range).joined_key()).is_not_equal_to((&version).joined_key());
+
+            let map: Map<&ModuleInfo, u64> = Map::new("map");
+            map.save(deps.as_mut().storage, &version, &42069).unwrap();
+            assert_that!(map.has(&deps.storage, &range)).is_false();
+
+            // ranges round-trip through their key
+            let key = (&range).joined_key();
+            let info = <&ModuleInfo>::from_vec(key).unwrap();
+            assert_that!(info).is_equal_to(range);
+        }
     }
 
     mod module_info {
@@ -725,6 +834,13 @@ mod test {
             let expected = "namespace:name:1.0.0".to_string();
 
             assert_that!(info.id_with_version()).is_equal_to(expected);
+
+            let range = ModuleInfo {
+                version: ModuleVersion::Range("1.0.0".into()),
+                ..info
+            };
+            assert_that!(range.id_with_version())
+                .is_equal_to("namespace:name:range(1.0.0)".to_string());
         }
     }
 
@@ -750,5 +866,82 @@ mod test {
 
             assert_that!(actual).is_err();
         }
+
+        #[test]
+        fn highest_in_range() {
+            let versions = ["1.0.0", "1.2.3", "1.10.0", "2.0.0", "invalid"].map(String::from);
+
+            let range = ModuleVersion::Range("^1.2".into());
+            assert_that!(range.highest_in_range(versions.clone()).unwrap())
+                .is_equal_to(Some("1.10.0".to_string()));
+
+            let range = ModuleVersion::Range("~1.2".into());
+            assert_that!(range.highest_in_range(versions.clone()).unwrap())
+                .is_equal_to(Some("1.2.3".to_string()));
+
+            let range = ModuleVersion::Range(">=3".into());
+            assert_that!(range.highest_in_range(versions.clone()).unwrap()).is_none();
+
+            let latest = ModuleVersion::Latest;
+            assert_that!(latest.highest_in_range(versions)).is_err();
+        }
+
+        #[test]
+        fn validate_range() {
+            assert_that!(ModuleVersion::Range(">=1.0, <2".into()).validate()).is_ok();
+            assert_that!(ModuleVersion::Range("not a range".into()).validate()).is_err();
+        }
+    }
+
+    mod module_metadata {
+        use super::*;
+
+        fn metadata() -> ModuleMetadata {
+            ModuleMetadata {
+                description: Some("Swaps assets on any dex".to_string()),
+                repository: Some("https://github.com/Abstract-OS/contracts".to_string()),
+                audits: vec!["ipfs://audit-report".to_string()],
+                tags: vec!["dex".to_string()],
+                icon: None,
+                license: Some("GPL-3.0".to_string()),
+                chains: vec!["juno-1".to_string()],
+            }
+        }
+
+        #[test]
+        fn valid() {
+            assert_that!(metadata().validate()).is_ok();
+            assert_that!(ModuleMetadata::default().validate()).is_ok();
+        }
+
+        #[test]
+        fn invalid_links() {
+            let mut invalid = metadata();
+            invalid.repository = Some("github.com/Abstract-OS/contracts".to_string());
+            assert_that!(invalid.validate()).is_err();
+
+            let mut invalid = metadata();
+            invalid.audits.push("not a link".to_string());
+            assert_that!(invalid.validate()).is_err();
+        }
+
+        #[test]
+        fn invalid_tag() {
+            let mut invalid = metadata();
+            invalid.tags.push("<script>".to_string());
+            assert_that!(invalid.validate())
+                .is_err()
+                .is_equal_to(ValidationError::TitleContainsDangerousCharacters {});
+
+            let mut invalid = metadata();
+            invalid.tags.push(String::new());
+            assert_that!(invalid.validate()).is_err();
+        }
+
+        #[test]
+        fn has_tag() {
+            assert_that!(metadata().has_tag("dex")).is_true();
+            assert_that!(metadata().has_tag("staking")).is_false();
+        }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Deps, DepsMut, Order, StdError, StdResult, Uint128};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, Order, StdError, StdResult, Uint128};
 use cw_asset::{Asset, AssetInfo};
 use cw_storage_plus::{Bound, Map};
 
@@ -9,7 +9,7 @@ use crate::AbstractResult;
 
 use super::{
     ans_host::AnsHost,
-    price_source::{AssetConversion, PriceSource, UncheckedPriceSource},
+    price_source::{remove_twap, AssetConversion, PriceSource, UncheckedPriceSource},
     AssetEntry,
 };
 
@@ -31,6 +31,8 @@ pub struct Oracle<'a> {
     /// the amount set for an asset will be added to its balance.
     /// Vec instead of HashMap because it's faster for small sets + AssetInfo does not implement `Hash`!
     asset_equivalent_cache: Vec<(AssetInfo, Vec<(AssetInfo, Uint128)>)>,
+    /// Balances held outside of the account (e.g. on remote chains) that are added to its own balances.
+    external_balances: Vec<Asset>,
 }
 
 impl<'a> Oracle<'a> {
@@ -40,6 +42,7 @@ impl<'a> Oracle<'a> {
             assets: Map::new("assets"),
             complexity: Map::new("complexity"),
             asset_equivalent_cache: Vec::new(),
+            external_balances: Vec::new(),
         }
     }
 
@@ -171,6 +174,8 @@ impl<'a> Oracle<'a> {
             let (_, complexity) = self.assets.load(deps.storage, &asset)?;
             // remove from assets
             self.assets.remove(deps.storage, &asset);
+            // remove its time-weighted average price, if any
+            remove_twap(deps.storage, &asset);
             // remove from complexity level
             self.complexity.update(deps.storage, complexity, |v| {
                 let mut v = v.unwrap_or_default();
@@ -184,9 +189,10 @@ impl<'a> Oracle<'a> {
     /// Returns the complexity of an asset
     // Complexity logic:
     // base: 0
-    // Pair: paired asset + 1
+    // Pair/Twap: paired asset + 1
     // LP: highest in pool + 1
     // ValueAs: equal asset + 1
+    // PriceFeed: quote asset + 1
     fn asset_complexity(
         &self,
         deps: Deps,
@@ -195,7 +201,7 @@ impl<'a> Oracle<'a> {
     ) -> AbstractResult<Complexity> {
         match price_source {
             PriceSource::None => Ok(0),
-            PriceSource::Pool { .. } => {
+            PriceSource::Pool { .. } | PriceSource::Twap { .. } => {
                 let compl = self.assets.load(deps.storage, &dependencies[0])?.1;
                 Ok(compl + 1)
             }
@@ -213,16 +219,35 @@ impl<'a> Oracle<'a> {
                 let (_, complexity) = self.assets.load(deps.storage, asset)?;
                 Ok(complexity + 1)
             }
+            PriceSource::PriceFeed { quote, .. } => {
+                let (_, complexity) = self.assets.load(deps.storage, quote)?;
+                Ok(complexity + 1)
+            }
+        }
+    }
+
+    /// Accumulates the pool ratios of assets priced by a [`PriceSource::Twap`].
+    /// Should be called regularly as the averages only reflect the pool ratios observed at these updates.
+    pub fn update_twaps(&self, mut deps: DepsMut, env: &Env) -> AbstractResult<()> {
+        let assets = self
+            .assets
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for (asset, (price_source, _)) in assets {
+            if let PriceSource::Twap { .. } = price_source {
+                price_source.accumulate_twap(deps.branch(), env, &asset)?;
+            }
         }
+        Ok(())
     }
 
     /// Calculates the value of a single asset by recursive conversion to underlying asset(s).
     /// Does not make use of the cache to prevent querying the same price source multiple times.
-    pub fn asset_value(&self, deps: Deps, asset: Asset) -> AbstractResult<Uint128> {
+    pub fn asset_value(&self, deps: Deps, env: &Env, asset: Asset) -> AbstractResult<Uint128> {
         // get the price source for the asset
         let (price_source, _) = self.assets.load(deps.storage, &asset.info)?;
         // get the conversions for this asset
-        let conversion_rates = price_source.conversion_rates(deps, &asset.info)?;
+        let conversion_rates = price_source.conversion_rates(deps, env, &asset.info)?;
         if conversion_rates.is_empty() {
             // no conversion rates means this is the base asset, return the amount
             return Ok(asset.amount);
@@ -232,7 +257,7 @@ impl<'a> Oracle<'a> {
         // recursively calculate the value of the underlying assets
         converted_assets
             .into_iter()
-            .map(|a| self.asset_value(deps, a))
+            .map(|a| self.asset_value(deps, env, a))
             .sum()
     }
 
@@ -245,17 +270,36 @@ impl<'a> Oracle<'a> {
     /// 2. For each asset query it's balance, get the conversion ratios associated with that asset and load its cached values.
     /// 3. Using the conversion ratio convert the balance and cached values and save the resulting values in the cache for that lower complexity asset.
     /// 4. Repeat until the base asset is reached. (complexity = 0)
-    pub fn account_value(&mut self, deps: Deps, account: &Addr) -> AbstractResult<AccountValue> {
+    pub fn account_value(
+        &mut self,
+        deps: Deps,
+        env: &Env,
+        account: &Addr,
+    ) -> AbstractResult<AccountValue> {
         // get the highest complexity
         let start_complexity = self.highest_complexity(deps)?;
         eprintln!("start complexity: {start_complexity}");
-        self.complexity_value_calculation(deps, start_complexity, account)
+        self.complexity_value_calculation(deps, env, start_complexity, account)
+    }
+
+    /// Calculates the value of the account, including the provided balances held outside of it.
+    /// Balances of assets that are not registered in the oracle are ignored.
+    pub fn account_value_with_balances(
+        &mut self,
+        deps: Deps,
+        env: &Env,
+        account: &Addr,
+        external_balances: Vec<Asset>,
+    ) -> AbstractResult<AccountValue> {
+        self.external_balances = external_balances;
+        self.account_value(deps, env, account)
     }
 
     /// Calculates the values of assets for a given complexity level
     fn complexity_value_calculation(
         &mut self,
         deps: Deps,
+        env: &Env,
         complexity: u8,
         account: &Addr,
     ) -> AbstractResult<AccountValue> {
@@ -263,7 +307,9 @@ impl<'a> Oracle<'a> {
         for asset in assets {
             let (price_source, _) = self.assets.load(deps.storage, &asset)?;
             // get the balance for this asset
-            let balance = asset.query_balance(&deps.querier, account)?;
+            let balance = asset
+                .query_balance(&deps.querier, account)?
+                .checked_add(self.external_balance(&asset)?)?;
             eprintln!("{asset}: {balance} ");
             // and the cached balances
             let mut cached_balances = self.cached_balance(&asset).unwrap_or_default();
@@ -272,7 +318,7 @@ impl<'a> Oracle<'a> {
             cached_balances.push((asset.clone(), balance));
 
             // get the conversion rates for this asset
-            let conversion_rates = price_source.conversion_rates(deps, &asset)?;
+            let conversion_rates = price_source.conversion_rates(deps, env, &asset)?;
             if conversion_rates.is_empty() {
                 // no conversion rates means this is the base asset, construct the account value and return
                 let total: u128 = cached_balances
@@ -289,7 +335,18 @@ impl<'a> Oracle<'a> {
             self.update_cache(cached_balances, conversion_rates)?;
         }
         // call recursively for the next complexity level
-        self.complexity_value_calculation(deps, complexity - 1, account)
+        self.complexity_value_calculation(deps, env, complexity - 1, account)
+    }
+
+    /// Get the external balance for an asset
+    fn external_balance(&self, asset: &AssetInfo) -> AbstractResult<Uint128> {
+        self.external_balances
+            .iter()
+            .filter(|external| &external.info == asset)
+            .try_fold(Uint128::zero(), |total, external| {
+                total.checked_add(external.amount)
+            })
+            .map_err(Into::into)
     }
 
     /// Get the cached balance for an asset
@@ -634,15 +691,22 @@ mod tests {
         // add base asset
         oracle.update_assets(deps.as_mut(), &ans, vec![base_asset()], vec![])?;
 
-        let value = oracle.account_value(deps.as_ref(), &Addr::unchecked(MOCK_CONTRACT_ADDR))?;
+        let value = oracle.account_value(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+        )?;
         assert_that!(value.total_value.amount.u128()).is_equal_to(1000u128);
 
         let base_asset = oracle.base_asset(deps.as_ref())?;
         assert_that!(base_asset).is_equal_to(AssetInfo::native(USD));
 
         // get the one-asset value of the base asset
-        let asset_value =
-            oracle.asset_value(deps.as_ref(), Asset::new(AssetInfo::native(USD), 1000u128))?;
+        let asset_value = oracle.asset_value(
+            deps.as_ref(),
+            &mock_env(),
+            Asset::new(AssetInfo::native(USD), 1000u128),
+        )?;
         assert_that!(asset_value.u128()).is_equal_to(1000u128);
         Ok(())
     }
@@ -678,7 +742,11 @@ mod tests {
             vec![],
         )?;
 
-        let value = oracle.account_value(deps.as_ref(), &Addr::unchecked(MOCK_CONTRACT_ADDR))?;
+        let value = oracle.account_value(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+        )?;
         assert_that!(value.total_value.amount.u128()).is_equal_to(500u128);
 
         // give the account some base asset
@@ -686,21 +754,63 @@ mod tests {
             .update_balance(MOCK_CONTRACT_ADDR, vec![coin(1000, USD), coin(1000, EUR)]);
 
         // assert that the value increases with 1000
-        let value = oracle.account_value(deps.as_ref(), &Addr::unchecked(MOCK_CONTRACT_ADDR))?;
+        let value = oracle.account_value(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+        )?;
         assert_that!(value.total_value.amount.u128()).is_equal_to(1500u128);
 
         // get the one-asset value of the base asset
-        let asset_value =
-            oracle.asset_value(deps.as_ref(), Asset::new(AssetInfo::native(USD), 1000u128))?;
+        let asset_value = oracle.asset_value(
+            deps.as_ref(),
+            &mock_env(),
+            Asset::new(AssetInfo::native(USD), 1000u128),
+        )?;
         assert_that!(asset_value.u128()).is_equal_to(1000u128);
 
         // now for EUR
-        let asset_value =
-            oracle.asset_value(deps.as_ref(), Asset::new(AssetInfo::native(EUR), 1000u128))?;
+        let asset_value = oracle.asset_value(
+            deps.as_ref(),
+            &mock_env(),
+            Asset::new(AssetInfo::native(EUR), 1000u128),
+        )?;
         assert_that!(asset_value.u128()).is_equal_to(500u128);
         Ok(())
     }
 
+    #[test]
+    fn query_value_with_external_balances() -> AResult {
+        let mut deps = mock_dependencies();
+        let mock_ans = MockAnsHost::new().with_defaults();
+        deps.querier = mock_ans.to_querier();
+        deps.querier
+            .update_balance(MOCK_CONTRACT_ADDR, vec![coin(1000, USD), coin(1000, EUR)]);
+        let ans = get_ans();
+        let mut oracle = Oracle::new();
+        oracle.update_assets(
+            deps.as_mut(),
+            &ans,
+            vec![base_asset(), asset_as_half()],
+            vec![],
+        )?;
+
+        let value = oracle.account_value_with_balances(
+            deps.as_ref(),
+            &mock_env(),
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+            vec![
+                Asset::native(EUR, 500u128),
+                Asset::native(USD, 100u128),
+                // not registered in the oracle
+                Asset::native("unknown", 1000u128),
+            ],
+        )?;
+        // 1100 USD + 1500 EUR at half the value
+        assert_that!(value.total_value.amount.u128()).is_equal_to(1850u128);
+        Ok(())
+    }
+
     #[test]
     fn reject_duplicate_entries() -> AResult {
         let mut deps = mock_dependencies();
@@ -10,16 +10,20 @@
 //! **There should only be ONE base asset when configuring your proxy**
 
 use cosmwasm_std::{
-    to_binary, Addr, Decimal, Deps, QuerierWrapper, QueryRequest, StdError, Uint128, WasmQuery,
+    to_binary, Addr, Decimal, Deps, DepsMut, Env, QuerierWrapper, QueryRequest, StdError, Storage,
+    Timestamp, Uint128, WasmQuery,
 };
 use cw_asset::{Asset, AssetInfo};
+use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::{error::AbstractError, AbstractResult};
 
+use super::time_weighted_average::TimeWeightedAverage;
 use super::{
-    ans_host::AnsHost, AnsEntryConvertor, AssetEntry, DexAssetPairing, PoolAddress, PoolReference,
+    ans_host::AnsHost, AnsEntryConvertor, AssetEntry, ContractEntry, DexAssetPairing, PoolAddress,
+    PoolReference,
 };
 
 /// represents the conversion of an asset in terms of the provided asset
@@ -62,6 +66,19 @@ pub enum UncheckedPriceSource {
         asset: AssetEntry,
         multiplier: Decimal,
     },
+    /// A pool address of an asset/asset pair, priced by the time-weighted average of the pool ratio over `window` seconds.
+    /// Both assets must be defined in the Proxy_assets state
+    Twap {
+        pair: DexAssetPairing,
+        window: u64,
+    },
+    /// An external price feed contract, registered in the ans host, that provides the price of the asset in `quote`.
+    /// Prices older than `max_age` seconds, which must be non-zero, are rejected.
+    PriceFeed {
+        feed: ContractEntry,
+        quote: AssetEntry,
+        max_age: u64,
+    },
     None,
 }
 
@@ -74,24 +91,20 @@ impl UncheckedPriceSource {
     ) -> AbstractResult<PriceSource> {
         match self {
             UncheckedPriceSource::Pair(pair_info) => {
-                let PoolReference {
-                    pool_address,
-                    unique_id,
-                } = ans_host
-                    .query_asset_pairing(&deps.querier, &pair_info)?
-                    .pop()
-                    .unwrap();
-                let pool_assets = ans_host
-                    .query_pool_metadata(&deps.querier, &unique_id)?
-                    .assets;
-                let assets = ans_host.query_assets(&deps.querier, &pool_assets)?;
-                // TODO: fix this for pools with multiple assets
-                assert_eq!(assets.len(), 2);
-                // TODO: fix this for Osmosis pools
-                pool_address.expect_contract()?;
-                Ok(PriceSource::Pool {
-                    address: pool_address,
-                    pair: assets,
+                let (address, pair) = resolve_pair(deps, ans_host, &pair_info)?;
+                Ok(PriceSource::Pool { address, pair })
+            }
+            UncheckedPriceSource::Twap { pair, window } => {
+                if window == 0 {
+                    return Err(AbstractError::Assert(
+                        "TWAP window must be non-zero".to_string(),
+                    ));
+                }
+                let (address, pair) = resolve_pair(deps, ans_host, &pair)?;
+                Ok(PriceSource::Twap {
+                    address,
+                    pair,
+                    window,
                 })
             }
             UncheckedPriceSource::LiquidityToken {} => {
@@ -118,11 +131,53 @@ impl UncheckedPriceSource {
                     multiplier,
                 })
             }
+            UncheckedPriceSource::PriceFeed {
+                feed,
+                quote,
+                max_age,
+            } => {
+                if max_age == 0 {
+                    return Err(AbstractError::Assert(
+                        "Price feed max age must be non-zero".to_string(),
+                    ));
+                }
+                let feed = ans_host.query_contract(&deps.querier, &feed)?;
+                let quote = ans_host.query_asset(&deps.querier, &quote)?;
+                Ok(PriceSource::PriceFeed {
+                    feed,
+                    quote,
+                    max_age,
+                })
+            }
             UncheckedPriceSource::None => Ok(PriceSource::None),
         }
     }
 }
 
+/// Resolves the pool address and assets of a dex asset pairing
+fn resolve_pair(
+    deps: Deps,
+    ans_host: &AnsHost,
+    pair_info: &DexAssetPairing,
+) -> AbstractResult<(PoolAddress, Vec<AssetInfo>)> {
+    let PoolReference {
+        pool_address,
+        unique_id,
+    } = ans_host
+        .query_asset_pairing(&deps.querier, pair_info)?
+        .pop()
+        .unwrap();
+    let pool_assets = ans_host
+        .query_pool_metadata(&deps.querier, &unique_id)?
+        .assets;
+    let assets = ans_host.query_assets(&deps.querier, &pool_assets)?;
+    // TODO: fix this for pools with multiple assets
+    assert_eq!(assets.len(), 2);
+    // TODO: fix this for Osmosis pools
+    pool_address.expect_contract()?;
+    Ok((pool_address, assets))
+}
+
 /// Provides information on how to calculate the value of an asset
 #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, JsonSchema)]
 #[non_exhaustive]
@@ -141,11 +196,42 @@ pub enum PriceSource {
         pool_assets: Vec<AssetInfo>,
         pool_address: PoolAddress,
     },
+    /// A pool of an asset/asset pair, priced by the time-weighted average of the pool ratio.
+    /// The average is accumulated by [`crate::objects::oracle::Oracle::update_twaps`].
+    Twap {
+        address: PoolAddress,
+        /// two assets that make up a pair in the pool
+        pair: Vec<AssetInfo>,
+        /// averaging window in seconds
+        window: u64,
+    },
     /// Asset will be valued as if they are ValueAs.asset tokens
     ValueAs {
         asset: AssetInfo,
         multiplier: Decimal,
     },
+    /// Asset is valued in `quote` tokens at the price reported by the feed contract
+    PriceFeed {
+        feed: Addr,
+        quote: AssetInfo,
+        /// Maximum age of the reported price in seconds
+        max_age: u64,
+    },
+}
+
+/// Query message an external price feed contract must support to be used as [`PriceSource::PriceFeed`]
+#[cosmwasm_schema::cw_serde]
+pub enum PriceFeedQueryMsg {
+    /// Returns the price of one unit of `asset` in units of `quote`
+    /// [`PriceFeedResponse`]
+    Price { asset: AssetInfo, quote: AssetInfo },
+}
+
+#[cosmwasm_schema::cw_serde]
+pub struct PriceFeedResponse {
+    pub price: Decimal,
+    /// Time at which the price was last updated
+    pub updated_at: Timestamp,
 }
 
 impl PriceSource {
@@ -154,11 +240,12 @@ impl PriceSource {
     pub fn dependencies(&self, asset: &AssetInfo) -> Vec<AssetInfo> {
         match self {
             // return the other asset as the dependency
-            PriceSource::Pool { pair, .. } => {
+            PriceSource::Pool { pair, .. } | PriceSource::Twap { pair, .. } => {
                 pair.iter().filter(|a| *a != asset).cloned().collect()
             }
             PriceSource::LiquidityToken { pool_assets, .. } => pool_assets.clone(),
             PriceSource::ValueAs { asset, .. } => vec![asset.clone()],
+            PriceSource::PriceFeed { quote, .. } => vec![quote.clone()],
             PriceSource::None => vec![],
         }
     }
@@ -167,6 +254,7 @@ impl PriceSource {
     pub fn conversion_rates(
         &self,
         deps: Deps,
+        env: &Env,
         asset: &AssetInfo,
     ) -> AbstractResult<Vec<AssetConversion>> {
         // Is there a reference to calculate the price?
@@ -176,6 +264,8 @@ impl PriceSource {
             PriceSource::Pool { address, pair } => self
                 .trade_pair_price(deps, asset, &address.expect_contract()?, pair)
                 .map(|e| vec![e]),
+            // A Twap uses the time-weighted average of the pool ratio
+            PriceSource::Twap { pair, .. } => self.twap_price(deps, asset, pair).map(|e| vec![e]),
             // Liquidity is an LP token,
             PriceSource::LiquidityToken {
                 pool_address,
@@ -185,13 +275,21 @@ impl PriceSource {
             PriceSource::ValueAs { asset, multiplier } => {
                 Ok(vec![AssetConversion::new(asset.clone(), *multiplier)])
             }
+            // An external feed provides the price in the quote asset
+            PriceSource::PriceFeed {
+                feed,
+                quote,
+                max_age,
+            } => self
+                .feed_price(deps, env, asset, feed, quote, *max_age)
+                .map(|e| vec![e]),
             // None means it's the base asset
             PriceSource::None => Ok(vec![]),
         }
     }
 
     /// Calculates the price of an asset compared to some other asset through the provided trading pair.
-    fn trade_pair_price(
+    pub(crate) fn trade_pair_price(
         &self,
         deps: Deps,
         priced_asset: &AssetInfo,
@@ -211,6 +309,106 @@ impl PriceSource {
         Ok(AssetConversion::new(other_asset_info.clone(), ratio))
     }
 
+    /// Accumulates the pool ratio held since the last update into the time-weighted average of a [`PriceSource::Twap`] asset,
+    /// updates the average once its window passed and stores the current pool ratio for the next update.
+    pub(crate) fn accumulate_twap(
+        &self,
+        deps: DepsMut,
+        env: &Env,
+        asset: &AssetInfo,
+    ) -> AbstractResult<()> {
+        let PriceSource::Twap {
+            address,
+            pair,
+            window,
+        } = self
+        else {
+            return Ok(());
+        };
+        let key = twap_key(asset);
+        let twa = TimeWeightedAverage::new(&key);
+        let spot_key = twap_spot_key(asset);
+        let last_spot: Item<Decimal> = Item::new(&spot_key);
+
+        match twa.may_load(deps.storage)? {
+            Some(data) => {
+                if data.averaging_period != *window {
+                    twa.update_settings(env, deps.storage, *window)?;
+                }
+                // the previous ratio is the one that held over the elapsed time,
+                // so a ratio set right before this update only counts from now on
+                let previous = last_spot.load(deps.storage)?;
+                twa.accumulate(
+                    env,
+                    deps.storage,
+                    previous * Decimal::from_ratio(twap_scale(data.precision), 1u128),
+                )?;
+                twa.try_update_value(env, deps.storage)?;
+            }
+            None => twa.instantiate(deps.storage, env, None, *window)?,
+        }
+
+        let spot =
+            self.trade_pair_price(deps.as_ref(), asset, &address.expect_contract()?, pair)?;
+        last_spot.save(deps.storage, &spot.ratio)?;
+        Ok(())
+    }
+
+    /// Returns the time-weighted average price of the asset accumulated for the pair.
+    fn twap_price(
+        &self,
+        deps: Deps,
+        priced_asset: &AssetInfo,
+        pair: &[AssetInfo],
+    ) -> AbstractResult<AssetConversion> {
+        let other_asset_info = pair
+            .iter()
+            .find(|a| a != &priced_asset)
+            .ok_or_else(|| AbstractError::Assert(format!("{priced_asset} has no paired asset")))?;
+        let key = twap_key(priced_asset);
+        let twa = TimeWeightedAverage::new(&key)
+            .may_load(deps.storage)?
+            .filter(|twa| !twa.average_value().is_zero())
+            .ok_or_else(|| AbstractError::TwapNotReady(priced_asset.to_string()))?;
+        Ok(AssetConversion::new(
+            other_asset_info.clone(),
+            twa.average_value() * Decimal::from_ratio(1u128, twap_scale(twa.precision)),
+        ))
+    }
+
+    /// Queries the price of an asset from an external price feed and rejects stale prices and prices from the future.
+    fn feed_price(
+        &self,
+        deps: Deps,
+        env: &Env,
+        priced_asset: &AssetInfo,
+        feed: &Addr,
+        quote: &AssetInfo,
+        max_age: u64,
+    ) -> AbstractResult<AssetConversion> {
+        let PriceFeedResponse { price, updated_at } = deps.querier.query_wasm_smart(
+            feed,
+            &PriceFeedQueryMsg::Price {
+                asset: priced_asset.clone(),
+                quote: quote.clone(),
+            },
+        )?;
+        if updated_at > env.block.time {
+            return Err(AbstractError::FuturePrice {
+                asset: priced_asset.to_string(),
+                updated_at,
+            });
+        }
+        if updated_at.plus_seconds(max_age) < env.block.time {
+            return Err(AbstractError::StalePrice {
+                asset: priced_asset.to_string(),
+                updated_at,
+                max_age,
+            });
+        }
+        Ok(AssetConversion::new(quote.clone(), price))
+    }
+
     /// Calculate the conversions of an LP token
     /// Uses the lp token name to query pair pool for both assets
     /// Returns the conversion ratio of the LP token in terms of the other asset
@@ -242,6 +440,28 @@ impl PriceSource {
     }
 }
 
+/// Storage key of the time-weighted average price of an asset
+fn twap_key(asset: &AssetInfo) -> String {
+    format!("twap_{asset}")
+}
+
+/// Storage key of the pool ratio of an asset at the last time-weighted average update
+fn twap_spot_key(asset: &AssetInfo) -> String {
+    format!("twap_spot_{asset}")
+}
+
+/// Removes the time-weighted average price of an asset, if any
+pub(crate) fn remove_twap(storage: &mut dyn Storage, asset: &AssetInfo) {
+    TimeWeightedAverage::new(&twap_key(asset)).remove(storage);
+    Item::<Decimal>::new(&twap_spot_key(asset)).remove(storage);
+}
+
+/// Pool ratios are scaled up by 10^precision before being accumulated
+/// because the time-weighted average accumulates integers.
+fn twap_scale(precision: u8) -> u128 {
+    10u128.pow(precision as u32)
+}
+
 fn query_cw20_supply(querier: &QuerierWrapper, contract_addr: &Addr) -> AbstractResult<Uint128> {
     let response: cw20::TokenInfoResponse =
         querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
@@ -355,6 +575,234 @@ mod tests {
         }
     }
 
+    mod price_feed {
+        use super::*;
+        use crate::ans_host;
+        use cosmwasm_std::testing::mock_env;
+
+        const TEST_FEED: &str = "price_feed";
+
+        fn feed_querier(age: i64) -> MockQuerierBuilder {
+            MockQuerierBuilder::default().with_smart_handler(TEST_FEED, move |msg| {
+                match from_binary::<PriceFeedQueryMsg>(msg).unwrap() {
+                    PriceFeedQueryMsg::Price { .. } => Ok(to_binary(&PriceFeedResponse {
+                        price: Decimal::percent(250),
+                        updated_at: Timestamp::from_seconds(
+                            (mock_env().block.time.seconds() as i64 - age) as u64,
+                        ),
+                    })
+                    .unwrap()),
+                }
+            })
+        }
+
+        fn price_source() -> PriceSource {
+            PriceSource::PriceFeed {
+                feed: Addr::unchecked(TEST_FEED),
+                quote: AssetInfo::native(TEST_ASSET_2),
+                max_age: 60,
+            }
+        }
+
+        #[test]
+        fn check() -> AbstractResult<()> {
+            let mut deps = mock_dependencies();
+            let feed_entry = ContractEntry {
+                protocol: "oracle".to_string(),
+                contract: "feed".to_string(),
+            };
+            deps.querier = MockQuerierBuilder::default()
+                .with_contract_map_entry(
+                    TEST_ANS_HOST,
+                    ans_host::state::CONTRACT_ADDRESSES,
+                    (&feed_entry, Addr::unchecked(TEST_FEED)),
+                )
+                .with_contract_map_entry(
+                    TEST_ANS_HOST,
+                    ans_host::state::ASSET_ADDRESSES,
+                    (
+                        &AssetEntry::from(TEST_ASSET_2),
+                        AssetInfo::native(TEST_ASSET_2),
+                    ),
+                )
+                .build();
+
+            let actual_source = UncheckedPriceSource::PriceFeed {
+                feed: feed_entry.clone(),
+                quote: AssetEntry::from(TEST_ASSET_2),
+                max_age: 60,
+            }
+            .check(
+                deps.as_ref(),
+                &AnsHost::new(Addr::unchecked(TEST_ANS_HOST)),
+                &AssetEntry::new(TEST_ASSET_1),
+            )?;
+
+            assert_that!(actual_source).is_equal_to(price_source());
+
+            let res = UncheckedPriceSource::PriceFeed {
+                feed: feed_entry,
+                quote: AssetEntry::from(TEST_ASSET_2),
+                max_age: 0,
+            }
+            .check(
+                deps.as_ref(),
+                &AnsHost::new(Addr::unchecked(TEST_ANS_HOST)),
+                &AssetEntry::new(TEST_ASSET_1),
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::Assert(
+                    "Price feed max age must be non-zero".to_string(),
+                ));
+            Ok(())
+        }
+
+        #[test]
+        fn converts_at_feed_price() -> AbstractResult<()> {
+            let mut deps = mock_dependencies();
+            deps.querier = feed_querier(60).build();
+
+            let rates = price_source().conversion_rates(
+                deps.as_ref(),
+                &mock_env(),
+                &AssetInfo::native(TEST_ASSET_1),
+            )?;
+            assert_that!(rates).is_equal_to(vec![AssetConversion::new(
+                AssetInfo::native(TEST_ASSET_2),
+                Decimal::percent(250),
+            )]);
+            Ok(())
+        }
+
+        #[test]
+        fn rejects_stale_price() {
+            let mut deps = mock_dependencies();
+            deps.querier = feed_querier(61).build();
+
+            let res = price_source().conversion_rates(
+                deps.as_ref(),
+                &mock_env(),
+                &AssetInfo::native(TEST_ASSET_1),
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::StalePrice {
+                    asset: AssetInfo::native(TEST_ASSET_1).to_string(),
+                    updated_at: mock_env().block.time.minus_seconds(61),
+                    max_age: 60,
+                });
+        }
+
+        #[test]
+        fn rejects_future_price() {
+            let mut deps = mock_dependencies();
+            deps.querier = feed_querier(-1).build();
+
+            let res = price_source().conversion_rates(
+                deps.as_ref(),
+                &mock_env(),
+                &AssetInfo::native(TEST_ASSET_1),
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::FuturePrice {
+                    asset: AssetInfo::native(TEST_ASSET_1).to_string(),
+                    updated_at: mock_env().block.time.plus_seconds(1),
+                });
+        }
+    }
+
+    mod twap {
+        use super::*;
+        use cosmwasm_std::{coin, testing::mock_env};
+
+        fn twap_source() -> PriceSource {
+            PriceSource::Twap {
+                address: PoolAddress::contract(Addr::unchecked(TEST_POOL_ADDR)),
+                pair: vec![
+                    AssetInfo::native(TEST_ASSET_1),
+                    AssetInfo::native(TEST_ASSET_2),
+                ],
+                window: 100,
+            }
+        }
+
+        #[test]
+        fn averages_pool_ratio_over_window() -> AbstractResult<()> {
+            let mut deps = mock_dependencies();
+            let asset = AssetInfo::native(TEST_ASSET_1);
+            let source = twap_source();
+            let mut env = mock_env();
+            deps.querier.update_balance(
+                TEST_POOL_ADDR,
+                vec![coin(100, TEST_ASSET_1), coin(200, TEST_ASSET_2)],
+            );
+
+            source.accumulate_twap(deps.as_mut(), &env, &asset)?;
+            let res = source.conversion_rates(deps.as_ref(), &env, &asset);
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(AbstractError::TwapNotReady(asset.to_string()));
+
+            env.block.time = env.block.time.plus_seconds(50);
+            source.accumulate_twap(deps.as_mut(), &env, &asset)?;
+            // window did not pass yet
+            let res = source.conversion_rates(deps.as_ref(), &env, &asset);
+            assert_that!(res).is_err();
+
+            // pool ratio doubles right before the window passes
+            deps.querier.update_balance(
+                TEST_POOL_ADDR,
+                vec![coin(100, TEST_ASSET_1), coin(400, TEST_ASSET_2)],
+            );
+            env.block.time = env.block.time.plus_seconds(50);
+            source.accumulate_twap(deps.as_mut(), &env, &asset)?;
+
+            // the new ratio did not hold over the window yet
+            let rates = source.conversion_rates(deps.as_ref(), &env, &asset)?;
+            assert_that!(rates).is_equal_to(vec![AssetConversion::new(
+                AssetInfo::native(TEST_ASSET_2),
+                Decimal::from_ratio(2u128, 1u128),
+            )]);
+
+            env.block.time = env.block.time.plus_seconds(100);
+            source.accumulate_twap(deps.as_mut(), &env, &asset)?;
+            let rates = source.conversion_rates(deps.as_ref(), &env, &asset)?;
+            assert_that!(rates).is_equal_to(vec![AssetConversion::new(
+                AssetInfo::native(TEST_ASSET_2),
+                Decimal::from_ratio(4u128, 1u128),
+            )]);
+            Ok(())
+        }
+
+        #[test]
+        fn updates_window() -> AbstractResult<()> {
+            let mut deps = mock_dependencies();
+            let asset = AssetInfo::native(TEST_ASSET_1);
+            let env = mock_env();
+            deps.querier.update_balance(
+                TEST_POOL_ADDR,
+                vec![coin(100, TEST_ASSET_1), coin(200, TEST_ASSET_2)],
+            );
+            twap_source().accumulate_twap(deps.as_mut(), &env, &asset)?;
+
+            let PriceSource::Twap { address, pair, .. } = twap_source() else {
+                unreachable!()
+            };
+            let source = PriceSource::Twap {
+                address,
+                pair,
+                window: 50,
+            };
+            source.accumulate_twap(deps.as_mut(), &env, &asset)?;
+
+            let twa = TimeWeightedAverage::new(&twap_key(&asset)).load(&deps.storage)?;
+            assert_that!(twa.averaging_period).is_equal_to(50);
+            Ok(())
+        }
+    }
+
     mod lp_conversion {
         use super::*;
 
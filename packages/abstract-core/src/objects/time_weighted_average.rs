@@ -67,6 +67,14 @@ impl<'a> TimeWeightedAverage<'a> {
         self.0.load(store).map_err(Into::into)
     }
 
+    pub fn may_load(&self, store: &dyn Storage) -> AbstractResult<Option<TimeWeightedAverageData>> {
+        self.0.may_load(store).map_err(Into::into)
+    }
+
+    pub fn remove(&self, store: &mut dyn Storage) {
+        self.0.remove(store)
+    }
+
     /// Get average value, updates when possible
     pub fn try_update_value(
         &self,
@@ -128,3 +136,10 @@ pub struct TimeWeightedAverageData {
     /// The requested average value
     average_value: Decimal,
 }
+
+impl TimeWeightedAverageData {
+    /// The average value over the last completed averaging period
+    pub fn average_value(&self) -> Decimal {
+        self.average_value
+    }
+}
@@ -1,6 +1,8 @@
 use crate::{
     error::HostError,
-    host_commands::{receive_query, receive_register, receive_who_am_i},
+    host_commands::{
+        receive_create_account, receive_manager, receive_query, receive_register, receive_who_am_i,
+    },
     state::{ContractError, Host, ACCOUNTS, CLIENT_PROXY, CLOSED_CHANNELS, PROCESSING_PACKET},
 };
 use abstract_sdk::{
@@ -101,6 +103,10 @@ impl<
                 let this_chain = self.base_state.load(deps.storage)?.chain;
                 receive_who_am_i(this_chain)
             }
+            HostAction::Internal(InternalAction::CreateAccount) => {
+                receive_create_account(deps, env, self, channel, account_id, client_chain)
+            }
+            HostAction::Manager(msg) => receive_manager(deps.as_ref(), channel, account_id, msg),
             HostAction::Dispatch { msgs, .. } => self.receive_dispatch(deps, msgs),
             HostAction::Query { msgs, .. } => receive_query(deps.as_ref(), msgs),
             HostAction::Balances {} => self.receive_balances(deps),
@@ -135,7 +141,14 @@ impl<
             BaseExecuteMsg::UpdateConfig {
                 ans_host_address,
                 cw1_code_id,
-            } => self.update_config(deps, info, ans_host_address, cw1_code_id),
+                account_factory_address,
+            } => self.update_config(
+                deps,
+                info,
+                ans_host_address,
+                cw1_code_id,
+                account_factory_address,
+            ),
             BaseExecuteMsg::RecoverAccount {
                 closed_channel,
                 account_id,
@@ -163,6 +176,7 @@ impl<
         info: MessageInfo,
         ans_host_address: Option<String>,
         cw1_code_id: Option<u64>,
+        account_factory_address: Option<String>,
     ) -> HostResult {
         let mut state = self.state(deps.storage)?;
 
@@ -176,6 +190,10 @@ impl<
             // validate address format
             state.cw1_code_id = cw1_code_id;
         }
+        if let Some(account_factory_address) = account_factory_address {
+            // validate address format
+            state.account_factory = Some(deps.api.addr_validate(&account_factory_address)?);
+        }
         self.base_state.save(deps.storage, &state)?;
         Ok(Response::new())
     }
@@ -47,6 +47,11 @@ impl<
             chain: self.chain.to_string(),
             ans_host,
             cw1_code_id: msg.base.cw1_code_id,
+            account_factory: msg
+                .base
+                .account_factory_address
+                .map(|addr| deps.api.addr_validate(&addr))
+                .transpose()?,
         };
         let (name, version, metadata) = self.info();
         // Keep track of all the closed channels, allows for fund recovery if channel closes.
@@ -57,7 +62,7 @@ impl<
         self.base_state.save(deps.storage, &state)?;
 
         let Some(handler) = self.maybe_instantiate_handler() else {
-            return Ok(Response::new())
+            return Ok(Response::new());
         };
         self.admin.set(deps.branch(), Some(info.sender.clone()))?;
         handler(deps, env, info, self, msg.module)
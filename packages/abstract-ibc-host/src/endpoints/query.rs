@@ -1,10 +1,10 @@
-use crate::state::{ContractError, Host, ACCOUNTS};
+use crate::state::{ContractError, Host, ABSTRACT_ACCOUNTS, ACCOUNTS};
 use abstract_core::objects::AccountId;
 use abstract_sdk::{
     base::{Handler, QueryEndpoint},
     core::ibc_host::{
-        AccountInfo, AccountResponse, BaseQueryMsg, HostConfigResponse, ListAccountsResponse,
-        QueryMsg,
+        AbstractAccountResponse, AccountInfo, AccountResponse, BaseQueryMsg, HostConfigResponse,
+        ListAccountsResponse, QueryMsg,
     },
 };
 use cosmwasm_std::{to_binary, Binary, Deps, Env, Order, StdResult};
@@ -59,12 +59,17 @@ impl<
                 account_id,
             } => to_binary(&query_account(deps, client_chain, account_id)?),
             BaseQueryMsg::ListAccounts {} => to_binary(&query_list_accounts(deps)?),
+            BaseQueryMsg::AbstractAccount {
+                client_chain,
+                account_id,
+            } => to_binary(&query_abstract_account(deps, client_chain, account_id)?),
         }
     }
     fn dapp_config(&self, deps: Deps) -> StdResult<HostConfigResponse> {
         let state = self.base_state.load(deps.storage)?;
         Ok(HostConfigResponse {
             ans_host_address: state.ans_host.address,
+            account_factory_address: state.account_factory,
         })
     }
 }
@@ -80,6 +85,15 @@ pub fn query_account(
     })
 }
 
+pub fn query_abstract_account(
+    deps: Deps,
+    channel_id: String,
+    account_id: AccountId,
+) -> StdResult<AbstractAccountResponse> {
+    let account = ABSTRACT_ACCOUNTS.may_load(deps.storage, (&channel_id, account_id))?;
+    Ok(AbstractAccountResponse { account })
+}
+
 pub fn query_list_accounts(deps: Deps) -> StdResult<ListAccountsResponse> {
     let accounts = ACCOUNTS
         .range(deps.storage, None, None, Order::Ascending)
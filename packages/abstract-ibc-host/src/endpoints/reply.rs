@@ -1,19 +1,26 @@
 use crate::{
-    state::{ContractError, ACCOUNTS, CLIENT_PROXY, PENDING, PROCESSING_PACKET, RESULTS},
+    state::{
+        ContractError, ABSTRACT_ACCOUNTS, ACCOUNTS, CLIENT_PROXY, PENDING, PENDING_ACCOUNT,
+        PROCESSING_PACKET, RESULTS,
+    },
     Host, HostError,
 };
 use abstract_sdk::{
     base::{Handler, ReplyEndpoint},
     core::{
-        abstract_ica::{DispatchResponse, RegisterResponse, StdAck},
+        abstract_ica::{CreateAccountResponse, DispatchResponse, RegisterResponse, StdAck},
+        account_factory,
         ibc_host::PacketMsg,
+        version_control::{self, AccountBaseResponse},
     },
 };
-use cosmwasm_std::{DepsMut, Empty, Env, Reply, Response};
+use cosmwasm_std::{DepsMut, Empty, Env, Reply, Response, SubMsgResult};
 use cw_utils::parse_reply_instantiate_data;
 
 pub const RECEIVE_DISPATCH_ID: u64 = 1234;
 pub const INIT_CALLBACK_ID: u64 = 7890;
+pub const CREATE_ACCOUNT_CALLBACK_ID: u64 = 4567;
+pub const RECEIVE_MANAGER_ID: u64 = 6789;
 
 impl<
         Error: ContractError,
@@ -93,6 +100,37 @@ pub fn reply_dispatch_callback<
     Ok(Response::new().set_data(data))
 }
 
+/// Reports the result of a relayed manager message in the acknowledgement.
+/// A failed message is not reverted through an error so the packet is still acknowledged.
+pub fn reply_manager_callback<
+    Error: ContractError,
+    CustomExecMsg,
+    CustomInitMsg,
+    CustomQueryMsg,
+    CustomMigrateMsg,
+    ReceiveMsg,
+    SudoMsg,
+>(
+    _deps: DepsMut,
+    _env: Env,
+    _host: Host<
+        Error,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        CustomMigrateMsg,
+        ReceiveMsg,
+        SudoMsg,
+    >,
+    reply: Reply,
+) -> Result<Response, Error> {
+    let data = match reply.result {
+        SubMsgResult::Ok(_) => StdAck::success(Empty {}),
+        SubMsgResult::Err(err) => StdAck::fail(err),
+    };
+    Ok(Response::new().set_data(data))
+}
+
 pub fn reply_init_callback<
     Error: ContractError,
     CustomExecMsg,
@@ -138,3 +176,53 @@ pub fn reply_init_callback<
     });
     Ok(Response::new().set_data(data))
 }
+
+pub fn reply_create_account_callback<
+    Error: ContractError,
+    CustomExecMsg,
+    CustomInitMsg,
+    CustomQueryMsg,
+    CustomMigrateMsg,
+    SudoMsg,
+    ReceiveMsg,
+>(
+    deps: DepsMut,
+    _env: Env,
+    host: Host<
+        Error,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        CustomMigrateMsg,
+        ReceiveMsg,
+        SudoMsg,
+    >,
+    _reply: Reply,
+) -> Result<Response, Error> {
+    // we use storage to pass info from the caller to the reply
+    let (channel, account_id, new_account_id) = PENDING_ACCOUNT.load(deps.storage)?;
+    PENDING_ACCOUNT.remove(deps.storage);
+
+    // the factory registered the new Account on version control
+    let account_factory = host
+        .base_state
+        .load(deps.storage)?
+        .account_factory
+        .ok_or(HostError::MissingAccountFactory)?;
+    let factory_config: account_factory::ConfigResponse = deps
+        .querier
+        .query_wasm_smart(account_factory, &account_factory::QueryMsg::Config {})?;
+    let AccountBaseResponse { account_base } = deps.querier.query_wasm_smart(
+        factory_config.version_control_contract,
+        &version_control::QueryMsg::AccountBase {
+            account_id: new_account_id,
+        },
+    )?;
+
+    ABSTRACT_ACCOUNTS.save(deps.storage, (&channel, account_id), &account_base)?;
+    let data = StdAck::success(CreateAccountResponse {
+        manager: account_base.manager.into_string(),
+        proxy: account_base.proxy.into_string(),
+    });
+    Ok(Response::new().set_data(data))
+}
@@ -45,4 +45,13 @@ pub enum HostError {
 
     #[error("Ibc hopping not supported")]
     IbcHopping,
+
+    #[error("No account factory configured to create Abstract Accounts.")]
+    MissingAccountFactory,
+
+    #[error("An Abstract Account was already created for this Account.")]
+    AbstractAccountAlreadyCreated,
+
+    #[error("No Abstract Account was created for this Account.")]
+    NoAbstractAccount,
 }
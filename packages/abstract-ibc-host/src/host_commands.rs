@@ -1,18 +1,23 @@
 use crate::{
-    endpoints::reply::INIT_CALLBACK_ID,
-    state::{ContractError, CLIENT_PROXY, CLOSED_CHANNELS, PENDING},
+    endpoints::reply::{CREATE_ACCOUNT_CALLBACK_ID, INIT_CALLBACK_ID, RECEIVE_MANAGER_ID},
+    state::{
+        ContractError, ABSTRACT_ACCOUNTS, CLIENT_PROXY, CLOSED_CHANNELS, PENDING, PENDING_ACCOUNT,
+    },
     Host, HostError,
 };
-use abstract_core::objects::AccountId;
-use abstract_sdk::core::abstract_ica::{
-    check_order, check_version, IbcQueryResponse, StdAck, WhoAmIResponse, IBC_APP_VERSION,
+use abstract_core::{account_factory, manager, objects::AccountId};
+use abstract_sdk::core::{
+    abstract_ica::{
+        check_order, check_version, IbcQueryResponse, StdAck, WhoAmIResponse, IBC_APP_VERSION,
+    },
+    objects::gov_type::GovernanceDetails,
 };
 use cosmwasm_std::{
-    entry_point, to_binary, to_vec, Binary, ContractResult, Deps, DepsMut, Empty, Env, Event,
-    Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg, IbcChannelConnectMsg,
-    IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketAckMsg, IbcPacketTimeoutMsg,
-    IbcReceiveResponse, QuerierWrapper, QueryRequest, StdError, StdResult, SubMsg, SystemResult,
-    WasmMsg,
+    entry_point, to_binary, to_vec, wasm_execute, Binary, ContractResult, Deps, DepsMut, Empty,
+    Env, Event, Ibc3ChannelOpenResponse, IbcBasicResponse, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcPacketAckMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, QuerierWrapper, QueryRequest, StdError, StdResult,
+    SubMsg, SystemResult, WasmMsg,
 };
 
 // one hour
@@ -178,6 +183,102 @@ pub fn receive_register<
         .add_attribute("action", "register"))
 }
 
+// processes InternalAction::CreateAccount variant
+/// Creates an Abstract Account for the remote Account through the account factory
+pub fn receive_create_account<
+    Error: ContractError,
+    CustomExecMsg,
+    CustomInitMsg,
+    CustomQueryMsg,
+    CustomMigrateMsg,
+    ReceiveMsg,
+    SudoMsg,
+>(
+    deps: DepsMut,
+    env: Env,
+    host: Host<
+        Error,
+        CustomInitMsg,
+        CustomExecMsg,
+        CustomQueryMsg,
+        CustomMigrateMsg,
+        ReceiveMsg,
+        SudoMsg,
+    >,
+    channel: String,
+    account_id: AccountId,
+    client_chain: String,
+) -> Result<IbcReceiveResponse, HostError> {
+    let cfg = host.base_state.load(deps.storage)?;
+    let account_factory = cfg
+        .account_factory
+        .ok_or(HostError::MissingAccountFactory)?;
+    if ABSTRACT_ACCOUNTS.has(deps.storage, (&channel, account_id)) {
+        return Err(HostError::AbstractAccountAlreadyCreated);
+    }
+
+    // the factory assigns the next account id to the new Account
+    let factory_config: account_factory::ConfigResponse = deps
+        .querier
+        .query_wasm_smart(&account_factory, &account_factory::QueryMsg::Config {})?;
+
+    // the host owns the Account so it can relay manager messages
+    let create_msg = account_factory::ExecuteMsg::CreateAccount {
+        governance: GovernanceDetails::Monarchy {
+            monarch: env.contract.address.into_string(),
+        },
+        name: format!("{client_chain}-{account_id}"),
+        description: None,
+        link: None,
+    };
+    let msg = SubMsg::reply_on_success(
+        wasm_execute(account_factory, &create_msg, vec![])?,
+        CREATE_ACCOUNT_CALLBACK_ID,
+    );
+
+    // store the account info for the reply handler
+    PENDING_ACCOUNT.save(
+        deps.storage,
+        &(channel, account_id, factory_config.next_account_id),
+    )?;
+
+    // We rely on Reply handler to change this to Success!
+    let acknowledgement = StdAck::fail(format!(
+        "Failed to create Abstract Account for Account {account_id}"
+    ));
+
+    Ok(IbcReceiveResponse::new()
+        .add_submessage(msg)
+        .set_ack(acknowledgement)
+        .add_attribute("action", "create_account"))
+}
+
+// processes HostAction::Manager variant
+/// Forwards the message to the manager of the Abstract Account created for the remote Account
+pub fn receive_manager(
+    deps: Deps,
+    channel: String,
+    account_id: AccountId,
+    msg: manager::ExecuteMsg,
+) -> Result<IbcReceiveResponse, HostError> {
+    let account = ABSTRACT_ACCOUNTS
+        .may_load(deps.storage, (&channel, account_id))?
+        .ok_or(HostError::NoAbstractAccount)?;
+    let wasm_msg = wasm_execute(account.manager, &msg, vec![])?;
+    // the reply reports the result of the manager message
+    let msg = SubMsg::reply_always(wasm_msg, RECEIVE_MANAGER_ID);
+
+    // We rely on Reply handler to change this to Success!
+    let acknowledgement = StdAck::fail(format!(
+        "Failed to execute manager message for Account {account_id}"
+    ));
+
+    Ok(IbcReceiveResponse::new()
+        .set_ack(acknowledgement)
+        .add_submessage(msg)
+        .add_attribute("action", "receive_manager"))
+}
+
 // processes InternalAction::WhoAmI variant
 pub fn receive_who_am_i(this_chain: String) -> Result<IbcReceiveResponse, HostError> {
     // let them know we're fine
@@ -210,6 +311,228 @@ pub fn ibc_packet_timeout(
     Ok(IbcBasicResponse::new().add_attribute("action", "ibc_packet_timeout"))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::HostState;
+    use abstract_sdk::{
+        base::ReplyEndpoint,
+        core::{
+            abstract_ica::CreateAccountResponse,
+            objects::ans_host::AnsHost,
+            version_control::{self, AccountBaseResponse},
+        },
+    };
+    use abstract_testing::prelude::*;
+    use cosmwasm_std::{
+        from_binary,
+        testing::{mock_dependencies, mock_env},
+        Addr, CosmosMsg, Reply, Response, SubMsgResponse, SubMsgResult,
+    };
+    use speculoos::prelude::*;
+
+    type MockHost = Host<HostError, Empty, Empty, Empty, Empty>;
+
+    const TEST_CHANNEL: &str = "channel-1";
+    const NEW_ACCOUNT_ID: AccountId = 5;
+
+    fn mock_host() -> MockHost {
+        Host::new("test-host", TEST_VERSION, TEST_CHAIN, None)
+    }
+
+    fn mock_init() -> MockDeps {
+        let mut deps = mock_dependencies();
+        deps.querier = MockQuerierBuilder::default()
+            .with_smart_handler(TEST_ACCOUNT_FACTORY, |msg| {
+                match from_binary::<account_factory::QueryMsg>(msg).unwrap() {
+                    account_factory::QueryMsg::Config {} => {
+                        Ok(to_binary(&account_factory::ConfigResponse {
+                            ans_host_contract: Addr::unchecked(TEST_ANS_HOST),
+                            version_control_contract: Addr::unchecked(TEST_VERSION_CONTROL),
+                            module_factory_address: Addr::unchecked(TEST_MODULE_FACTORY),
+                            next_account_id: NEW_ACCOUNT_ID,
+                        })
+                        .unwrap())
+                    }
+                }
+            })
+            .with_smart_handler(TEST_VERSION_CONTROL, |msg| {
+                match from_binary::<version_control::QueryMsg>(msg).unwrap() {
+                    version_control::QueryMsg::AccountBase { account_id } => {
+                        assert_that!(account_id).is_equal_to(NEW_ACCOUNT_ID);
+                        Ok(to_binary(&AccountBaseResponse {
+                            account_base: test_account_base(),
+                        })
+                        .unwrap())
+                    }
+                    _ => panic!("unexpected query"),
+                }
+            })
+            .build();
+        mock_host()
+            .base_state
+            .save(
+                deps.as_mut().storage,
+                &HostState {
+                    ans_host: AnsHost {
+                        address: Addr::unchecked(TEST_ANS_HOST),
+                    },
+                    cw1_code_id: 1,
+                    account_factory: Some(Addr::unchecked(TEST_ACCOUNT_FACTORY)),
+                    chain: TEST_CHAIN.to_string(),
+                },
+            )
+            .unwrap();
+        deps
+    }
+
+    fn create_account(deps: DepsMut) -> Result<IbcReceiveResponse, HostError> {
+        receive_create_account(
+            deps,
+            mock_env(),
+            mock_host(),
+            TEST_CHANNEL.to_string(),
+            TEST_ACCOUNT_ID,
+            "juno".to_string(),
+        )
+    }
+
+    fn reply(deps: DepsMut, id: u64, result: SubMsgResult) -> Result<Response, HostError> {
+        mock_host().reply(deps, mock_env(), Reply { id, result })
+    }
+
+    fn reply_ok() -> SubMsgResult {
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        })
+    }
+
+    fn update_info_msg() -> manager::ExecuteMsg {
+        manager::ExecuteMsg::UpdateInfo {
+            name: Some("name".to_string()),
+            description: None,
+            link: None,
+        }
+    }
+
+    mod create_account {
+        use super::*;
+
+        #[test]
+        fn registers_account_on_reply() -> Result<(), HostError> {
+            let mut deps = mock_init();
+
+            let res = create_account(deps.as_mut())?;
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].id).is_equal_to(CREATE_ACCOUNT_CALLBACK_ID);
+            // the ack fails until the reply reports success
+            assert_that!(from_binary::<StdAck>(&res.acknowledgement)?)
+                .matches(|ack| matches!(ack, StdAck::Error(_)));
+            assert_that!(PENDING_ACCOUNT.load(&deps.storage)?).is_equal_to((
+                TEST_CHANNEL.to_string(),
+                TEST_ACCOUNT_ID,
+                NEW_ACCOUNT_ID,
+            ));
+
+            let res = reply(deps.as_mut(), CREATE_ACCOUNT_CALLBACK_ID, reply_ok())?;
+            let account = test_account_base();
+            assert_that!(res.data).is_equal_to(Some(StdAck::success(CreateAccountResponse {
+                manager: account.manager.to_string(),
+                proxy: account.proxy.to_string(),
+            })));
+            assert_that!(ABSTRACT_ACCOUNTS.load(&deps.storage, (TEST_CHANNEL, TEST_ACCOUNT_ID))?)
+                .is_equal_to(account);
+            assert_that!(PENDING_ACCOUNT.exists(&deps.storage)).is_false();
+
+            let res = create_account(deps.as_mut());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(HostError::AbstractAccountAlreadyCreated);
+            Ok(())
+        }
+
+        #[test]
+        fn requires_account_factory() -> Result<(), HostError> {
+            let mut deps = mock_init();
+            mock_host()
+                .base_state
+                .update(deps.as_mut().storage, |mut state| -> StdResult<_> {
+                    state.account_factory = None;
+                    Ok(state)
+                })?;
+
+            let res = create_account(deps.as_mut());
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(HostError::MissingAccountFactory);
+            Ok(())
+        }
+    }
+
+    mod receive_manager {
+        use super::*;
+
+        #[test]
+        fn requires_abstract_account() -> Result<(), HostError> {
+            let deps = mock_init();
+
+            let res = receive_manager(
+                deps.as_ref(),
+                TEST_CHANNEL.to_string(),
+                TEST_ACCOUNT_ID,
+                update_info_msg(),
+            );
+            assert_that!(res)
+                .is_err()
+                .is_equal_to(HostError::NoAbstractAccount);
+            Ok(())
+        }
+
+        #[test]
+        fn forwards_to_manager() -> Result<(), HostError> {
+            let mut deps = mock_init();
+            ABSTRACT_ACCOUNTS.save(
+                deps.as_mut().storage,
+                (TEST_CHANNEL, TEST_ACCOUNT_ID),
+                &test_account_base(),
+            )?;
+
+            let res = receive_manager(
+                deps.as_ref(),
+                TEST_CHANNEL.to_string(),
+                TEST_ACCOUNT_ID,
+                update_info_msg(),
+            )?;
+            assert_that!(res.messages).has_length(1);
+            assert_that!(res.messages[0].id).is_equal_to(RECEIVE_MANAGER_ID);
+            assert_that!(res.messages[0].msg).is_equal_to(CosmosMsg::from(wasm_execute(
+                TEST_MANAGER,
+                &update_info_msg(),
+                vec![],
+            )?));
+
+            let res = reply(deps.as_mut(), RECEIVE_MANAGER_ID, reply_ok())?;
+            assert_that!(res.data).is_equal_to(Some(StdAck::success(Empty {})));
+            Ok(())
+        }
+
+        #[test]
+        fn acks_failed_message() -> Result<(), HostError> {
+            let mut deps = mock_init();
+
+            let res = reply(
+                deps.as_mut(),
+                RECEIVE_MANAGER_ID,
+                SubMsgResult::Err("manager error".to_string()),
+            )?;
+            let ack: StdAck = from_binary(&res.data.unwrap())?;
+            assert_that!(ack).is_equal_to(StdAck::Error("manager error".to_string()));
+            Ok(())
+        }
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
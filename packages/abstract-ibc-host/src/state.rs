@@ -1,6 +1,8 @@
 use crate::{
     endpoints::reply::{
-        reply_dispatch_callback, reply_init_callback, INIT_CALLBACK_ID, RECEIVE_DISPATCH_ID,
+        reply_create_account_callback, reply_dispatch_callback, reply_init_callback,
+        reply_manager_callback, CREATE_ACCOUNT_CALLBACK_ID, INIT_CALLBACK_ID, RECEIVE_DISPATCH_ID,
+        RECEIVE_MANAGER_ID,
     },
     HostError,
 };
@@ -10,7 +12,7 @@ use abstract_sdk::{
         AbstractContract, ExecuteHandlerFn, InstantiateHandlerFn, QueryHandlerFn, ReceiveHandlerFn,
         ReplyHandlerFn, SudoHandlerFn,
     },
-    core::ibc_host::PacketMsg,
+    core::{ibc_host::PacketMsg, version_control::AccountBase},
     feature_objects::AnsHost,
     namespaces::{ADMIN_NAMESPACE, BASE_STATE},
     AbstractSdkError,
@@ -23,12 +25,16 @@ use serde::{Deserialize, Serialize};
 
 /// Store channel information for proxy contract creation reply
 pub const PENDING: Item<(String, AccountId)> = Item::new("pending");
+/// Store channel information and the expected account id for Abstract Account creation reply
+pub const PENDING_ACCOUNT: Item<(String, AccountId, AccountId)> = Item::new("pending_account");
 /// Store the processing packet information for processing in Reply along with the channel id it came from
 pub const PROCESSING_PACKET: Item<(PacketMsg, String)> = Item::new("processing");
 /// (channel-id,account_id) -> local_proxy_addr
 pub const ACCOUNTS: Map<(&str, AccountId), Addr> = Map::new("accounts");
 /// (channel-id,account_id) -> client_proxy_addr
 pub const CLIENT_PROXY: Map<(&str, AccountId), String> = Map::new("client_proxy");
+/// (channel-id,account_id) -> Abstract Account created on this chain
+pub const ABSTRACT_ACCOUNTS: Map<(&str, AccountId), AccountBase> = Map::new("abstract_accounts");
 /// List of closed channels
 /// Allows for fund recovery
 pub const CLOSED_CHANNELS: Item<Vec<String>> = Item::new("closed");
@@ -86,6 +92,8 @@ impl<
                 // add reply handlers we want to support by default
                 (RECEIVE_DISPATCH_ID, reply_dispatch_callback),
                 (INIT_CALLBACK_ID, reply_init_callback),
+                (CREATE_ACCOUNT_CALLBACK_ID, reply_create_account_callback),
+                (RECEIVE_MANAGER_ID, reply_manager_callback),
             ],
             &[],
         ]);
@@ -162,6 +170,8 @@ pub struct HostState {
     pub ans_host: AnsHost,
     /// code id for Stargate proxy contract
     pub cw1_code_id: u64,
+    /// Account factory used to create Abstract Accounts for remote Accounts
+    pub account_factory: Option<Addr>,
     /// Chain identifier
     pub chain: String,
 }
@@ -7,7 +7,16 @@ use schemars::JsonSchema;
 pub struct IbcResponseMsg {
     /// The ID chosen by the caller in the `callback_id`
     pub id: String,
-    pub msg: StdAck,
+    pub msg: IbcCallbackOutcome,
+}
+
+/// What happened to the packet the callback was requested for
+#[cosmwasm_schema::cw_serde]
+pub enum IbcCallbackOutcome {
+    /// The host acknowledged the packet
+    Ack(StdAck),
+    /// The packet timed out before it was received by the host
+    Timeout {},
 }
 
 impl IbcResponseMsg {
@@ -51,7 +60,7 @@ mod test {
     fn into_binary_should_wrap_in_callback() {
         let msg = IbcResponseMsg {
             id: "my-id".to_string(),
-            msg: StdAck::Result(Binary::default()),
+            msg: IbcCallbackOutcome::Ack(StdAck::Result(Binary::default())),
         };
 
         let actual = msg.clone().into_binary().unwrap();
@@ -63,7 +72,7 @@ mod test {
     fn into_cosmos_account_msg_should_build_wasm_execute() {
         let msg = IbcResponseMsg {
             id: "my-id".to_string(),
-            msg: StdAck::Result(Binary::default()),
+            msg: IbcCallbackOutcome::Ack(StdAck::Result(Binary::default())),
         };
 
         let actual = msg.clone().into_cosmos_account_msg("my-addr").unwrap();
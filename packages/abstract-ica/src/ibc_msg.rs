@@ -73,6 +73,14 @@ pub struct RegisterResponse {
     pub account: String,
 }
 
+/// This is the success response we send on ack for PacketMsg::CreateAccount.
+/// Return the caller's Abstract Account addresses on the remote chain
+#[cosmwasm_schema::cw_serde]
+pub struct CreateAccountResponse {
+    pub manager: String,
+    pub proxy: String,
+}
+
 /// This is the success response we send on ack for PacketMsg::Balance.
 /// Just acknowledge success or error
 #[cosmwasm_schema::cw_serde]
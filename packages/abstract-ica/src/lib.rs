@@ -4,11 +4,11 @@ mod ibc_msg;
 use cosmwasm_std::IbcOrder;
 
 pub use crate::{
-    callback::IbcResponseMsg,
+    callback::{IbcCallbackOutcome, IbcResponseMsg},
     checks::{check_order, check_version, SimpleIcaError},
     ibc_msg::{
-        BalancesResponse, DispatchResponse, IbcQueryResponse, RegisterResponse,
-        SendAllBackResponse, StdAck, WhoAmIResponse,
+        BalancesResponse, CreateAccountResponse, DispatchResponse, IbcQueryResponse,
+        RegisterResponse, SendAllBackResponse, StdAck, WhoAmIResponse,
     },
 };
 
@@ -80,6 +80,7 @@ impl<Chain: CwEnv> Manager<Chain> {
             &ExecuteMsg::InstallModule {
                 module: ModuleInfo::from_id(module_id, version)?,
                 init_msg: Some(to_binary(init_msg).unwrap()),
+                permissions: None,
             },
             funds,
         )?;
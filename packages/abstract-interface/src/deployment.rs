@@ -142,6 +142,8 @@ impl<Chain: CwEnv> Abstract<Chain> {
             &abstract_core::version_control::InstantiateMsg {
                 allow_direct_module_registration_and_updates: Some(true),
                 namespace_registration_fee: None,
+                namespace_limit: None,
+                namespace_registration_period: None,
             },
             Some(sender),
             None,
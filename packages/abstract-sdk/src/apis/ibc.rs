@@ -70,6 +70,7 @@ impl<'a, T: IbcInterface> IbcClient<'a, T> {
                     action,
                     callback_info: callback,
                     retries,
+                    timeout: None,
                 }],
             },
             vec![],
@@ -88,6 +89,7 @@ impl<'a, T: IbcInterface> IbcClient<'a, T> {
                 msgs: vec![IbcClientMsg::SendFunds {
                     host_chain: receiving_chain,
                     funds,
+                    timeout: None,
                 }],
             },
             vec![],
@@ -128,6 +130,7 @@ mod test {
                     action: HostAction::Balances {},
                     callback_info: None,
                     retries: expected_retries,
+                    timeout: None,
                 }],
             })
             .unwrap(),
@@ -166,6 +169,7 @@ mod test {
                     action: HostAction::Balances {},
                     callback_info: Some(expected_callback),
                     retries: expected_retries,
+                    timeout: None,
                 }],
             })
             .unwrap(),
@@ -193,6 +197,7 @@ mod test {
                 msgs: vec![IbcClientMsg::SendFunds {
                     host_chain: TEST_HOST_CHAIN.to_string(),
                     funds: expected_funds,
+                    timeout: None,
                 }],
             })
             .unwrap(),
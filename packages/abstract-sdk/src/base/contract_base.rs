@@ -1,6 +1,6 @@
 use super::handler::Handler;
 use crate::{AbstractSdkError, AbstractSdkResult};
-use abstract_core::abstract_ica::StdAck;
+use abstract_core::abstract_ica::IbcCallbackOutcome;
 use core::objects::dependency::StaticDependency;
 use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, Storage};
 use cw2::{ContractVersion, CONTRACT};
@@ -40,8 +40,15 @@ pub type QueryHandlerFn<Module, CustomQueryMsg, Error> =
 type CallbackId = String;
 // ANCHOR: ibc
 /// Function signature for an IBC callback handler.
-pub type IbcCallbackHandlerFn<Module, Error> =
-    fn(DepsMut, Env, MessageInfo, Module, CallbackId, StdAck) -> Result<Response, Error>;
+/// The handler receives the acknowledgement of the packet, or a timeout if it never reached the host.
+pub type IbcCallbackHandlerFn<Module, Error> = fn(
+    DepsMut,
+    Env,
+    MessageInfo,
+    Module,
+    CallbackId,
+    IbcCallbackOutcome,
+) -> Result<Response, Error>;
 // ANCHOR_END: ibc
 
 // ANCHOR: mig
@@ -22,11 +22,11 @@ pub trait IbcCallbackEndpoint: Handler + ModuleInterface {
             }
             .into());
         };
-        let IbcResponseMsg { id, msg: ack } = msg;
+        let IbcResponseMsg { id, msg: outcome } = msg;
         let maybe_handler = self.maybe_ibc_callback_handler(&id);
         maybe_handler.map_or_else(
             || Ok(Response::new()),
-            |handler| handler(deps, env, info, self, id, ack),
+            |handler| handler(deps, env, info, self, id, outcome),
         )
     }
 }